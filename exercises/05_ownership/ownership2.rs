@@ -0,0 +1,23 @@
+// ownership2.rs
+// calculate_length 拿走了 s 的所有权。改成借用，让调用方之后还能使用 s。
+// 对应课程：src/ownership.rs
+
+pub fn length_and_string() -> (usize, String) {
+    let s = String::from("hello");
+    let len = calculate_length(s);
+    (len, s)
+}
+
+fn calculate_length(s: String) -> usize {
+    s.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_string() {
+        assert_eq!(length_and_string(), (5, String::from("hello")));
+    }
+}
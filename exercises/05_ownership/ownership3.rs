@@ -0,0 +1,19 @@
+// ownership3.rs
+// 通过不可变引用无法修改字符串，改用可变借用。
+// 对应课程：src/ownership.rs
+
+pub fn change(s: &String) {
+    s.push_str(", world!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_world() {
+        let mut s = String::from("hello");
+        change(&mut s);
+        assert_eq!(s, "hello, world!");
+    }
+}
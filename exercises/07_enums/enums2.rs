@@ -0,0 +1,22 @@
+// enums2.rs
+// 用 Option 代替 null：除数为 0 时返回 None。
+// 对应课程：src/enums.rs
+
+pub fn checked_divide(a: i32, b: i32) -> Option<i32> {
+    todo!("b 为 0 时返回 None，否则返回 Some(a / b)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divides() {
+        assert_eq!(checked_divide(10, 2), Some(5));
+    }
+
+    #[test]
+    fn zero_divisor_is_none() {
+        assert_eq!(checked_divide(5, 0), None);
+    }
+}
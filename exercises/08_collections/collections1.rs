@@ -0,0 +1,24 @@
+// collections1.rs
+// 返回一个只包含偶数的新 Vec，保持原有顺序。
+// 对应课程：src/collections.rs
+
+pub fn evens(numbers: &[i32]) -> Vec<i32> {
+    let result = Vec::new();
+    for n in numbers {
+        if n % 2 == 0 {
+            // 把 n 放进 result
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_even_numbers() {
+        assert_eq!(evens(&[1, 2, 3, 4, 6]), vec![2, 4, 6]);
+        assert!(evens(&[]).is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+// modules2.rs
+// 结构体的私有字段不能在模块外直接赋值。为 Breakfast 添加一个公开的
+// 关联函数 summer(toast)，由它填入 seasonal_fruit = "peaches"。
+// 对应课程：src/modules/mod.rs
+
+mod back_of_house {
+    pub struct Breakfast {
+        pub toast: String,
+        seasonal_fruit: String,
+    }
+
+    impl Breakfast {
+        pub fn fruit(&self) -> &str {
+            &self.seasonal_fruit
+        }
+    }
+}
+
+pub fn order() -> back_of_house::Breakfast {
+    back_of_house::Breakfast::summer("Rye")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summer_breakfast_has_peaches() {
+        let meal = order();
+        assert_eq!(meal.toast, "Rye");
+        assert_eq!(meal.fruit(), "peaches");
+    }
+}
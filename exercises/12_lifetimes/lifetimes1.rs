@@ -0,0 +1,17 @@
+// lifetimes1.rs
+// 返回值借用自哪个参数？用生命周期注解告诉编译器。
+// 对应课程：src/lifetimes.rs
+
+pub fn longest(x: &str, y: &str) -> &str {
+    if x.len() > y.len() { x } else { y }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_longest() {
+        assert_eq!(longest("abcd", "xyz"), "abcd");
+    }
+}
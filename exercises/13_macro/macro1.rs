@@ -0,0 +1,23 @@
+// macro1.rs
+// 声明式宏必须先定义、后使用（按源码顺序）。调整位置让代码通过编译。
+// 对应课程：src/macros.rs
+
+pub fn greeting() -> String {
+    my_macro!()
+}
+
+macro_rules! my_macro {
+    () => {
+        String::from("Hello, macro!")
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_macro() {
+        assert_eq!(greeting(), "Hello, macro!");
+    }
+}
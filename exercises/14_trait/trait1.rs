@@ -0,0 +1,31 @@
+// trait1.rs
+// 为 Counter 实现标准库的 Iterator：指定关联类型 Item 并实现 next。
+// 对应课程：src/traits.rs
+
+pub struct Counter {
+    pub count: u32,
+    pub max: u32,
+}
+
+impl Iterator for Counter {
+    fn next(&mut self) -> Option<Self::Item> {
+        todo!("count 小于 max 时返回当前值并加一，否则返回 None")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_up_to_max() {
+        let counter = Counter { count: 0, max: 5 };
+        assert_eq!(counter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn works_with_iterator_adapters() {
+        let sum: u32 = Counter { count: 0, max: 5 }.filter(|n| n % 2 == 0).sum();
+        assert_eq!(sum, 6);
+    }
+}
@@ -0,0 +1,19 @@
+// variables1.rs
+// 变量默认不可变。让代码通过编译，使 count_to_six 返回 6。
+// 对应课程：src/variables.rs
+
+pub fn count_to_six() -> i32 {
+    let mut x = 5;
+    x = 6;
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reassigns_to_six() {
+        assert_eq!(count_to_six(), 6);
+    }
+}
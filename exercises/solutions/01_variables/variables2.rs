@@ -0,0 +1,20 @@
+// variables2.rs
+// 同一个名字先是字符串、后是它的长度。mut 变量不能改变类型，
+// 用遮蔽（shadowing）让代码通过编译。
+// 对应课程：src/variables.rs
+
+pub fn count_spaces() -> usize {
+    let spaces = "   ";
+    let spaces = spaces.len();
+    spaces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shadowing_changes_type() {
+        assert_eq!(count_spaces(), 3);
+    }
+}
@@ -0,0 +1,18 @@
+// data_types1.rs
+// 用解构把元组 (i32, char) 交换成 (char, i32)。
+// 对应课程：src/data_types.rs
+
+pub fn swap(pair: (i32, char)) -> (char, i32) {
+    let (number, letter) = pair;
+    (letter, number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swaps_tuple() {
+        assert_eq!(swap((500, 'a')), ('a', 500));
+    }
+}
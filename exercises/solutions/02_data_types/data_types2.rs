@@ -0,0 +1,17 @@
+// data_types2.rs
+// 数组长度是类型的一部分：[i32; 5] 的最后一个下标是多少？
+// 对应课程：src/data_types.rs
+
+pub fn first_and_last(arr: [i32; 5]) -> (i32, i32) {
+    (arr[0], arr[4])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_both_ends() {
+        assert_eq!(first_and_last([1, 2, 3, 4, 5]), (1, 5));
+    }
+}
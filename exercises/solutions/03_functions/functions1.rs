@@ -0,0 +1,18 @@
+// functions1.rs
+// plus_one 应该真的返回 x + 1。注意最后一行的分号。
+// 对应课程：src/functions.rs
+
+pub fn plus_one(x: i32) -> i32 {
+    x + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_one() {
+        assert_eq!(plus_one(5), 6);
+        assert_eq!(plus_one(-1), 0);
+    }
+}
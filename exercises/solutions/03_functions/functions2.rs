@@ -0,0 +1,17 @@
+// functions2.rs
+// 函数参数必须标注类型。
+// 对应课程：src/functions.rs
+
+pub fn square(x: i32) -> i32 {
+    x * x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squares() {
+        assert_eq!(square(4), 16);
+    }
+}
@@ -0,0 +1,22 @@
+// control_flow1.rs
+// if 作为表达式时必须有 else，且每个分支类型一致。
+// 对应课程：src/control_flow.rs
+
+pub fn bigger(a: i32, b: i32) -> i32 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_bigger() {
+        assert_eq!(bigger(10, 8), 10);
+        assert_eq!(bigger(32, 42), 42);
+    }
+}
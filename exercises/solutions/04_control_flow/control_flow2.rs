@@ -0,0 +1,24 @@
+// control_flow2.rs
+// 用 loop 和 break 返回值：找到第一个大于 above 且能被 n 整除的数。
+// 对应课程：src/control_flow.rs
+
+pub fn first_multiple_above(n: u32, above: u32) -> u32 {
+    let mut candidate = above;
+    loop {
+        candidate += 1;
+        if candidate % n == 0 {
+            break candidate;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_multiple() {
+        assert_eq!(first_multiple_above(7, 20), 21);
+        assert_eq!(first_multiple_above(5, 10), 15);
+    }
+}
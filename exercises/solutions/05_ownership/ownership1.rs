@@ -0,0 +1,19 @@
+// ownership1.rs
+// s1 被 move 之后就不能再用了。在不修改返回值的前提下让代码通过编译。
+// 对应课程：src/ownership.rs
+
+pub fn two_hellos() -> (String, String) {
+    let s1 = String::from("hello");
+    let s2 = s1.clone();
+    (s1, s2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_strings_are_hello() {
+        assert_eq!(two_hellos(), (String::from("hello"), String::from("hello")));
+    }
+}
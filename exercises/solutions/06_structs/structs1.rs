@@ -0,0 +1,33 @@
+// structs1.rs
+// 创建结构体实例时必须给每个字段赋值。新用户默认激活、登录次数为 1。
+// 对应课程：src/structs.rs
+
+#[derive(Debug)]
+pub struct User {
+    pub active: bool,
+    pub username: String,
+    pub email: String,
+    pub sign_in_count: u64,
+}
+
+pub fn build_user(email: String, username: String) -> User {
+    User {
+        email,
+        username,
+        active: true,
+        sign_in_count: 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_active_user() {
+        let user = build_user(String::from("a@example.com"), String::from("a"));
+        assert!(user.active);
+        assert_eq!(user.sign_in_count, 1);
+        assert_eq!(user.email, "a@example.com");
+    }
+}
@@ -0,0 +1,30 @@
+// structs2.rs
+// email_domain 应该返回 @ 后面的部分，没有 @ 时返回 None。
+// 对应课程：src/structs.rs
+
+pub struct User {
+    pub email: String,
+}
+
+impl User {
+    pub fn email_domain(&self) -> Option<&str> {
+        self.email.split_once('@').map(|(_, domain)| domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_domain() {
+        let user = User { email: String::from("alice@example.org") };
+        assert_eq!(user.email_domain(), Some("example.org"));
+    }
+
+    #[test]
+    fn no_at_sign_means_no_domain() {
+        let user = User { email: String::from("alice") };
+        assert_eq!(user.email_domain(), None);
+    }
+}
@@ -0,0 +1,48 @@
+// structs3.rs
+// 为 Task<Paused> 添加 resume 方法，让暂停的任务回到 Running 状态。
+// 对应课程：src/structs.rs
+
+use std::marker::PhantomData;
+
+pub struct Ready;
+pub struct Running;
+pub struct Paused;
+
+pub struct Task<S> {
+    pub name: String,
+    state: PhantomData<S>,
+}
+
+impl Task<Ready> {
+    pub fn new(name: &str) -> Self {
+        Task { name: name.to_string(), state: PhantomData }
+    }
+
+    pub fn start(self) -> Task<Running> {
+        Task { name: self.name, state: PhantomData }
+    }
+}
+
+impl Task<Running> {
+    pub fn pause(self) -> Task<Paused> {
+        Task { name: self.name, state: PhantomData }
+    }
+}
+
+impl Task<Paused> {
+    pub fn resume(self) -> Task<Running> {
+        Task { name: self.name, state: PhantomData }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paused_task_can_resume() {
+        let task = Task::new("Backup").start().pause();
+        let task: Task<Running> = task.resume();
+        assert_eq!(task.name, "Backup");
+    }
+}
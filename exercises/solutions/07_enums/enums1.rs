@@ -0,0 +1,32 @@
+// enums1.rs
+// match 必须覆盖枚举的所有变体。
+// 对应课程：src/enums.rs
+
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+pub fn describe(msg: &Message) -> String {
+    match msg {
+        Message::Quit => String::from("quit"),
+        Message::Move { x, y } => format!("move to ({}, {})", x, y),
+        Message::Write(text) => format!("write {}", text),
+        Message::ChangeColor(r, g, b) => format!("color ({}, {}, {})", r, g, b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_every_variant() {
+        assert_eq!(describe(&Message::Quit), "quit");
+        assert_eq!(describe(&Message::Move { x: 1, y: 2 }), "move to (1, 2)");
+        assert_eq!(describe(&Message::Write(String::from("hi"))), "write hi");
+        assert_eq!(describe(&Message::ChangeColor(255, 0, 0)), "color (255, 0, 0)");
+    }
+}
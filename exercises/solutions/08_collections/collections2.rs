@@ -0,0 +1,26 @@
+// collections2.rs
+// 统计每个单词出现的次数。insert 会覆盖旧值，试试 entry API。
+// 对应课程：src/collections.rs
+
+use std::collections::HashMap;
+
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_repeated_words() {
+        let counts = word_count("hello world wonderful world");
+        assert_eq!(counts["world"], 2);
+        assert_eq!(counts["hello"], 1);
+        assert_eq!(counts.len(), 3);
+    }
+}
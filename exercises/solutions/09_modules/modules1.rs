@@ -0,0 +1,25 @@
+// modules1.rs
+// 模块内的条目默认私有。只修改 front_of_house 内部，让外部能调用 add_to_waitlist。
+// 对应课程：src/modules/mod.rs
+
+mod front_of_house {
+    pub mod hosting {
+        pub fn add_to_waitlist() -> &'static str {
+            "Added to waitlist!"
+        }
+    }
+}
+
+pub fn eat_at_restaurant() -> &'static str {
+    front_of_house::hosting::add_to_waitlist()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_reach_hosting() {
+        assert_eq!(eat_at_restaurant(), "Added to waitlist!");
+    }
+}
@@ -0,0 +1,25 @@
+// error_handling1.rs
+// 不要 unwrap：把解析错误交给调用方处理。
+// 对应课程：src/error_handling.rs
+
+use std::num::ParseIntError;
+
+pub fn parse_number(s: &str) -> Result<i32, ParseIntError> {
+    let n: i32 = s.trim().parse()?;
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_number() {
+        assert_eq!(parse_number(" 42 "), Ok(42));
+    }
+
+    #[test]
+    fn reports_invalid_number() {
+        assert!(parse_number("abc").is_err());
+    }
+}
@@ -0,0 +1,26 @@
+// error_handling2.rs
+// parse 返回的是 Result，不能直接参与乘法。用 ? 操作符取出其中的值。
+// 对应课程：src/error_handling.rs
+
+use std::num::ParseIntError;
+
+pub fn total_cost(quantity: &str) -> Result<i32, ParseIntError> {
+    let cost_per_item = 5;
+    let qty = quantity.parse::<i32>()?;
+    Ok(qty * cost_per_item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_cost() {
+        assert_eq!(total_cost("34"), Ok(170));
+    }
+
+    #[test]
+    fn propagates_error() {
+        assert!(total_cost("beep boop").is_err());
+    }
+}
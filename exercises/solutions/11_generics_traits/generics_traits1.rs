@@ -0,0 +1,30 @@
+// generics_traits1.rs
+// 课程中的 largest 遇到空切片会 panic。让它返回 Option<T>，空切片返回 None。
+// 对应课程：src/generics_traits.rs
+
+pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    let mut largest = *list.first()?;
+    for &item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_largest() {
+        assert_eq!(largest(&[34, 50, 25, 100, 65]), Some(100));
+        assert_eq!(largest(&['y', 'm', 'a', 'q']), Some('y'));
+    }
+
+    #[test]
+    fn empty_slice_has_no_largest() {
+        let empty: [i32; 0] = [];
+        assert_eq!(largest(&empty), None);
+    }
+}
@@ -0,0 +1,34 @@
+// generics_traits2.rs
+// Tweet 目前使用了 Summary 的默认实现。重写 summarize，返回 "用户名: 内容"。
+// 对应课程：src/generics_traits.rs
+
+pub trait Summary {
+    fn summarize(&self) -> String {
+        String::from("(Read more...)")
+    }
+}
+
+pub struct Tweet {
+    pub username: String,
+    pub content: String,
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tweet_has_custom_summary() {
+        let tweet = Tweet {
+            username: String::from("horse_ebooks"),
+            content: String::from("of course"),
+        };
+        assert_eq!(tweet.summarize(), "horse_ebooks: of course");
+    }
+}
@@ -0,0 +1,24 @@
+// lifetimes2.rs
+// 结构体持有引用时需要生命周期参数。
+// 对应课程：src/lifetimes.rs
+
+pub struct ImportantExcerpt<'a> {
+    pub part: &'a str,
+}
+
+pub fn first_sentence(text: &str) -> ImportantExcerpt<'_> {
+    ImportantExcerpt {
+        part: text.split('.').next().unwrap_or(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_first_sentence() {
+        let novel = String::from("Call me Ishmael. Some years ago...");
+        assert_eq!(first_sentence(&novel).part, "Call me Ishmael");
+    }
+}
@@ -0,0 +1,25 @@
+// macro2.rs
+// sum! 应该把任意个表达式加起来。用 $(...)* 重复展开每个参数。
+// 对应课程：src/macros.rs
+
+macro_rules! sum {
+    ($($x:expr),*) => {
+        0 $(+ $x)*
+    };
+}
+
+pub fn total() -> i32 {
+    sum!(1, 2, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_all_arguments() {
+        assert_eq!(total(), 6);
+        assert_eq!(sum!(), 0);
+        assert_eq!(sum!(10, -4), 6);
+    }
+}
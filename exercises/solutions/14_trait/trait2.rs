@@ -0,0 +1,22 @@
+// trait2.rs
+// assert_eq! 需要 PartialEq 和 Debug，按值复制需要 Copy。用 #[derive] 自动实现它们。
+// 对应课程：src/traits.rs
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_compare_and_copy() {
+        let p1 = Point { x: 1, y: 2 };
+        let p2 = p1;
+        assert_eq!(p1, p2);
+        assert_ne!(p1, Point { x: 3, y: 4 });
+    }
+}
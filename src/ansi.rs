@@ -0,0 +1,255 @@
+//! 终端样式：用 ANSI 转义序列给文字上色，颜色来自第 6 课的 `Color(r, g, b)`
+//!
+//! 终端支持的颜色数不同，[`ColorLevel`] 决定输出哪种转义：
+//!
+//! - 16 色：`ESC[31m`，把颜色换成 xterm 默认调色板里最接近的一种
+//! - 256 色：`ESC[38;5;208m`，换成 6×6×6 色块或 24 级灰阶中最接近的一种
+//! - 真彩色：`ESC[38;2;255;136;0m`，原样输出
+//!
+//! 设置了 `NO_COLOR`（见 <https://no-color.org>）、输出不是终端（被重定向到文件或管道）
+//! 或 `TERM=dumb` 时不输出任何转义，所以黄金快照测试拿到的始终是纯文本。
+//!
+//! ```
+//! use rust_learning::ansi::{ColorLevel, Style};
+//! use rust_learning::structs::Color;
+//!
+//! let style = Style::new().bold().fg(Color(255, 136, 0));
+//! assert_eq!(style.paint("hi", ColorLevel::Ansi256), "\x1b[1;38;5;208mhi\x1b[0m");
+//! assert_eq!(style.paint("hi", ColorLevel::Plain), "hi");
+//! ```
+
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+use crate::structs::Color;
+
+/// 清除全部样式
+pub const RESET: &str = "\x1b[0m";
+
+/// 课程里 `=== 示例 7: 派生 Trait ===` 这类小节标题的样式
+pub const HEADING: Style = Style::new().bold().fg(Color(0, 205, 205));
+
+/// 课程运行器在每节课开头打印的横幅的样式
+pub const BANNER: Style = Style::new().bold().fg(Color(205, 205, 0));
+
+/// 终端能显示的颜色级别，从少到多排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorLevel {
+    /// 不输出转义序列
+    Plain,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorLevel {
+    /// 按环境判断颜色级别；`var` 读取环境变量，`is_tty` 表示输出是否为终端
+    ///
+    /// 依次检查：非空的 `NO_COLOR`、是否为终端、`TERM=dumb`、
+    /// `COLORTERM=truecolor|24bit`、`TERM` 是否含 `256color`，否则为 16 色。
+    pub fn detect(is_tty: bool, var: impl Fn(&str) -> Option<String>) -> ColorLevel {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) || !is_tty {
+            return ColorLevel::Plain;
+        }
+        let term = var("TERM").unwrap_or_default();
+        if term == "dumb" {
+            return ColorLevel::Plain;
+        }
+        let colorterm = var("COLORTERM").unwrap_or_default();
+        if colorterm.eq_ignore_ascii_case("truecolor") || colorterm.eq_ignore_ascii_case("24bit") {
+            ColorLevel::TrueColor
+        } else if term.contains("256color") {
+            ColorLevel::Ansi256
+        } else {
+            ColorLevel::Ansi16
+        }
+    }
+
+    /// 标准输出的颜色级别，第一次调用时检测一次
+    pub fn stdout() -> ColorLevel {
+        static LEVEL: OnceLock<ColorLevel> = OnceLock::new();
+        *LEVEL.get_or_init(|| {
+            ColorLevel::detect(io::stdout().is_terminal(), |name| env::var(name).ok())
+        })
+    }
+}
+
+/// xterm 默认的 16 色调色板，下标就是颜色编号
+const PALETTE_16: [Color; 16] = [
+    Color(0, 0, 0),
+    Color(205, 0, 0),
+    Color(0, 205, 0),
+    Color(205, 205, 0),
+    Color(0, 0, 238),
+    Color(205, 0, 205),
+    Color(0, 205, 205),
+    Color(229, 229, 229),
+    Color(127, 127, 127),
+    Color(255, 0, 0),
+    Color(0, 255, 0),
+    Color(255, 255, 0),
+    Color(92, 92, 255),
+    Color(255, 0, 255),
+    Color(0, 255, 255),
+    Color(255, 255, 255),
+];
+
+/// 256 色中 6×6×6 色块每个通道的取值
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: Color, b: Color) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// 最接近的 16 色编号（0..=15），0..=7 为普通色，8..=15 为亮色
+pub fn ansi16(color: Color) -> u8 {
+    (0..16u8)
+        .min_by_key(|&index| distance(color, PALETTE_16[usize::from(index)]))
+        .unwrap_or(0)
+}
+
+/// 最接近的 256 色编号（16..=255），在色块和灰阶中取距离较小的一个
+pub fn ansi256(color: Color) -> u8 {
+    let cube_index = |value: u8| match value {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (value - 35) / 40,
+    };
+    let (r, g, b) = (
+        cube_index(color.0),
+        cube_index(color.1),
+        cube_index(color.2),
+    );
+    let cube = Color(
+        CUBE_LEVELS[usize::from(r)],
+        CUBE_LEVELS[usize::from(g)],
+        CUBE_LEVELS[usize::from(b)],
+    );
+
+    // 灰阶 232..=255 的亮度为 8, 18, ..., 238
+    let average = (u32::from(color.0) + u32::from(color.1) + u32::from(color.2)) / 3;
+    let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+    let level = 8 + gray_index * 10;
+    let gray = Color(level, level, level);
+
+    if distance(color, gray) < distance(color, cube) {
+        232 + gray_index
+    } else {
+        16 + 36 * r + 6 * g + b
+    }
+}
+
+/// 前景色、背景色和字体效果的组合
+///
+/// 用 `const fn` 链式构造，可以直接写成常量，例如 [`HEADING`]。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    underline: bool,
+}
+
+impl Style {
+    /// 不带任何效果的样式
+    pub const fn new() -> Style {
+        Style {
+            fg: None,
+            bg: None,
+            bold: false,
+            underline: false,
+        }
+    }
+
+    pub const fn fg(mut self, color: Color) -> Style {
+        self.fg = Some(color);
+        self
+    }
+
+    pub const fn bg(mut self, color: Color) -> Style {
+        self.bg = Some(color);
+        self
+    }
+
+    pub const fn bold(mut self) -> Style {
+        self.bold = true;
+        self
+    }
+
+    pub const fn underline(mut self) -> Style {
+        self.underline = true;
+        self
+    }
+
+    /// 开启样式的转义序列；纯文本模式或样式为空时返回空字符串
+    pub fn prefix(&self, level: ColorLevel) -> String {
+        if level == ColorLevel::Plain {
+            return String::new();
+        }
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if self.underline {
+            codes.push("4".to_string());
+        }
+        if let Some(color) = self.fg {
+            codes.push(color_code(color, level, false));
+        }
+        if let Some(color) = self.bg {
+            codes.push(color_code(color, level, true));
+        }
+        if codes.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", codes.join(";"))
+        }
+    }
+
+    /// 给文字加上样式，结尾用 [`RESET`] 恢复；不需要转义时原样返回
+    pub fn paint(&self, text: &str, level: ColorLevel) -> String {
+        let prefix = self.prefix(level);
+        if prefix.is_empty() {
+            text.to_string()
+        } else {
+            format!("{}{}{}", prefix, text, RESET)
+        }
+    }
+}
+
+/// 单个颜色的 SGR 参数，例如 `31`、`38;5;208`、`48;2;255;136;0`
+fn color_code(color: Color, level: ColorLevel, background: bool) -> String {
+    match level {
+        ColorLevel::Plain => String::new(),
+        ColorLevel::Ansi16 => {
+            let index = ansi16(color);
+            let base = if index < 8 {
+                30 + index
+            } else {
+                90 + index - 8
+            };
+            (base + if background { 10 } else { 0 }).to_string()
+        }
+        ColorLevel::Ansi256 => {
+            let kind = if background { 48 } else { 38 };
+            format!("{};5;{}", kind, ansi256(color))
+        }
+        ColorLevel::TrueColor => {
+            let kind = if background { 48 } else { 38 };
+            let Color(r, g, b) = color;
+            format!("{};2;{};{};{}", kind, r, g, b)
+        }
+    }
+}
+
+/// 按标准输出的颜色级别，用 [`HEADING`] 样式渲染一行小节标题
+pub fn heading(text: &str) -> String {
+    HEADING.paint(text, ColorLevel::stdout())
+}
+
+/// 按标准输出的颜色级别，用 [`BANNER`] 样式渲染课程横幅
+pub fn banner(text: &str) -> String {
+    BANNER.paint(text, ColorLevel::stdout())
+}
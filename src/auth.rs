@@ -0,0 +1,409 @@
+//! 登录认证：加盐密码哈希、会话令牌和连续失败后的锁定
+//!
+//! [`Authenticator`] 包装一个 [`UserRepository`]，密码哈希保存在仓库里，失败次数和会话保存在内存中。
+//! 密码用 PBKDF2-HMAC-SHA256（见 [`crate::crypto`]）加随机盐哈希，只保存哈希不保存明文。
+//! 只有密码校验通过、账号处于激活状态时才算登录成功：这时才会增加 `sign_in_count`、
+//! 更新最近登录时间并写回用户仓库，然后签发一个带过期时间的会话令牌。
+//!
+//! 所有和时间有关的方法都显式接收 `now`（Unix 秒），调用方通常传入 [`crate::progress::now`]，
+//! 测试里则可以直接控制时间。
+//!
+//! ```
+//! use rust_learning::auth::{AuthConfig, Authenticator};
+//! use rust_learning::structs::User;
+//! use rust_learning::users::MemoryUserRepository;
+//!
+//! let config = AuthConfig { iterations: 1_000, ..AuthConfig::default() };
+//! let mut auth = Authenticator::with_config(MemoryUserRepository::new(), config);
+//! auth.register(User::from_email("alice@example.org").unwrap(), "correct horse").unwrap();
+//!
+//! let session = auth.authenticate("alice", "correct horse", 1_000).unwrap();
+//! assert_eq!(auth.session_user(&session.token, 1_060).unwrap().sign_in_count(), 1);
+//! assert!(auth.authenticate("alice", "wrong", 1_100).is_err());
+//! ```
+
+use std::collections::BTreeMap;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::crypto::{self, Sha256};
+use crate::structs::User;
+use crate::users::{UserError, UserRepository};
+
+const SALT_LEN: usize = 16;
+const HASH_LEN: usize = crypto::DIGEST_LEN;
+const SCHEME: &str = "pbkdf2-sha256";
+
+/// 迭代次数上限：文件里被篡改或损坏的哈希不能让一次登录算上几十亿轮
+pub const MAX_ITERATIONS: u32 = 1_000_000;
+
+/// 认证参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthConfig {
+    /// PBKDF2 迭代次数，越大越难暴力破解，登录也越慢；超过 [`MAX_ITERATIONS`] 按上限计算
+    pub iterations: u32,
+    /// 连续失败多少次后锁定账号
+    pub max_failures: u32,
+    /// 锁定时长（秒）
+    pub lockout_secs: u64,
+    /// 会话有效期（秒）
+    pub session_ttl_secs: u64,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        AuthConfig {
+            iterations: 100_000,
+            max_failures: 5,
+            lockout_secs: 15 * 60,
+            session_ttl_secs: 24 * 60 * 60,
+        }
+    }
+}
+
+/// 认证失败的原因
+#[derive(Debug)]
+pub enum AuthError {
+    EmptyPassword,
+    /// 用户名不存在或密码错误（不区分两者，避免泄露哪些用户名存在）
+    InvalidCredentials,
+    /// 连续失败次数过多，在 `until` 之前拒绝登录
+    Locked {
+        until: u64,
+    },
+    /// 密码正确，但账号已停用
+    Inactive(String),
+    /// 会话令牌不存在、已注销或对应的用户已不可用
+    InvalidSession,
+    SessionExpired,
+    User(UserError),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthError::EmptyPassword => write!(f, "密码不能为空"),
+            AuthError::InvalidCredentials => write!(f, "用户名或密码错误"),
+            AuthError::Locked { until } => write!(f, "登录失败次数过多，账号锁定到 {}", until),
+            AuthError::Inactive(username) => write!(f, "账号已停用: {}", username),
+            AuthError::InvalidSession => write!(f, "无效的会话"),
+            AuthError::SessionExpired => write!(f, "会话已过期，请重新登录"),
+            AuthError::User(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AuthError::User(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<UserError> for AuthError {
+    fn from(e: UserError) -> Self {
+        AuthError::User(e)
+    }
+}
+
+/// 加盐的密码哈希，文本形式为 `pbkdf2-sha256$<迭代次数>$<盐>$<哈希>`（盐和哈希为十六进制）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHash {
+    iterations: u32,
+    salt: [u8; SALT_LEN],
+    hash: [u8; HASH_LEN],
+}
+
+impl PasswordHash {
+    /// 用随机盐哈希密码
+    pub fn new(password: &str, iterations: u32) -> PasswordHash {
+        let mut salt = [0; SALT_LEN];
+        salt.copy_from_slice(&random_bytes()[..SALT_LEN]);
+        PasswordHash::with_salt(password, salt, iterations)
+    }
+
+    /// 用指定的盐哈希密码
+    pub fn with_salt(password: &str, salt: [u8; SALT_LEN], iterations: u32) -> PasswordHash {
+        let iterations = iterations.clamp(1, MAX_ITERATIONS);
+        let mut hash = [0; HASH_LEN];
+        crypto::pbkdf2_sha256(password.as_bytes(), &salt, iterations, &mut hash);
+        PasswordHash {
+            iterations,
+            salt,
+            hash,
+        }
+    }
+
+    /// 用同样的盐和迭代次数重新计算，再做定长比较
+    pub fn verify(&self, password: &str) -> bool {
+        let candidate = PasswordHash::with_salt(password, self.salt, self.iterations);
+        crypto::constant_time_eq(&candidate.hash, &self.hash)
+    }
+
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+}
+
+impl fmt::Display for PasswordHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}${}${}${}",
+            SCHEME,
+            self.iterations,
+            crypto::to_hex(&self.salt),
+            crypto::to_hex(&self.hash)
+        )
+    }
+}
+
+impl FromStr for PasswordHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<PasswordHash, String> {
+        let parts: Vec<&str> = s.split('$').collect();
+        let [SCHEME, iterations, salt, hash] = parts.as_slice() else {
+            return Err(format!("应为 {}$<迭代次数>$<盐>$<哈希>", SCHEME));
+        };
+        let iterations = iterations
+            .parse()
+            .ok()
+            .filter(|&n| (1..=MAX_ITERATIONS).contains(&n))
+            .ok_or_else(|| format!("无效的迭代次数: {}", iterations))?;
+        let bytes = |hex: &str, what: &str| {
+            crypto::from_hex(hex).ok_or_else(|| format!("{}不是十六进制: {}", what, hex))
+        };
+        let salt = bytes(salt, "盐")?
+            .try_into()
+            .map_err(|_| format!("盐应为 {} 字节", SALT_LEN))?;
+        let hash = bytes(hash, "哈希")?
+            .try_into()
+            .map_err(|_| format!("哈希应为 {} 字节", HASH_LEN))?;
+        Ok(PasswordHash {
+            iterations,
+            salt,
+            hash,
+        })
+    }
+}
+
+/// 一次成功登录签发的会话
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    /// 64 位十六进制的随机令牌
+    pub token: String,
+    pub username: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+impl Session {
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// 每个用户的连续失败记录
+#[derive(Debug, Clone, Default)]
+struct Attempts {
+    failures: u32,
+    locked_until: Option<u64>,
+}
+
+/// 基于用户仓库的认证服务
+pub struct Authenticator<R> {
+    users: R,
+    config: AuthConfig,
+    attempts: BTreeMap<String, Attempts>,
+    sessions: BTreeMap<String, Session>,
+    /// 用户不存在时拿来空算一次的哈希，第一次用到时才计算
+    dummy_hash: Option<PasswordHash>,
+}
+
+impl<R: UserRepository> Authenticator<R> {
+    pub fn new(users: R) -> Authenticator<R> {
+        Authenticator::with_config(users, AuthConfig::default())
+    }
+
+    pub fn with_config(users: R, config: AuthConfig) -> Authenticator<R> {
+        Authenticator {
+            users,
+            config,
+            attempts: BTreeMap::new(),
+            sessions: BTreeMap::new(),
+            dummy_hash: None,
+        }
+    }
+
+    pub fn users(&self) -> &R {
+        &self.users
+    }
+
+    pub fn users_mut(&mut self) -> &mut R {
+        &mut self.users
+    }
+
+    /// 新建用户并设置密码
+    pub fn register(&mut self, user: User, password: &str) -> Result<(), AuthError> {
+        if password.is_empty() {
+            return Err(AuthError::EmptyPassword);
+        }
+        let username = user.username().to_string();
+        self.users.create(user)?;
+        if let Err(e) = self.store_password(&username, password) {
+            // 没有密码的用户无法登录，撤销刚才的创建
+            let _ = self.users.delete(&username);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// 为已有用户设置新密码：清除失败记录和锁定，并注销该用户的全部会话
+    pub fn set_password(&mut self, username: &str, password: &str) -> Result<(), AuthError> {
+        if password.is_empty() {
+            return Err(AuthError::EmptyPassword);
+        }
+        self.store_password(username, password)?;
+        self.sessions
+            .retain(|_, session| session.username != username);
+        Ok(())
+    }
+
+    fn store_password(&mut self, username: &str, password: &str) -> Result<(), AuthError> {
+        let hash = PasswordHash::new(password, self.config.iterations);
+        self.users.set_password_hash(username, hash)?;
+        self.attempts.remove(username);
+        Ok(())
+    }
+
+    /// 用固定的哈希校验一次密码，结果总是被丢弃
+    ///
+    /// 用户名不存在时也做同样多的 PBKDF2 运算，不能从响应时间判断用户名是否存在。
+    fn verify_dummy(&mut self, password: &str) {
+        let iterations = self.config.iterations;
+        let dummy = self
+            .dummy_hash
+            .get_or_insert_with(|| PasswordHash::with_salt("", [0; SALT_LEN], iterations));
+        let _ = dummy.verify(password);
+    }
+
+    /// 校验用户名和密码，成功时记录登录并签发会话
+    pub fn authenticate(
+        &mut self,
+        username: &str,
+        password: &str,
+        now: u64,
+    ) -> Result<Session, AuthError> {
+        let attempts = self.attempts.entry(username.to_string()).or_default();
+        if let Some(until) = attempts.locked_until {
+            if now < until {
+                return Err(AuthError::Locked { until });
+            }
+            attempts.locked_until = None;
+        }
+
+        // 用户不存在时仓库里也没有哈希。这时同样空算一次、同样计入失败次数，
+        // 不存在的用户名也会被锁定，不能从响应区分用户名是否存在
+        let found = match (
+            self.users.find_by_username(username).cloned(),
+            self.users.password_hash(username).cloned(),
+        ) {
+            (Some(user), Some(hash)) => hash.verify(password).then_some(user),
+            _ => {
+                self.verify_dummy(password);
+                None
+            }
+        };
+        let Some(mut user) = found else {
+            let attempts = self.attempts.entry(username.to_string()).or_default();
+            attempts.failures += 1;
+            if attempts.failures >= self.config.max_failures {
+                let until = now + self.config.lockout_secs;
+                attempts.failures = 0;
+                attempts.locked_until = Some(until);
+                return Err(AuthError::Locked { until });
+            }
+            return Err(AuthError::InvalidCredentials);
+        };
+        self.attempts.remove(username);
+        if !user.is_active() {
+            return Err(AuthError::Inactive(username.to_string()));
+        }
+
+        user.record_sign_in(now);
+        self.users.update(user)?;
+
+        let session = Session {
+            token: crypto::to_hex(&random_bytes()),
+            username: username.to_string(),
+            created_at: now,
+            expires_at: now + self.config.session_ttl_secs,
+        };
+        self.sessions.insert(session.token.clone(), session.clone());
+        Ok(session)
+    }
+
+    /// 会话对应的用户；过期的会话会被删除
+    pub fn session_user(&mut self, token: &str, now: u64) -> Result<&User, AuthError> {
+        let Some(session) = self.sessions.get(token) else {
+            return Err(AuthError::InvalidSession);
+        };
+        if session.is_expired(now) {
+            self.sessions.remove(token);
+            return Err(AuthError::SessionExpired);
+        }
+        match self.users.find_by_username(&session.username) {
+            Some(user) if user.is_active() => Ok(user),
+            _ => {
+                self.sessions.remove(token);
+                Err(AuthError::InvalidSession)
+            }
+        }
+    }
+
+    /// 注销会话；令牌不存在时返回 false
+    pub fn logout(&mut self, token: &str) -> bool {
+        self.sessions.remove(token).is_some()
+    }
+
+    /// 删除所有过期的会话，返回删除的数量
+    pub fn purge_expired(&mut self, now: u64) -> usize {
+        let before = self.sessions.len();
+        self.sessions.retain(|_, session| !session.is_expired(now));
+        before - self.sessions.len()
+    }
+
+    /// 当前有效（未注销、未清理）的会话数
+    pub fn session_count(&self) -> usize {
+        self.sessions.len()
+    }
+}
+
+/// 生成 32 个随机字节，用作盐和会话令牌
+///
+/// 标准库没有公开密码学安全的随机数接口，这里把 `RandomState` 的随机种子、当前时间、
+/// 进程号和一个递增计数器混合后做 SHA-256。足够让每次生成的值都不同且难以预测，
+/// 但不是经过审计的 CSPRNG。
+fn random_bytes() -> [u8; 32] {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+
+    let mut hasher = Sha256::new();
+    for _ in 0..4 {
+        hasher.update(&RandomState::new().hash_one(count).to_le_bytes());
+    }
+    hasher.update(&nanos.to_le_bytes());
+    hasher.update(&std::process::id().to_le_bytes());
+    hasher.update(&count.to_le_bytes());
+    hasher.finalize()
+}
@@ -0,0 +1,124 @@
+//! 子网计算器：第 7 课 `IpAddr` 和 `Cidr` 的命令行用法
+//!
+//! ```bash
+//! cargo run --bin cidr -- info 192.168.1.0/24
+//! cargo run --bin cidr -- contains 10.0.0.0/8 10.20.30.40
+//! cargo run --bin cidr -- split 10.0.0.0/24 26
+//! cargo run --bin cidr -- aggregate 10.0.0.0/25 10.0.0.128/25 10.0.1.7
+//! ```
+
+use std::env;
+use std::process::ExitCode;
+
+use rust_learning::cidr::{Cidr, CidrError};
+use rust_learning::enums::IpAddr;
+
+const USAGE: &str = "\
+用法: cidr <命令>
+
+命令:
+  info <网段>...              显示网络地址、广播地址、掩码和可用主机数
+  contains <网段> <地址>...   检查地址是否在网段内
+  split <网段> <新前缀>       按更长的前缀切分子网，例如 split 10.0.0.0/24 26
+  aggregate <网段或地址>...   合并成覆盖相同地址的最少网段
+  help                        显示本帮助
+
+网段写成 10.0.0.0/8 或 fe80::/64，单个地址视为 /32 或 /128";
+
+/// 最多列出的子网个数，避免 `split ::/0 128` 这类命令输出不完
+const MAX_SUBNETS: usize = 4096;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["info", networks @ ..] if !networks.is_empty() => info(networks),
+        ["contains", network, addresses @ ..] if !addresses.is_empty() => {
+            contains(network, addresses)
+        }
+        ["split", network, prefix] => split(network, prefix),
+        ["aggregate", items @ ..] if !items.is_empty() => aggregate(items),
+        [] | ["help"] | ["-h"] | ["--help"] => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("错误: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn info(networks: &[&str]) -> Result<(), CidrError> {
+    for (i, text) in networks.iter().enumerate() {
+        let network = Cidr::parse(text)?;
+        if i > 0 {
+            println!();
+        }
+        println!("网段        {}", network);
+        println!("网络地址    {}", network.network());
+        println!("子网掩码    {}", network.netmask());
+        println!("广播地址    {}", network.broadcast());
+        println!(
+            "可用主机    {} - {}（{} 个）",
+            network.first_host(),
+            network.last_host(),
+            network.host_count()
+        );
+    }
+    Ok(())
+}
+
+fn contains(network: &str, addresses: &[&str]) -> Result<(), CidrError> {
+    let network = Cidr::parse(network)?;
+    for text in addresses {
+        let address = IpAddr::parse(text)?;
+        let answer = if network.contains(address) {
+            "在"
+        } else {
+            "不在"
+        };
+        println!("{} {} {} 内", address, answer, network);
+    }
+    Ok(())
+}
+
+fn split(network: &str, prefix: &str) -> Result<(), CidrError> {
+    let network = Cidr::parse(network)?;
+    let prefix = prefix
+        .trim_start_matches('/')
+        .parse()
+        .map_err(|_| CidrError::InvalidPrefix(prefix.to_string()))?;
+    let mut subnets = network.subnets(prefix)?;
+    for subnet in subnets.by_ref().take(MAX_SUBNETS) {
+        println!("{}", subnet);
+    }
+    if subnets.next().is_some() {
+        println!("……（只列出前 {} 个）", MAX_SUBNETS);
+    }
+    Ok(())
+}
+
+fn aggregate(items: &[&str]) -> Result<(), CidrError> {
+    let mut networks = Vec::new();
+    for text in items {
+        let network = if text.contains('/') {
+            Cidr::parse(text)?
+        } else {
+            Cidr::host(IpAddr::parse(text)?)
+        };
+        networks.push(network);
+    }
+    for network in Cidr::aggregate(networks) {
+        println!("{}", network);
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+//! 练习检查器：编译并测试 `exercises/` 下的练习，停在第一道未完成的练习上
+//!
+//! ```bash
+//! cargo run --bin exercise               # 检查并前进到下一道未完成的练习
+//! cargo run --bin exercise -- list
+//! cargo run --bin exercise -- check functions1
+//! cargo run --bin exercise -- hint functions1
+//! cargo run --bin exercise -- verify
+//! ```
+
+use std::env;
+use std::process::ExitCode;
+
+use rust_learning::exercises::{self, EXERCISES, Exercise, Outcome};
+use rust_learning::lessons;
+use rust_learning::progress;
+
+const USAGE: &str = "\
+用法: exercise [命令]
+
+命令:
+  next            检查练习并停在第一道未完成的练习上（默认）
+  list            列出全部练习及完成情况
+  check <name>    检查指定练习
+  hint <name>     显示指定练习的提示
+  verify          检查全部练习并汇总
+  help            显示本帮助";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let ok = match args.as_slice() {
+        [] | ["next"] => next(),
+        ["list"] => {
+            list();
+            true
+        }
+        ["check", name] => match exercises::find(name) {
+            Some(exercise) => check_and_report(exercise),
+            None => not_found(name),
+        },
+        ["hint", name] => match exercises::find(name) {
+            Some(exercise) => {
+                println!("💡 {}: {}", exercise.name, exercise.hint);
+                true
+            }
+            None => not_found(name),
+        },
+        ["verify"] => verify(),
+        ["help"] | ["-h"] | ["--help"] => {
+            println!("{}", USAGE);
+            true
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            false
+        }
+    };
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn not_found(name: &str) -> bool {
+    eprintln!("找不到练习: {}（用 `exercise list` 查看全部）", name);
+    false
+}
+
+/// 检查一道练习，第一次通过时写入学习进度
+fn run_check(exercise: &Exercise) -> Outcome {
+    let outcome = exercises::check(exercise).unwrap_or_else(|e| {
+        eprintln!("无法检查 {}: {}", exercise.name, e);
+        std::process::exit(1);
+    });
+    if outcome.is_passed() {
+        let path = progress::default_path();
+        let recorded = progress::update(&path, |p| {
+            if !p.exercises.contains_key(exercise.name) {
+                p.record_exercise(exercise.name, progress::now());
+            }
+        });
+        if let Err(e) = recorded {
+            eprintln!("无法保存进度 {}: {}", path.display(), e);
+        }
+    }
+    outcome
+}
+
+/// 按顺序检查，遇到第一道未完成的练习就停下并给出详细信息
+fn next() -> bool {
+    for exercise in EXERCISES {
+        let outcome = run_check(exercise);
+        if outcome.is_passed() {
+            println!("✅ {}", exercise.name);
+            continue;
+        }
+        report_failure(exercise, &outcome);
+        return false;
+    }
+    println!("\n🎉 全部 {} 道练习都已完成！", EXERCISES.len());
+    true
+}
+
+fn check_and_report(exercise: &Exercise) -> bool {
+    let outcome = run_check(exercise);
+    if outcome.is_passed() {
+        println!("✅ {} 通过", exercise.name);
+        if let Some(next) = EXERCISES
+            .iter()
+            .skip_while(|e| e.name != exercise.name)
+            .nth(1)
+        {
+            println!("下一题: {}（exercises/{}）", next.name, next.path);
+        }
+        true
+    } else {
+        report_failure(exercise, &outcome);
+        false
+    }
+}
+
+fn report_failure(exercise: &Exercise, outcome: &Outcome) {
+    println!("\n❌ {}：{}", exercise.name, outcome);
+    println!("文件: exercises/{}", exercise.path);
+    if let Some(lesson) = lessons::find(&exercise.lesson.to_string()) {
+        println!("课程: {}（{}）", lesson.name, lesson.source);
+    }
+    if !outcome.output().is_empty() {
+        println!("\n{}", outcome.output().trim_end());
+    }
+    println!(
+        "\n修改文件后再次运行 `cargo run --bin exercise`；需要提示可运行 `cargo run --bin exercise -- hint {}`",
+        exercise.name
+    );
+}
+
+fn list() {
+    for exercise in EXERCISES {
+        let outcome = run_check(exercise);
+        let mark = if outcome.is_passed() { "✅" } else { "⬜" };
+        println!("{} {:<18} {}", mark, exercise.name, exercise.path);
+    }
+}
+
+fn verify() -> bool {
+    let mut passed = 0;
+    for exercise in EXERCISES {
+        let outcome = run_check(exercise);
+        if outcome.is_passed() {
+            passed += 1;
+        }
+        println!("{:<18} {}", exercise.name, outcome);
+    }
+    println!("\n通过 {}/{}", passed, EXERCISES.len());
+    passed == EXERCISES.len()
+}
@@ -0,0 +1,223 @@
+//! 课程运行器：一个入口列出、运行全部课程
+//!
+//! ```bash
+//! cargo run --bin learn -- list
+//! cargo run --bin learn -- run 6        # 或 run 06_structs / run structs
+//! cargo run --bin learn -- next
+//! cargo run --bin learn -- all
+//! cargo run --bin learn -- status
+//! cargo run --bin learn -- quiz 1                        # 交互式测验
+//! cargo run --bin learn -- quiz 1 --answers answers.txt  # 从文件读取答案
+//! cargo run --bin learn -- compare java                 # 从 Java 转来的对比速查表
+//! cargo run --bin learn -- --lang en run 7               # 用英文显示（也可设置 LEARN_LANG=en）
+//! NO_COLOR=1 cargo run --bin learn -- run 7             # 不给标题上色（输出到管道或文件时也不上色）
+//! ```
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process::ExitCode;
+
+use rust_learning::ansi;
+use rust_learning::compare;
+use rust_learning::i18n::{self, Lang};
+use rust_learning::lessons::{self, LESSONS, Lesson, Section};
+use rust_learning::progress::{self, Progress};
+use rust_learning::quiz;
+
+const USAGE: &str = "\
+用法: learn [--lang zh|en] <命令>
+
+命令:
+  list [--markdown]   列出全部课程（--markdown 输出 README 表格）
+  run <n|name>        运行指定课程，例如 run 6、run 06_structs、run structs
+  next                运行第一节还没看过的课程
+  all                 按顺序运行全部课程
+  status              按学习阶段显示学习进度
+  quiz <n|name> [--answers FILE]
+                      课程测验；--answers 从文件读取答案（每题一行，输出题以空行结束）
+  compare [language]  按来源语言列出课程中的「🆚 对比」要点，不带参数时列出可选语言
+  help                显示本帮助
+
+选项:
+  --lang zh|en        课程标题和简介使用的语言，默认读取环境变量 LEARN_LANG，否则为中文";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if let Some(index) = args.iter().position(|arg| *arg == "--lang") {
+        match args.get(index + 1).and_then(|code| Lang::parse(code)) {
+            Some(lang) => i18n::set_lang(lang),
+            None => {
+                eprintln!("--lang 只支持 zh 或 en\n\n{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+        args.drain(index..index + 2);
+    }
+
+    match args.as_slice() {
+        ["list"] => list(),
+        ["list", "--markdown"] => list_markdown(),
+        ["run", query] => match lessons::find(query) {
+            Some(lesson) => run(lesson),
+            None => {
+                eprintln!("找不到课程: {}（用 `learn list` 查看全部）", query);
+                return ExitCode::FAILURE;
+            }
+        },
+        ["next"] => {
+            let next = load_progress()
+                .next_lesson()
+                .and_then(|number| LESSONS.iter().find(|lesson| lesson.number == number));
+            match next {
+                Some(lesson) => run(lesson),
+                None => println!("{}", i18n::tf("learn.all_done", &[&LESSONS.len()])),
+            }
+        }
+        ["status"] => {
+            println!("学习进度（{}）\n", progress::default_path().display());
+            print!("{}", load_progress().report());
+        }
+        ["all"] => {
+            for lesson in LESSONS {
+                run(lesson);
+            }
+        }
+        ["quiz", query, rest @ ..] => {
+            let answers = match rest {
+                [] => None,
+                ["--answers", file] => Some(*file),
+                _ => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let Some(lesson) = lessons::find(query) else {
+                eprintln!("找不到课程: {}（用 `learn list` 查看全部）", query);
+                return ExitCode::FAILURE;
+            };
+            if let Err(e) = take_quiz(lesson, answers) {
+                eprintln!("测验失败: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        ["compare", language @ ..] if language.len() <= 1 => {
+            if !show_comparisons(language.first().copied()) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [] | ["help"] | ["-h"] | ["--help"] => println!("{}", USAGE),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn list() {
+    for section in Section::ALL {
+        println!("【{}】", section.title());
+        for lesson in LESSONS.iter().filter(|lesson| lesson.section == section) {
+            println!(
+                "  {:>2}. {:<20} {} —— {}",
+                lesson.number,
+                lesson.name,
+                lesson.topic(),
+                lesson.summary()
+            );
+        }
+    }
+}
+
+/// 生成 README「代码示例索引」表格，避免手工同步
+fn list_markdown() {
+    println!("{}", i18n::t("learn.table_header"));
+    println!("|------|------|----------|");
+    for lesson in LESSONS {
+        println!(
+            "| [{}]({}) | {} | {} |",
+            lesson.name,
+            lesson.source,
+            lesson.topic(),
+            lesson.summary()
+        );
+    }
+}
+
+fn run(lesson: &Lesson) {
+    let title = i18n::tf(
+        "learn.banner",
+        &[&lesson.number, &lesson.topic(), &lesson.source],
+    );
+    println!("\n{}\n", ansi::banner(&format!("########## {} ##########", title)));
+    (lesson.run)();
+
+    let path = progress::default_path();
+    if let Err(e) = progress::update(&path, |p| p.record_lesson(lesson.number, progress::now())) {
+        eprintln!("无法保存进度 {}: {}", path.display(), e);
+    }
+}
+
+/// 进行一次测验并记录成绩；`answers` 为答案文件路径，否则从标准输入读取
+fn take_quiz(lesson: &Lesson, answers: Option<&str>) -> io::Result<()> {
+    let questions = quiz::load(lesson)?;
+    println!(
+        "第 {} 课测验：{}（共 {} 题）",
+        lesson.number,
+        lesson.topic(),
+        questions.len()
+    );
+
+    let mut out = io::stdout().lock();
+    let result = match answers {
+        Some(file) => {
+            let mut input = BufReader::new(File::open(file)?);
+            quiz::run(&questions, lesson.source, &mut input, &mut out)?
+        }
+        None => quiz::run(&questions, lesson.source, &mut io::stdin().lock(), &mut out)?,
+    };
+
+    progress::update(&progress::default_path(), |p| {
+        p.record_quiz(lesson.number, result.correct, result.total, progress::now())
+    })
+}
+
+/// 打印某种语言的对比速查表；`language` 为空时列出全部语言
+fn show_comparisons(language: Option<&str>) -> bool {
+    let comparisons = match compare::all() {
+        Ok(comparisons) => comparisons,
+        Err(e) => {
+            eprintln!("无法读取课程源码: {}", e);
+            return false;
+        }
+    };
+    let languages = compare::languages(&comparisons);
+    let sheet = language.and_then(|language| compare::cheat_sheet(&comparisons, language));
+    match (language, sheet) {
+        (_, Some(sheet)) => {
+            print!("{}", sheet);
+            true
+        }
+        (language, None) => {
+            if let Some(language) = language {
+                eprintln!("课程中没有与 {} 的对比\n", language);
+            }
+            println!("可选的语言:");
+            for (language, count) in languages {
+                println!("  {:<8} {} 条", language, count);
+            }
+            language.is_none()
+        }
+    }
+}
+
+/// 读取进度；文件损坏时提示并从空进度开始，不影响运行课程
+fn load_progress() -> Progress {
+    let path = progress::default_path();
+    Progress::load(&path).unwrap_or_else(|e| {
+        eprintln!("无法读取进度文件 {}: {}", path.display(), e);
+        Progress::default()
+    })
+}
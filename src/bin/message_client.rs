@@ -0,0 +1,103 @@
+//! 消息客户端：把命令行参数组成第 7 课的 `Message` 发给 message_server
+//!
+//! ```bash
+//! cargo run --bin message_client -- move 10 20 write "Hello Rust!" color 255 0 0 quit
+//! cargo run --bin message_client -- --port 9000 write hi
+//! ```
+
+use std::env;
+use std::net::Ipv4Addr;
+use std::process::ExitCode;
+
+use rust_learning::enums::Message;
+use rust_learning::messaging::{Client, DEFAULT_PORT};
+use rust_learning::structs::Color;
+
+const USAGE: &str = "\
+用法: message_client [--port PORT] <消息>...
+
+消息:
+  quit              让服务端关闭这个连接
+  move X Y          Message::Move { x: X, y: Y }
+  write TEXT        Message::Write(TEXT)，含空格时请用引号括起来
+  color R G B       Message::ChangeColor(Color(R, G, B))
+
+选项:
+  --port PORT       服务端在 127.0.0.1 上的端口，默认 7878";
+
+/// 把参数依次解析成消息
+fn parse_messages(mut args: &[&str]) -> Result<Vec<Message>, String> {
+    fn number<T: std::str::FromStr>(text: &str) -> Result<T, String> {
+        text.parse()
+            .map_err(|_| format!("不是合法的数字: {}", text))
+    }
+
+    let mut messages = Vec::new();
+    while !args.is_empty() {
+        let (message, rest) = match args {
+            ["quit", rest @ ..] => (Message::Quit, rest),
+            ["move", x, y, rest @ ..] => (
+                Message::Move {
+                    x: number(x)?,
+                    y: number(y)?,
+                },
+                rest,
+            ),
+            ["write", text, rest @ ..] => (Message::Write(text.to_string()), rest),
+            ["color", r, g, b, rest @ ..] => (
+                Message::ChangeColor(Color(number(r)?, number(g)?, number(b)?)),
+                rest,
+            ),
+            [other, ..] => return Err(format!("无法识别的消息或参数不足: {}", other)),
+            [] => unreachable!(),
+        };
+        messages.push(message);
+        args = rest;
+    }
+    Ok(messages)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let mut port = DEFAULT_PORT;
+    if let ["--port", value, ..] = args.as_slice() {
+        match value.parse() {
+            Ok(value) => port = value,
+            Err(_) => {
+                eprintln!("端口不合法: {}\n\n{}", value, USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+        args.drain(..2);
+    }
+    if matches!(args.as_slice(), [] | ["help" | "-h" | "--help"]) {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let messages = match parse_messages(&args) {
+        Ok(messages) => messages,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut client = match Client::connect((Ipv4Addr::LOCALHOST, port)) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("无法连接 127.0.0.1:{}: {}（服务端启动了吗？）", port, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    for message in &messages {
+        match client.send(message) {
+            Ok(n) => println!("#{} {:?} 已确认", n, message),
+            Err(e) => {
+                eprintln!("发送 {:?} 失败: {}", message, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
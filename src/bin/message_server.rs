@@ -0,0 +1,94 @@
+//! 消息服务端：接收客户端发来的第 7 课 `Message`，打印出来并逐条确认
+//!
+//! ```bash
+//! cargo run --bin message_server                # 监听 127.0.0.1:7878
+//! cargo run --bin message_server -- --port 9000
+//! ```
+
+use std::env;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::process::ExitCode;
+
+use rust_learning::enums::Handler;
+use rust_learning::messaging::{Closed, DEFAULT_PORT, Server};
+use rust_learning::structs::Color;
+
+const USAGE: &str = "\
+用法: message_server [--port PORT]
+
+选项:
+  --port PORT     监听 127.0.0.1 上的端口，默认 7878";
+
+/// 打印收到的消息，行首带上客户端地址，区分同时连接的多个客户端
+struct Logger {
+    peer: SocketAddr,
+}
+
+impl Handler for Logger {
+    fn quit(&mut self) {
+        println!("[{}] quit", self.peer);
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        println!("[{}] move x={}, y={}", self.peer, x, y);
+    }
+
+    fn write(&mut self, text: &str) {
+        println!("[{}] write {:?}", self.peer, text);
+    }
+
+    fn change_color(&mut self, color: Color) {
+        println!("[{}] color {}", self.peer, color);
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let port = match args.as_slice() {
+        [] => DEFAULT_PORT,
+        ["--port", port] => match port.parse() {
+            Ok(port) => port,
+            Err(_) => {
+                eprintln!("端口不合法: {}\n\n{}", port, USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        ["help" | "-h" | "--help"] => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let server = match Server::bind((Ipv4Addr::LOCALHOST, port)) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("无法监听端口 {}: {}", port, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match server.local_addr() {
+        Ok(addr) => println!("正在监听 {}，按 Ctrl+C 退出", addr),
+        Err(e) => eprintln!("无法获取监听地址: {}", e),
+    }
+
+    let on_close = |peer: SocketAddr, closed| match closed {
+        Ok(Closed::Quit) => println!("[{}] 客户端发送 Quit，已关闭连接", peer),
+        Ok(Closed::Eof { pending: 0 }) => println!("[{}] 客户端断开", peer),
+        Ok(Closed::Eof { pending }) => {
+            println!("[{}] 客户端断开，丢弃不完整的 {} 字节", peer, pending)
+        }
+        Ok(Closed::Corrupt(e)) => println!("[{}] 数据无法解码，已关闭连接: {}", peer, e),
+        Err(e) => println!("[{}] 连接出错: {}", peer, e),
+    };
+    server.run(
+        |peer| Logger { peer },
+        on_close,
+        |e| eprintln!("接受连接失败，稍后重试: {}", e),
+    );
+    ExitCode::SUCCESS
+}
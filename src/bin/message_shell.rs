@@ -0,0 +1,71 @@
+//! 消息命令行：逐行读取 `move 10 20` 这样的命令，解析成第 7 课的 `Message` 并调用 `call`
+//!
+//! ```bash
+//! cargo run --bin message_shell                            # 交互式输入，quit 退出
+//! printf 'move 1 2\nwrite "Hi\\n"\n' | cargo run --bin message_shell
+//! ```
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use rust_learning::command::{self, COMMANDS};
+use rust_learning::enums::Message;
+
+const PROMPT: &str = "> ";
+
+fn main() -> ExitCode {
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        println!(
+            "输入命令（{}），空行和 # 开头的行会被忽略，Ctrl+D 退出",
+            COMMANDS.join("、")
+        );
+    }
+
+    let mut failed = false;
+    let mut lines = io::stdin().lock().lines();
+    for number in 1.. {
+        if interactive {
+            print!("{}", PROMPT);
+            // 提示符没有换行，需要手动刷新才能显示出来
+            let _ = io::stdout().flush();
+        }
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(e)) => {
+                eprintln!("读取输入失败: {}", e);
+                return ExitCode::FAILURE;
+            }
+            None => break,
+        };
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        match command::parse(&line) {
+            Ok(message) => {
+                message.call();
+                if message == Message::Quit {
+                    break;
+                }
+            }
+            Err(e) => {
+                failed = true;
+                // 交互时命令就在提示符后面，箭头对齐到出错的列；读文件或管道时先把出错的行打出来
+                if interactive {
+                    eprintln!("{}^", " ".repeat(PROMPT.len() + e.column - 1));
+                    eprintln!("{}", e);
+                } else {
+                    eprintln!("{}", line);
+                    eprintln!("{}^", " ".repeat(e.column - 1));
+                    eprintln!("第 {} 行 {}", number, e);
+                }
+            }
+        }
+    }
+    if failed && !interactive {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
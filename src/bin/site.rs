@@ -0,0 +1,71 @@
+//! 静态站点生成器：把全部课程渲染成可离线浏览的 HTML
+//!
+//! ```bash
+//! cargo run --bin site                         # 输出到 target/site
+//! cargo run --bin site -- --out public --lang en
+//! ```
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use rust_learning::i18n::{self, Lang};
+use rust_learning::site;
+
+const USAGE: &str = "\
+用法: site [--out DIR] [--lang zh|en]
+
+选项:
+  --out DIR       输出目录，默认 target/site
+  --lang zh|en    页面语言，默认读取环境变量 LEARN_LANG，否则为中文";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut out = root.join("target/site");
+    let mut rest = args.as_slice();
+    loop {
+        match rest {
+            [] => break,
+            ["--out", dir, tail @ ..] => {
+                out = PathBuf::from(dir);
+                rest = tail;
+            }
+            ["--lang", code, tail @ ..] => match Lang::parse(code) {
+                Some(lang) => {
+                    i18n::set_lang(lang);
+                    rest = tail;
+                }
+                None => {
+                    eprintln!("--lang 只支持 zh 或 en\n\n{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            ["help" | "-h" | "--help", ..] => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => {
+                eprintln!("{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match site::build(root, &out) {
+        Ok(files) => {
+            println!(
+                "已生成 {} 个文件: {}",
+                files.len(),
+                out.join("index.html").display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("生成站点失败: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
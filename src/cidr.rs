@@ -0,0 +1,368 @@
+//! CIDR 网段：在第 7 课的 `IpAddr` 之上做子网计算
+//!
+//! `10.0.0.0/8`、`fe80::/64` 这样的写法表示「前 N 位相同的一段地址」，N 是前缀长度。
+//! [`Cidr`] 支持：
+//!
+//! - 解析和显示，网络地址的主机位必须为 0（`10.1.2.3/8` 会提示应写成 `10.0.0.0/8`）
+//! - 网络地址、广播地址（IPv6 没有广播，这里指网段的最后一个地址）、子网掩码、可用主机数
+//! - 判断地址或网段是否在网段内
+//! - 按更长的前缀切分子网，以及把一组地址/网段合并成最少的网段（聚合）
+//!
+//! IPv4 和 IPv6 用同一套代码计算：地址先转成 `u128`，IPv4 只用低 32 位。
+//!
+//! ```
+//! use rust_learning::cidr::Cidr;
+//!
+//! let net: Cidr = "192.168.1.0/24".parse().unwrap();
+//! assert_eq!(net.broadcast().to_string(), "192.168.1.255");
+//! assert_eq!(net.host_count(), 254);
+//! assert!(net.contains("192.168.1.77".parse().unwrap()));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::enums::IpAddr;
+use crate::ip::{IpError, Ipv4, Ipv6};
+
+/// 网段解析或计算错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CidrError {
+    /// 没有 `/前缀长度`
+    MissingPrefix(String),
+    /// 前缀长度不是十进制数
+    InvalidPrefix(String),
+    /// 前缀长度超过地址位数（IPv4 为 32，IPv6 为 128）
+    PrefixTooLong {
+        prefix: u32,
+        max: u8,
+    },
+    /// 地址的主机位不为 0，附带正确的网段
+    HostBitsSet(Cidr),
+    /// 切分子网时新前缀比原前缀短，或超过地址位数
+    InvalidSplit {
+        prefix: u8,
+        new_prefix: u8,
+    },
+    Address(IpError),
+}
+
+impl fmt::Display for CidrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CidrError::MissingPrefix(text) => write!(f, "{:?} 缺少 /前缀长度", text),
+            CidrError::InvalidPrefix(text) => write!(f, "前缀长度 {:?} 不是数字", text),
+            CidrError::PrefixTooLong { prefix, max } => {
+                write!(f, "前缀长度 {} 超过地址位数 {}", prefix, max)
+            }
+            CidrError::HostBitsSet(network) => {
+                write!(f, "主机位不为 0，网段应写成 {}", network)
+            }
+            CidrError::InvalidSplit { prefix, new_prefix } => {
+                write!(f, "/{} 不能切分成 /{} 的子网", prefix, new_prefix)
+            }
+            CidrError::Address(e) => write!(f, "地址不合法: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CidrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CidrError::Address(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<IpError> for CidrError {
+    fn from(e: IpError) -> CidrError {
+        CidrError::Address(e)
+    }
+}
+
+// ===== IpAddr 与 u128 互转 =====
+
+/// 地址位数：IPv4 为 32，IPv6 为 128
+fn width(ip: IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn to_bits(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u128::from(ip.to_bits()),
+        IpAddr::V6(ip) => ip.to_bits(),
+    }
+}
+
+/// 按 `like` 的地址族把整数转回地址
+fn from_bits(like: IpAddr, bits: u128) -> IpAddr {
+    match like {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4::from_bits(bits as u32)),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6::from_bits(bits)),
+    }
+}
+
+/// 低 `bits` 位全为 1
+fn low_mask(bits: u8) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// 一个网段：网络地址和前缀长度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cidr {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// 用任意地址和前缀长度构造网段，主机位会被清零
+    pub fn new(address: IpAddr, prefix: u8) -> Result<Cidr, CidrError> {
+        let max = width(address);
+        if prefix > max {
+            return Err(CidrError::PrefixTooLong {
+                prefix: u32::from(prefix),
+                max,
+            });
+        }
+        let host_bits = low_mask(max - prefix);
+        Ok(Cidr {
+            network: from_bits(address, to_bits(address) & !host_bits),
+            prefix,
+        })
+    }
+
+    /// 解析 `地址/前缀长度`，地址的主机位必须为 0
+    pub fn parse(input: &str) -> Result<Cidr, CidrError> {
+        let (address, prefix) = input
+            .split_once('/')
+            .ok_or_else(|| CidrError::MissingPrefix(input.to_string()))?;
+        let address = IpAddr::parse(address)?;
+        if prefix.is_empty() || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(CidrError::InvalidPrefix(prefix.to_string()));
+        }
+        let prefix: u32 = prefix
+            .parse()
+            .map_err(|_| CidrError::InvalidPrefix(prefix.to_string()))?;
+        let max = width(address);
+        if prefix > u32::from(max) {
+            return Err(CidrError::PrefixTooLong { prefix, max });
+        }
+        let cidr = Cidr::new(address, prefix as u8)?;
+        if cidr.network != address {
+            return Err(CidrError::HostBitsSet(cidr));
+        }
+        Ok(cidr)
+    }
+
+    /// 只包含一个地址的网段（IPv4 为 /32，IPv6 为 /128）
+    pub fn host(address: IpAddr) -> Cidr {
+        Cidr {
+            network: address,
+            prefix: width(address),
+        }
+    }
+
+    pub fn network(&self) -> IpAddr {
+        self.network
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    fn host_bits(&self) -> u8 {
+        width(self.network) - self.prefix
+    }
+
+    /// 子网掩码，例如 /24 是 `255.255.255.0`
+    pub fn netmask(&self) -> IpAddr {
+        let all = low_mask(width(self.network));
+        from_bits(self.network, all & !low_mask(self.host_bits()))
+    }
+
+    /// 网段的最后一个地址；IPv4 中就是广播地址
+    pub fn broadcast(&self) -> IpAddr {
+        from_bits(
+            self.network,
+            to_bits(self.network) | low_mask(self.host_bits()),
+        )
+    }
+
+    /// 网段中的地址总数；`::/0` 有 2^128 个地址，超出 `u128`，返回 `u128::MAX`
+    pub fn size(&self) -> u128 {
+        low_mask(self.host_bits()).saturating_add(1)
+    }
+
+    /// 可分配给主机的地址数
+    ///
+    /// IPv4 要去掉网络地址和广播地址，但 /31（点对点链路，RFC 3021）和 /32 例外；
+    /// IPv6 没有广播地址，全部可用。
+    pub fn host_count(&self) -> u128 {
+        match (self.network, self.prefix) {
+            (IpAddr::V4(_), 0..=30) => self.size() - 2,
+            _ => self.size(),
+        }
+    }
+
+    /// 第一个可分配的主机地址
+    pub fn first_host(&self) -> IpAddr {
+        match (self.network, self.prefix) {
+            (IpAddr::V4(_), 0..=30) => from_bits(self.network, to_bits(self.network) + 1),
+            _ => self.network,
+        }
+    }
+
+    /// 最后一个可分配的主机地址
+    pub fn last_host(&self) -> IpAddr {
+        let last = self.broadcast();
+        match (last, self.prefix) {
+            (IpAddr::V4(_), 0..=30) => from_bits(last, to_bits(last) - 1),
+            _ => last,
+        }
+    }
+
+    /// 地址是否在网段内；地址族不同时总是 `false`
+    pub fn contains(&self, address: IpAddr) -> bool {
+        width(address) == width(self.network)
+            && to_bits(address) & !low_mask(self.host_bits()) == to_bits(self.network)
+    }
+
+    /// `other` 是否整个落在这个网段内（包括两者相同）
+    pub fn covers(&self, other: &Cidr) -> bool {
+        other.prefix >= self.prefix && self.contains(other.network)
+    }
+
+    /// 前缀短一位的上级网段；/0 没有上级
+    pub fn supernet(&self) -> Option<Cidr> {
+        let prefix = self.prefix.checked_sub(1)?;
+        Cidr::new(self.network, prefix).ok()
+    }
+
+    /// 按前缀长度 `new_prefix` 切分成子网，按地址从小到大依次产生
+    pub fn subnets(&self, new_prefix: u8) -> Result<Subnets, CidrError> {
+        if new_prefix < self.prefix || new_prefix > width(self.network) {
+            return Err(CidrError::InvalidSplit {
+                prefix: self.prefix,
+                new_prefix,
+            });
+        }
+        let step_bits = width(self.network) - new_prefix;
+        let start = to_bits(self.network);
+        Ok(Subnets {
+            like: self.network,
+            prefix: new_prefix,
+            next: Some(start),
+            last: (start | low_mask(self.host_bits())) & !low_mask(step_bits),
+            step: low_mask(step_bits).wrapping_add(1),
+        })
+    }
+
+    /// 把一组网段合并成覆盖完全相同地址的最少网段，IPv4 在前，各自按地址排序
+    ///
+    /// 重叠或相邻的网段会被合并，例如 `10.0.0.0/25` 和 `10.0.0.128/25` 合并成 `10.0.0.0/24`。
+    /// 单个地址可以先用 [`Cidr::host`] 或 `Cidr::from` 转成网段。
+    pub fn aggregate(networks: impl IntoIterator<Item = Cidr>) -> Vec<Cidr> {
+        // 每个网段转成闭区间 [first, last]，IPv4 排在 IPv6 前面
+        let mut ranges: Vec<(u8, u128, u128, IpAddr)> = networks
+            .into_iter()
+            .map(|cidr| {
+                let first = to_bits(cidr.network);
+                let last = first | low_mask(cidr.host_bits());
+                (width(cidr.network), first, last, cidr.network)
+            })
+            .collect();
+        ranges.sort_by_key(|&(width, first, last, _)| (width, first, last));
+
+        let mut merged: Vec<(u128, u128, IpAddr)> = Vec::new();
+        for (_, first, last, like) in ranges {
+            match merged.last_mut() {
+                Some((_, end, prev))
+                    if width(*prev) == width(like) && first <= end.saturating_add(1) =>
+                {
+                    *end = (*end).max(last);
+                }
+                _ => merged.push((first, last, like)),
+            }
+        }
+
+        let mut result = Vec::new();
+        for (first, last, like) in merged {
+            range_to_cidrs(like, first, last, &mut result);
+        }
+        result
+    }
+}
+
+/// 把闭区间 `[first, last]` 拆成最少的对齐网段
+fn range_to_cidrs(like: IpAddr, mut first: u128, last: u128, out: &mut Vec<Cidr>) {
+    let max = width(like);
+    loop {
+        // 从 first 开始、按自身大小对齐、又不超出 last 的最大块
+        let mut bits = (first.trailing_zeros() as u8).min(max);
+        while bits > 0 && first | low_mask(bits) > last {
+            bits -= 1;
+        }
+        out.push(Cidr {
+            network: from_bits(like, first),
+            prefix: max - bits,
+        });
+        let end = first | low_mask(bits);
+        if end >= last {
+            break;
+        }
+        first = end + 1;
+    }
+}
+
+/// [`Cidr::subnets`] 返回的迭代器
+#[derive(Debug, Clone)]
+pub struct Subnets {
+    like: IpAddr,
+    prefix: u8,
+    next: Option<u128>,
+    /// 最后一个子网的网络地址
+    last: u128,
+    /// 相邻子网网络地址之差；`::/0` 切成 `/0` 时是 2^128，回绕成 0，但只会产生一个子网
+    step: u128,
+}
+
+impl Iterator for Subnets {
+    type Item = Cidr;
+
+    fn next(&mut self) -> Option<Cidr> {
+        let current = self.next?;
+        self.next = (current != self.last).then(|| current.wrapping_add(self.step));
+        Some(Cidr {
+            network: from_bits(self.like, current),
+            prefix: self.prefix,
+        })
+    }
+}
+
+impl From<IpAddr> for Cidr {
+    fn from(address: IpAddr) -> Cidr {
+        Cidr::host(address)
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix)
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = CidrError;
+
+    fn from_str(s: &str) -> Result<Cidr, CidrError> {
+        Cidr::parse(s)
+    }
+}
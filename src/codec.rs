@@ -0,0 +1,222 @@
+//! 二进制编解码：把第 7 课的 `Message` 变成字节，以便写入文件或通过网络发送
+//!
+//! 每条消息以一个标签字节开头，后面是该变体的字段，多字节整数一律大端序（网络字节序）：
+//!
+//! | 变体 | 标签 | 字段 |
+//! |------|------|------|
+//! | `Quit` | `0x00` | 无 |
+//! | `Move { x, y }` | `0x01` | `x: i32`、`y: i32` |
+//! | `Write(text)` | `0x02` | 长度 `u32`，随后是这么多字节的 UTF-8 文字 |
+//! | `ChangeColor(Color(r, g, b))` | `0x03` | `r`、`g`、`b` 各一个字节 |
+//!
+//! 消息之间没有分隔符，一条结束紧接着下一条。读网络数据时一次 `read` 可能只拿到半条消息，
+//! [`Decoder`] 会先缓存不完整的部分，等后续数据到达后再解出。
+//!
+//! ```
+//! use rust_learning::enums::Message;
+//!
+//! let bytes = Message::Move { x: 10, y: -1 }.encode().unwrap();
+//! assert_eq!(bytes, [0x01, 0, 0, 0, 10, 0xff, 0xff, 0xff, 0xff]);
+//! assert_eq!(Message::decode(&bytes), Ok(Message::Move { x: 10, y: -1 }));
+//! ```
+
+use std::fmt;
+use std::str::{self, Utf8Error};
+
+use crate::enums::Message;
+use crate::structs::Color;
+
+const TAG_QUIT: u8 = 0x00;
+const TAG_MOVE: u8 = 0x01;
+const TAG_WRITE: u8 = 0x02;
+const TAG_CHANGE_COLOR: u8 = 0x03;
+
+/// `Write` 文字的最大字节数；解码时拒绝更长的长度，避免一个错误的长度字段让接收方一直等待或占用大量内存
+pub const MAX_TEXT_LEN: usize = 1 << 20;
+
+/// 编解码错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodecError {
+    UnknownTag(u8),
+    /// 数据不完整：至少需要 `needed` 字节，实际只有 `available` 字节
+    Truncated {
+        needed: usize,
+        available: usize,
+    },
+    InvalidUtf8(Utf8Error),
+    /// `Write` 的文字超过 [`MAX_TEXT_LEN`]
+    TextTooLong(usize),
+    /// 一条完整的消息之后还有多余的字节
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodecError::UnknownTag(tag) => write!(f, "未知的消息标签 0x{:02x}", tag),
+            CodecError::Truncated { needed, available } => {
+                write!(
+                    f,
+                    "数据不完整：至少需要 {} 字节，只有 {} 字节",
+                    needed, available
+                )
+            }
+            CodecError::InvalidUtf8(e) => write!(f, "文字不是合法的 UTF-8: {}", e),
+            CodecError::TextTooLong(len) => {
+                write!(f, "文字长 {} 字节，超过上限 {}", len, MAX_TEXT_LEN)
+            }
+            CodecError::TrailingBytes(count) => write!(f, "消息之后多出 {} 字节", count),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CodecError::InvalidUtf8(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Message {
+    /// 编码成字节；只有 `Write` 的文字超过 [`MAX_TEXT_LEN`] 时会失败
+    pub fn encode(&self) -> Result<Vec<u8>, CodecError> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// 把编码追加到 `out` 末尾，便于把多条消息写进同一个缓冲区；失败时 `out` 不变
+    pub fn encode_into(&self, out: &mut Vec<u8>) -> Result<(), CodecError> {
+        match self {
+            Message::Quit => out.push(TAG_QUIT),
+            Message::Move { x, y } => {
+                out.push(TAG_MOVE);
+                out.extend_from_slice(&x.to_be_bytes());
+                out.extend_from_slice(&y.to_be_bytes());
+            }
+            Message::Write(text) => {
+                if text.len() > MAX_TEXT_LEN {
+                    return Err(CodecError::TextTooLong(text.len()));
+                }
+                out.push(TAG_WRITE);
+                out.extend_from_slice(&(text.len() as u32).to_be_bytes());
+                out.extend_from_slice(text.as_bytes());
+            }
+            Message::ChangeColor(Color(r, g, b)) => {
+                out.extend_from_slice(&[TAG_CHANGE_COLOR, *r, *g, *b]);
+            }
+        }
+        Ok(())
+    }
+
+    /// 解码正好一条消息，`bytes` 中不能有多余的字节
+    pub fn decode(bytes: &[u8]) -> Result<Message, CodecError> {
+        let (message, used) = decode_prefix(bytes)?;
+        match bytes.len() - used {
+            0 => Ok(message),
+            extra => Err(CodecError::TrailingBytes(extra)),
+        }
+    }
+}
+
+/// 按顺序读取字段的游标，数据不够时返回 `Truncated`
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CodecError> {
+        let end = self.pos + len;
+        let slice = self.bytes.get(self.pos..end).ok_or(CodecError::Truncated {
+            needed: end,
+            available: self.bytes.len(),
+        })?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CodecError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
+
+/// 从 `bytes` 开头解码一条消息，返回消息和它占用的字节数；后面多余的字节不管
+pub fn decode_prefix(bytes: &[u8]) -> Result<(Message, usize), CodecError> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let [tag] = reader.array()?;
+    let message = match tag {
+        TAG_QUIT => Message::Quit,
+        TAG_MOVE => {
+            let x = i32::from_be_bytes(reader.array()?);
+            let y = i32::from_be_bytes(reader.array()?);
+            Message::Move { x, y }
+        }
+        TAG_WRITE => {
+            let len = u32::from_be_bytes(reader.array()?) as usize;
+            if len > MAX_TEXT_LEN {
+                return Err(CodecError::TextTooLong(len));
+            }
+            let text = str::from_utf8(reader.take(len)?).map_err(CodecError::InvalidUtf8)?;
+            Message::Write(text.to_string())
+        }
+        TAG_CHANGE_COLOR => {
+            let [r, g, b] = reader.array()?;
+            Message::ChangeColor(Color(r, g, b))
+        }
+        tag => return Err(CodecError::UnknownTag(tag)),
+    };
+    Ok((message, reader.pos))
+}
+
+/// 流式解码器：数据可以分成任意大小的块陆续送进来
+///
+/// ```
+/// use rust_learning::codec::Decoder;
+/// use rust_learning::enums::Message;
+///
+/// let bytes = Message::Write("hi".to_string()).encode().unwrap();
+/// let mut decoder = Decoder::new();
+/// decoder.feed(&bytes[..3]);
+/// assert_eq!(decoder.next_message(), Ok(None));
+/// decoder.feed(&bytes[3..]);
+/// assert_eq!(decoder.next_message(), Ok(Some(Message::Write("hi".to_string()))));
+/// ```
+#[derive(Debug, Default)]
+pub struct Decoder {
+    buffer: Vec<u8>,
+}
+
+impl Decoder {
+    pub fn new() -> Decoder {
+        Decoder::default()
+    }
+
+    /// 追加收到的数据
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// 取出下一条完整的消息；数据还不够一条时返回 `Ok(None)`
+    ///
+    /// 出错说明数据流已经损坏（之后的字节无法再对齐到消息边界），调用方应当放弃这个数据流。
+    pub fn next_message(&mut self) -> Result<Option<Message>, CodecError> {
+        match decode_prefix(&self.buffer) {
+            Ok((message, used)) => {
+                self.buffer.drain(..used);
+                Ok(Some(message))
+            }
+            Err(CodecError::Truncated { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 缓存中尚未解码的字节数
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+}
@@ -0,0 +1,339 @@
+//! 颜色：为第 6 课的元组结构体 `Color(r, g, b)` 实现常用的颜色运算
+//!
+//! 类型本身定义在 `src/structs.rs`（课程里演示元组结构体），这里只是给它加上 `impl`：
+//! 同一个 crate 里，可以在任意模块为自己的类型实现方法和 trait。
+//!
+//! - 十六进制：`#ff8800`、`#f80` 与 `Color` 互相转换
+//! - HSL / HSV：和色相、饱和度、亮度表示互相转换
+//! - 混合：按透明度把一种颜色叠在另一种上（alpha blending）
+//! - 对比度：按 WCAG 2.x 计算相对亮度和对比度，判断文字是否易读
+//! - 名称：`orange`、`navy` 等 CSS 颜色名
+//!
+//! ```
+//! use rust_learning::structs::Color;
+//!
+//! let orange: Color = "#ff8800".parse().unwrap();
+//! assert_eq!(orange, Color(255, 136, 0));
+//! assert_eq!(Color::named("navy"), Some(Color(0, 0, 128)));
+//! assert!(Color::BLACK.contrast_ratio(Color::WHITE) > 20.0);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::i18n::tf;
+use crate::structs::Color;
+
+/// 颜色解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    /// 不是 `#rgb` 或 `#rrggbb` 形式
+    InvalidHex(String),
+    /// 第 `index` 个通道（0 = r, 1 = g, 2 = b）不在 0..=255 之间
+    ChannelOutOfRange {
+        index: usize,
+        value: i32,
+    },
+    UnknownName(String),
+}
+
+/// 错误文字来自消息目录（`color.*`），随 `LEARN_LANG` 切换中英文
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            ColorError::InvalidHex(text) => tf("color.invalid_hex", &[&format!("{:?}", text)]),
+            ColorError::ChannelOutOfRange { index, value } => tf(
+                "color.channel_out_of_range",
+                &[&["r", "g", "b"][*index], value],
+            ),
+            ColorError::UnknownName(name) => tf("color.unknown_name", &[name]),
+        };
+        f.write_str(&text)
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+/// HSL 表示：色相 `h` 为 0..360 度，饱和度 `s` 和亮度 `l` 为 0..=1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+/// HSV 表示：色相 `h` 为 0..360 度，饱和度 `s` 和明度 `v` 为 0..=1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+/// WCAG 2.x 对比度等级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Contrast {
+    /// 低于 3:1
+    Fail,
+    /// 至少 3:1，只适合大号文字
+    AaLarge,
+    /// 至少 4.5:1
+    Aa,
+    /// 至少 7:1
+    Aaa,
+}
+
+impl Contrast {
+    pub fn from_ratio(ratio: f64) -> Contrast {
+        if ratio >= 7.0 {
+            Contrast::Aaa
+        } else if ratio >= 4.5 {
+            Contrast::Aa
+        } else if ratio >= 3.0 {
+            Contrast::AaLarge
+        } else {
+            Contrast::Fail
+        }
+    }
+}
+
+/// CSS 颜色名；同一种颜色有多个名字时，排在前面的是 [`Color::name`] 返回的名字
+const NAMED: [(&str, Color); 26] = [
+    ("black", Color(0, 0, 0)),
+    ("silver", Color(192, 192, 192)),
+    ("gray", Color(128, 128, 128)),
+    ("grey", Color(128, 128, 128)),
+    ("white", Color(255, 255, 255)),
+    ("maroon", Color(128, 0, 0)),
+    ("red", Color(255, 0, 0)),
+    ("purple", Color(128, 0, 128)),
+    ("fuchsia", Color(255, 0, 255)),
+    ("magenta", Color(255, 0, 255)),
+    ("green", Color(0, 128, 0)),
+    ("lime", Color(0, 255, 0)),
+    ("olive", Color(128, 128, 0)),
+    ("yellow", Color(255, 255, 0)),
+    ("navy", Color(0, 0, 128)),
+    ("blue", Color(0, 0, 255)),
+    ("teal", Color(0, 128, 128)),
+    ("aqua", Color(0, 255, 255)),
+    ("cyan", Color(0, 255, 255)),
+    ("orange", Color(255, 165, 0)),
+    ("pink", Color(255, 192, 203)),
+    ("brown", Color(165, 42, 42)),
+    ("gold", Color(255, 215, 0)),
+    ("indigo", Color(75, 0, 130)),
+    ("violet", Color(238, 130, 238)),
+    ("rebeccapurple", Color(102, 51, 153)),
+];
+
+/// 0..=1 的分量转换为 0..=255 的通道
+fn channel(value: f64) -> u8 {
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// 把 0..=1 的 [r, g, b] 换算为色相（度）
+fn hue(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    if delta == 0.0 {
+        return 0.0;
+    }
+    let h = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    h * 60.0
+}
+
+/// HSL/HSV 转回 RGB 的公共部分：`chroma` 为色度，`m` 为三个通道共同加上的量
+fn from_chroma(h: f64, chroma: f64, m: f64) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Color(channel(r + m), channel(g + m), channel(b + m))
+}
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(255, 255, 255);
+
+    /// 解析 `#rrggbb` 或 `#rgb`，`#` 可以省略，不区分大小写
+    pub fn from_hex(text: &str) -> Result<Color, ColorError> {
+        let invalid = || ColorError::InvalidHex(text.to_string());
+        let digits = text.trim().strip_prefix('#').unwrap_or(text.trim());
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let value = |hex: &str| u8::from_str_radix(hex, 16).map_err(|_| invalid());
+        match digits.len() {
+            6 => Ok(Color(
+                value(&digits[0..2])?,
+                value(&digits[2..4])?,
+                value(&digits[4..6])?,
+            )),
+            // #f80 是 #ff8800 的简写：每一位重复一次
+            3 => Ok(Color(
+                value(&digits[0..1])? * 17,
+                value(&digits[1..2])? * 17,
+                value(&digits[2..3])? * 17,
+            )),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// 小写的 `#rrggbb`
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    /// 按 CSS 颜色名查找，不区分大小写
+    pub fn named(name: &str) -> Option<Color> {
+        let name = name.trim();
+        NAMED
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|&(_, color)| color)
+    }
+
+    /// 这个颜色的 CSS 名字（如果有）
+    pub fn name(self) -> Option<&'static str> {
+        NAMED
+            .iter()
+            .find(|(_, color)| *color == self)
+            .map(|&(name, _)| name)
+    }
+
+    fn unit(self) -> (f64, f64, f64) {
+        (
+            f64::from(self.0) / 255.0,
+            f64::from(self.1) / 255.0,
+            f64::from(self.2) / 255.0,
+        )
+    }
+
+    pub fn to_hsl(self) -> Hsl {
+        let (r, g, b) = self.unit();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl {
+            h: hue(r, g, b, max, delta),
+            s,
+            l,
+        }
+    }
+
+    /// 从 HSL 转换；`s`、`l` 会被限制在 0..=1，色相按 360 度取模
+    pub fn from_hsl(hsl: Hsl) -> Color {
+        let s = hsl.s.clamp(0.0, 1.0);
+        let l = hsl.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_chroma(hsl.h, chroma, l - chroma / 2.0)
+    }
+
+    pub fn to_hsv(self) -> Hsv {
+        let (r, g, b) = self.unit();
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        Hsv {
+            h: hue(r, g, b, max, delta),
+            s,
+            v: max,
+        }
+    }
+
+    /// 从 HSV 转换；`s`、`v` 会被限制在 0..=1，色相按 360 度取模
+    pub fn from_hsv(hsv: Hsv) -> Color {
+        let s = hsv.s.clamp(0.0, 1.0);
+        let v = hsv.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        from_chroma(hsv.h, chroma, v - chroma)
+    }
+
+    /// 以不透明度 `alpha`（0..=1）把 `self` 叠在 `background` 上
+    pub fn blend(self, background: Color, alpha: f64) -> Color {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let mix = |front: u8, back: u8| {
+            let value = alpha * f64::from(front) + (1.0 - alpha) * f64::from(back);
+            value.round() as u8
+        };
+        Color(
+            mix(self.0, background.0),
+            mix(self.1, background.1),
+            mix(self.2, background.2),
+        )
+    }
+
+    /// WCAG 2.x 的相对亮度：0（黑）到 1（白）
+    pub fn relative_luminance(self) -> f64 {
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    /// 两种颜色的对比度，1.0 到 21.0，与顺序无关
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    pub fn contrast(self, other: Color) -> Contrast {
+        Contrast::from_ratio(self.contrast_ratio(other))
+    }
+}
+
+impl TryFrom<(i32, i32, i32)> for Color {
+    type Error = ColorError;
+
+    /// 检查每个通道都在 0..=255 之间
+    fn try_from((r, g, b): (i32, i32, i32)) -> Result<Color, ColorError> {
+        let check = |index: usize, value: i32| {
+            u8::try_from(value).map_err(|_| ColorError::ChannelOutOfRange { index, value })
+        };
+        Ok(Color(check(0, r)?, check(1, g)?, check(2, b)?))
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    /// 接受十六进制（`#ff8800`、`#f80`）或颜色名（`orange`）
+    ///
+    /// 十六进制必须带 `#`，否则 `bad`、`cafe00` 这样的单词会被误当成颜色
+    fn from_str(s: &str) -> Result<Color, ColorError> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            return Color::from_hex(s);
+        }
+        Color::named(s).ok_or_else(|| ColorError::UnknownName(s.to_string()))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
@@ -0,0 +1,348 @@
+//! 文字命令：把 `move 10 20` 这样的一行文字解析成第 7 课的 `Message`
+//!
+//! | 命令 | 消息 |
+//! |------|------|
+//! | `quit` | `Message::Quit` |
+//! | `move X Y` | `Message::Move { x: X, y: Y }`，`X`、`Y` 是 `i32` |
+//! | `write TEXT` | `Message::Write(TEXT)` |
+//! | `color R G B` | `Message::ChangeColor(Color(R, G, B))`，各通道 0 到 255 |
+//!
+//! 各部分之间用空白分隔。`TEXT` 可以是一个不含空白的单词，也可以是双引号括起来的字符串，
+//! 字符串中支持转义 `\"`、`\\`、`\n`、`\r`、`\t`、`\0` 和 `\u{1F980}`。
+//! 出错时 [`ParseError`] 会给出出错位置的列号（从 1 开始，按字符计）以及期望的内容。
+//!
+//! ```
+//! use rust_learning::enums::Message;
+//!
+//! let message: Message = r#"write "Hello\tRust!""#.parse().unwrap();
+//! assert_eq!(message, Message::Write("Hello\tRust!".to_string()));
+//!
+//! let error = "move 10 x".parse::<Message>().unwrap_err();
+//! assert_eq!(error.to_string(), "第 9 列: 期望整数（i32），实际是 `x`");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::enums::Message;
+use crate::i18n::{self, Lang, t, tf};
+use crate::structs::Color;
+
+/// 可用的命令名，出现未知命令时列在错误信息里
+pub const COMMANDS: [&str; 4] = ["quit", "move", "write", "color"];
+
+/// 解析错误：`column` 是出错位置的列号，从 1 开始按字符计
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// 期望某种内容，实际是 `found`；`found` 为 `None` 表示已经到了行尾
+    Expected {
+        expected: Expected,
+        found: Option<String>,
+    },
+    UnknownCommand(String),
+    /// 字符串缺少结尾的双引号，列号指向开头的双引号
+    UnterminatedString,
+    /// 不认识的转义，例如 `\q` 或 `\u{110000}`，列号指向反斜杠
+    InvalidEscape(String),
+}
+
+/// 某个位置上期望出现的内容
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    Command,
+    /// `move` 的坐标
+    Integer,
+    /// `color` 的通道
+    Byte,
+    /// `write` 的文字
+    Text,
+    /// 命令已经完整，后面不应再有内容
+    End,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expected::Command => {
+                write!(f, "{}", tf("command.expected.command", &[&command_list()]))
+            }
+            Expected::Integer => write!(f, "{}", t("command.expected.integer")),
+            Expected::Byte => write!(f, "{}", t("command.expected.byte")),
+            Expected::Text => write!(f, "{}", t("command.expected.text")),
+            Expected::End => write!(f, "{}", t("command.expected.end")),
+        }
+    }
+}
+
+/// 错误文字来自消息目录（`command.*`），随 `LEARN_LANG` 切换中英文
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match &self.kind {
+            ErrorKind::Expected {
+                expected,
+                found: Some(found),
+            } => tf("command.expected_found", &[expected, found]),
+            ErrorKind::Expected {
+                expected,
+                found: None,
+            } => tf("command.expected_eol", &[expected]),
+            ErrorKind::UnknownCommand(name) => tf("command.unknown", &[name, &command_list()]),
+            ErrorKind::UnterminatedString => t("command.unterminated").to_string(),
+            ErrorKind::InvalidEscape(escape) => tf("command.invalid_escape", &[escape]),
+        };
+        write!(f, "{}", tf("command.error", &[&self.column, &message]))
+    }
+}
+
+/// 可用命令的列表，分隔符随语言变化
+fn command_list() -> String {
+    let separator = match i18n::lang() {
+        Lang::Zh => "、",
+        Lang::En => ", ",
+    };
+    COMMANDS.join(separator)
+}
+
+impl std::error::Error for ParseError {}
+
+/// 一个单词或一个带引号的字符串
+#[derive(Debug)]
+struct Token<'a> {
+    /// 原文，出错时原样显示
+    source: &'a str,
+    /// 去掉引号、处理完转义后的内容
+    value: String,
+    quoted: bool,
+}
+
+/// 逐个切出 token，同时记录列号
+struct Lexer<'a> {
+    line: &'a str,
+    /// 下一个字符的字节位置和列号
+    pos: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(line: &'a str) -> Lexer<'a> {
+        Lexer {
+            line,
+            pos: 0,
+            column: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.line[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        self.column += 1;
+        Some(c)
+    }
+
+    /// 跳过空白后的列号，即下一个 token（或行尾）所在的位置
+    fn column(&mut self) -> usize {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.column
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token<'a>>, ParseError> {
+        let column = self.column();
+        let start = self.pos;
+        let (value, quoted) = match self.peek() {
+            None => return Ok(None),
+            Some('"') => (self.string(column)?, true),
+            Some(_) => {
+                // 单词到空白或双引号为止
+                while self.peek().is_some_and(|c| !c.is_whitespace() && c != '"') {
+                    self.bump();
+                }
+                (self.line[start..self.pos].to_string(), false)
+            }
+        };
+        Ok(Some(Token {
+            source: &self.line[start..self.pos],
+            value,
+            quoted,
+        }))
+    }
+
+    /// 读取双引号字符串，处理转义
+    fn string(&mut self, column: usize) -> Result<String, ParseError> {
+        self.bump();
+        let mut value = String::new();
+        loop {
+            let escape_column = self.column;
+            let escape_start = self.pos;
+            match self.bump() {
+                None => {
+                    return Err(ParseError {
+                        column,
+                        kind: ErrorKind::UnterminatedString,
+                    });
+                }
+                Some('"') => return Ok(value),
+                Some('\\') => {
+                    let c = self.escape().ok_or_else(|| ParseError {
+                        column: escape_column,
+                        kind: ErrorKind::InvalidEscape(
+                            self.line[escape_start..self.pos].to_string(),
+                        ),
+                    })?;
+                    value.push(c);
+                }
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    /// 反斜杠之后的部分；不合法时返回 `None`，已读过的字符留作错误信息
+    fn escape(&mut self) -> Option<char> {
+        match self.bump()? {
+            '"' => Some('"'),
+            '\\' => Some('\\'),
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            't' => Some('\t'),
+            '0' => Some('\0'),
+            'u' => {
+                if self.bump()? != '{' {
+                    return None;
+                }
+                let mut digits = String::new();
+                loop {
+                    match self.bump()? {
+                        '}' => break,
+                        c if c.is_ascii_hexdigit() && digits.len() < 6 => digits.push(c),
+                        _ => return None,
+                    }
+                }
+                u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn unexpected(column: usize, expected: Expected, token: Option<Token>) -> ParseError {
+    ParseError {
+        column,
+        kind: ErrorKind::Expected {
+            expected,
+            found: token.map(|token| token.source.to_string()),
+        },
+    }
+}
+
+/// 读取下一个 token 并转换成需要的值，失败时报告期望的内容和实际的原文
+fn expect<T>(
+    lexer: &mut Lexer,
+    expected: Expected,
+    convert: impl FnOnce(&Token) -> Option<T>,
+) -> Result<T, ParseError> {
+    let column = lexer.column();
+    let token = lexer.next_token()?;
+    match token.as_ref().and_then(convert) {
+        Some(value) => Ok(value),
+        None => Err(unexpected(column, expected, token)),
+    }
+}
+
+/// 数字不能带引号，`move "10" 20` 是错误
+fn number<T: FromStr>(token: &Token) -> Option<T> {
+    if token.quoted {
+        return None;
+    }
+    token.value.parse().ok()
+}
+
+/// 解析一行命令
+pub fn parse(line: &str) -> Result<Message, ParseError> {
+    let mut lexer = Lexer::new(line);
+    let column = lexer.column();
+    let name = match lexer.next_token()? {
+        Some(token) if !token.quoted => token.value,
+        token => return Err(unexpected(column, Expected::Command, token)),
+    };
+    let message = match name.as_str() {
+        "quit" => Message::Quit,
+        "move" => Message::Move {
+            x: expect(&mut lexer, Expected::Integer, number)?,
+            y: expect(&mut lexer, Expected::Integer, number)?,
+        },
+        "write" => {
+            let text = expect(&mut lexer, Expected::Text, |token| {
+                Some(token.value.clone())
+            })?;
+            Message::Write(text)
+        }
+        "color" => Message::ChangeColor(Color(
+            expect(&mut lexer, Expected::Byte, number)?,
+            expect(&mut lexer, Expected::Byte, number)?,
+            expect(&mut lexer, Expected::Byte, number)?,
+        )),
+        _ => {
+            return Err(ParseError {
+                column,
+                kind: ErrorKind::UnknownCommand(name),
+            });
+        }
+    };
+    let column = lexer.column();
+    match lexer.next_token()? {
+        None => Ok(message),
+        token => Err(unexpected(column, Expected::End, token)),
+    }
+}
+
+impl FromStr for Message {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Message, ParseError> {
+        parse(line)
+    }
+}
+
+impl Message {
+    /// 写成命令文字，`parse` 可以原样解析回来；`Write` 的文字总是加引号
+    pub fn to_command(&self) -> String {
+        match self {
+            Message::Quit => "quit".to_string(),
+            Message::Move { x, y } => format!("move {} {}", x, y),
+            Message::Write(text) => format!("write {}", quote(text)),
+            Message::ChangeColor(Color(r, g, b)) => format!("color {} {} {}", r, g, b),
+        }
+    }
+}
+
+/// 加上双引号并转义特殊字符
+fn quote(text: &str) -> String {
+    let mut out = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\0' => out.push_str("\\0"),
+            c if c.is_control() => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,163 @@
+//! 跨语言对比：收集课程源码中的「🆚 对比」注释，按来源语言生成速查表
+//!
+//! 课程里有两种写法，都会被识别：
+//!
+//! ```text
+//! // 🆚 对比 Java：Java 没有遮蔽，只能重新赋值（需 mutable）
+//!
+//! // 🆚 对比 Java：
+//! // - Java 有 int[], boolean[] 等，但无元组
+//! // - Rust 数组长度是类型一部分（[i32; 5] ≠ [i32; 6]）
+//! ```
+//!
+//! 冒号后面的文字和紧跟着的每个 `// - ` 列表项各算一条对比要点。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::lessons::{self, LESSONS};
+
+const MARKER: &str = "🆚 对比";
+
+/// 一条对比要点
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    /// 课程编号，见 [`crate::lessons::LESSONS`]
+    pub lesson: u8,
+    /// 对比的语言，与源码中的写法一致，例如 `Java`、`C++`
+    pub language: String,
+    pub point: String,
+    /// 要点所在的行号（从 1 开始）
+    pub line: usize,
+}
+
+/// `//` 注释的内容
+fn comment(line: &str) -> Option<&str> {
+    line.trim().strip_prefix("//").map(str::trim)
+}
+
+/// 从一节课的源码中解析全部对比要点
+pub fn parse(lesson: u8, source: &str) -> Vec<Comparison> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut comparisons = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let Some(rest) = comment(line).and_then(|c| c.strip_prefix(MARKER)) else {
+            continue;
+        };
+        let (language, first) = match rest.split_once(['：', ':']) {
+            Some((language, point)) => (language.trim(), point.trim()),
+            None => (rest.trim(), ""),
+        };
+        if language.is_empty() {
+            continue;
+        }
+        let mut push = |point: &str, line: usize| {
+            comparisons.push(Comparison {
+                lesson,
+                language: language.to_string(),
+                point: point.to_string(),
+                line,
+            });
+        };
+
+        if !first.is_empty() {
+            push(first, index + 1);
+        }
+        for (offset, next) in lines[index + 1..].iter().enumerate() {
+            let Some(point) = comment(next).and_then(|c| c.strip_prefix("- ")) else {
+                break;
+            };
+            push(point.trim(), index + offset + 2);
+        }
+    }
+    comparisons
+}
+
+/// 读取全部课程源码（`root` 为仓库根目录），按学习路径顺序返回对比要点
+pub fn collect(root: &Path) -> io::Result<Vec<Comparison>> {
+    let mut comparisons = Vec::new();
+    for lesson in lessons::learning_path() {
+        let source = fs::read_to_string(root.join(lesson.source))?;
+        comparisons.extend(parse(lesson.number, &source));
+    }
+    Ok(comparisons)
+}
+
+/// 仓库自带课程的对比要点
+pub fn all() -> io::Result<Vec<Comparison>> {
+    collect(Path::new(env!("CARGO_MANIFEST_DIR")))
+}
+
+/// 比较语言名时忽略大小写，并接受 `cpp` 这样的别名
+pub fn same_language(a: &str, b: &str) -> bool {
+    fn canonical(name: &str) -> String {
+        match name.trim().to_lowercase().as_str() {
+            "cpp" | "cxx" | "c++" => "c++".to_string(),
+            "py" | "python" => "python".to_string(),
+            other => other.to_string(),
+        }
+    }
+    canonical(a) == canonical(b)
+}
+
+/// 某种语言的全部对比要点
+pub fn for_language<'a>(comparisons: &'a [Comparison], language: &str) -> Vec<&'a Comparison> {
+    comparisons
+        .iter()
+        .filter(|c| same_language(&c.language, language))
+        .collect()
+}
+
+/// 出现过的语言及其要点数量，按首次出现的顺序
+pub fn languages(comparisons: &[Comparison]) -> Vec<(&str, usize)> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for comparison in comparisons {
+        match counts
+            .iter_mut()
+            .find(|(language, _)| same_language(language, &comparison.language))
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((&comparison.language, 1)),
+        }
+    }
+    counts
+}
+
+/// `learn compare <语言>` 的速查表：按课程分组，标出源码位置
+pub fn cheat_sheet(comparisons: &[Comparison], language: &str) -> Option<String> {
+    let matching = for_language(comparisons, language);
+    let first = matching.first()?;
+
+    let mut lessons_seen = 0;
+    let mut body = String::new();
+    let mut current = None;
+    for comparison in &matching {
+        if current != Some(comparison.lesson) {
+            current = Some(comparison.lesson);
+            lessons_seen += 1;
+            if let Some(lesson) = LESSONS.iter().find(|l| l.number == comparison.lesson) {
+                let _ = writeln!(
+                    body,
+                    "\n{:02}. {}（{}:{}）",
+                    lesson.number,
+                    lesson.topic(),
+                    lesson.source,
+                    comparison.line
+                );
+            }
+        }
+        let _ = writeln!(body, "  - {}", comparison.point);
+    }
+
+    Some(format!(
+        "{} {}（{} 节课，{} 条）\n{}",
+        MARKER,
+        first.language,
+        lessons_seen,
+        matching.len(),
+        body
+    ))
+}
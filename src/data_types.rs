@@ -0,0 +1,35 @@
+/// Rust 是静态类型语言，但通常能自动推断类型
+pub fn run() {
+    // ===== 标量类型（Scalar）=====
+    
+    // 整数（默认 i32）
+    let _a: i32 = -10;
+    let _b = 100u8; // u8 表示 8 位无符号整数
+    
+    // 浮点数（默认 f64）
+    let _c = 2.5f32;
+    #[allow(clippy::approx_constant)]
+    let _d = 3.14; // f64
+
+    // 布尔
+    let _e = true;
+
+    // 字符（Unicode，4字节）
+    let _f = 'α';
+    let _g = '中';
+
+    // ===== 复合类型（Compound）=====
+
+    // 元组（Tuple）—— 固定长度，可不同类型
+    let _tup: (i32, f64, char) = (500, 6.4, 'a');
+    let (x, y, z) = _tup; // 解构
+    println!("x={}, y={}, z={}", x, y, z);
+
+    // 数组（Array）—— 固定长度，同类型
+    let _arr: [i32; 5] = [1, 2, 3, 4, 5];
+    let _arr2 = [3; 5]; // [3, 3, 3, 3, 3]
+
+    // 🆚 对比 Java：
+    // - Java 有 int[], boolean[] 等，但无元组
+    // - Rust 数组长度是类型一部分（[i32; 5] ≠ [i32; 6]）
+}
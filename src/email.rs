@@ -0,0 +1,205 @@
+//! 邮箱地址：解析、校验并规范化 `本地部分@域名`
+//!
+//! 只接受常见的 ASCII 地址（RFC 5321 dot-atom 形式），不支持带引号的本地部分和 IP 字面量域名：
+//!
+//! - 前后空白会被去掉；整个地址不超过 254 字节，本地部分不超过 64 字节
+//! - 本地部分由字母、数字和 `` !#$%&'*+-/=?^_`{|}~ `` 组成，`.` 不能开头、结尾或连续出现
+//! - 域名至少两段，每段 1 到 63 个字母、数字或 `-`，`-` 不能在段首段尾，顶级域不能是纯数字
+//! - 域名不区分大小写，统一转成小写；本地部分保持原样
+//!
+//! 本地部分中第一个 `+` 之后是子地址标签（plus addressing），例如 `alice+news@example.org`
+//! 的标签是 `news`，去掉标签后是 `alice@example.org`。
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::i18n::{t, tf};
+
+const MAX_LEN: usize = 254;
+const MAX_LOCAL_LEN: usize = 64;
+const MAX_LABEL_LEN: usize = 63;
+
+/// 邮箱地址解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailError {
+    Empty,
+    MissingAt,
+    MultipleAt,
+    TooLong(usize),
+    EmptyLocal,
+    LocalTooLong(usize),
+    InvalidLocalChar(char),
+    /// 本地部分以 `.` 开头、结尾或包含 `..`
+    MisplacedDot,
+    EmptyDomain,
+    /// 域名只有一段，例如 `localhost`
+    MissingTld,
+    /// 域名中不合法的一段（为空、过长、字符不合法或 `-` 在段首段尾）
+    InvalidLabel(String),
+    NumericTld(String),
+}
+
+/// 错误文字来自消息目录（`email.*`），随 `LEARN_LANG` 切换中英文
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            EmailError::Empty => t("email.empty").to_string(),
+            EmailError::MissingAt => t("email.missing_at").to_string(),
+            EmailError::MultipleAt => t("email.multiple_at").to_string(),
+            EmailError::TooLong(len) => tf("email.too_long", &[len, &MAX_LEN]),
+            EmailError::EmptyLocal => t("email.empty_local").to_string(),
+            EmailError::LocalTooLong(len) => tf("email.local_too_long", &[len, &MAX_LOCAL_LEN]),
+            EmailError::InvalidLocalChar(c) => {
+                tf("email.invalid_local_char", &[&format!("{:?}", c)])
+            }
+            EmailError::MisplacedDot => t("email.misplaced_dot").to_string(),
+            EmailError::EmptyDomain => t("email.empty_domain").to_string(),
+            EmailError::MissingTld => t("email.missing_tld").to_string(),
+            EmailError::InvalidLabel(label) => {
+                tf("email.invalid_label", &[&format!("{:?}", label)])
+            }
+            EmailError::NumericTld(tld) => tf("email.numeric_tld", &[tld]),
+        };
+        f.write_str(&text)
+    }
+}
+
+impl std::error::Error for EmailError {}
+
+/// 校验过的邮箱地址，域名已转成小写
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Email {
+    address: String,
+    /// `@` 的位置
+    at: usize,
+}
+
+impl Email {
+    /// 解析并规范化邮箱地址
+    pub fn parse(input: &str) -> Result<Email, EmailError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(EmailError::Empty);
+        }
+        let (local, domain) = input.split_once('@').ok_or(EmailError::MissingAt)?;
+        if domain.contains('@') {
+            return Err(EmailError::MultipleAt);
+        }
+        if input.len() > MAX_LEN {
+            return Err(EmailError::TooLong(input.len()));
+        }
+        check_local(local)?;
+        let domain = check_domain(domain)?;
+        Ok(Email {
+            address: format!("{}@{}", local, domain),
+            at: local.len(),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.address
+    }
+
+    /// `@` 前面的本地部分（含标签）
+    pub fn local(&self) -> &str {
+        &self.address[..self.at]
+    }
+
+    /// `@` 后面的域名（小写）
+    pub fn domain(&self) -> &str {
+        &self.address[self.at + 1..]
+    }
+
+    /// 子地址标签：本地部分第一个 `+` 之后的内容；`+` 在开头时不算标签
+    pub fn tag(&self) -> Option<&str> {
+        match self.local().split_once('+') {
+            Some((base, tag)) if !base.is_empty() => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// 去掉子地址标签后的地址，例如 `alice+news@example.org` → `alice@example.org`
+    pub fn without_tag(&self) -> Email {
+        let Some(tag) = self.tag() else {
+            return self.clone();
+        };
+        let base = &self.local()[..self.at - tag.len() - 1];
+        Email {
+            address: format!("{}@{}", base, self.domain()),
+            at: base.len(),
+        }
+    }
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+fn check_local(local: &str) -> Result<(), EmailError> {
+    if local.is_empty() {
+        return Err(EmailError::EmptyLocal);
+    }
+    if local.len() > MAX_LOCAL_LEN {
+        return Err(EmailError::LocalTooLong(local.len()));
+    }
+    if let Some(c) = local.chars().find(|&c| c != '.' && !is_atext(c)) {
+        return Err(EmailError::InvalidLocalChar(c));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err(EmailError::MisplacedDot);
+    }
+    Ok(())
+}
+
+/// 校验域名，返回小写形式
+fn check_domain(domain: &str) -> Result<String, EmailError> {
+    if domain.is_empty() {
+        return Err(EmailError::EmptyDomain);
+    }
+    let labels: Vec<&str> = domain.split('.').collect();
+    for label in &labels {
+        let valid = !label.is_empty()
+            && label.len() <= MAX_LABEL_LEN
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !valid {
+            return Err(EmailError::InvalidLabel(label.to_string()));
+        }
+    }
+    if labels.len() < 2 {
+        return Err(EmailError::MissingTld);
+    }
+    let tld = labels[labels.len() - 1];
+    if tld.chars().all(|c| c.is_ascii_digit()) {
+        return Err(EmailError::NumericTld(tld.to_string()));
+    }
+    Ok(domain.to_ascii_lowercase())
+}
+
+impl FromStr for Email {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Email, EmailError> {
+        Email::parse(s)
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.address)
+    }
+}
+
+// 调试输出和字符串一样，`{:?}` 打印 User 时仍是 `email: "alice@example.org"`
+impl fmt::Debug for Email {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.address, f)
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.address
+    }
+}
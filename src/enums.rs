@@ -0,0 +1,280 @@
+use crate::ansi::heading;
+use crate::cidr::Cidr;
+use crate::i18n::t;
+use crate::ip::{Ipv4, Ipv6};
+use crate::structs::Color;
+
+/// 枚举（Enum）：Rust 中强大的类型系统特性
+/// 
+/// Rust 的枚举比其他语言（如 Java）的枚举更强大，因为：
+/// 1. 每个变体可以包含不同类型和数量的数据
+/// 2. 可以为枚举实现方法
+/// 3. 可以与模式匹配（pattern matching）配合使用
+/// 4. 用于创建类型安全的状态机
+
+// 示例 1: 基本枚举 - IP 地址类型
+// 解析、格式化和分类（回环、私有、组播……）的实现见 src/ip.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpAddr {
+    // 变体 V4 包含一个 IPv4 地址（4 个字节）
+    V4(Ipv4),
+    // 变体 V6 包含一个 IPv6 地址（8 个 16 位段）
+    V6(Ipv6),
+}
+
+// 示例 2: 复杂枚举 - 消息类型
+// 编码成字节（标签 + 字段）以便在网络上传输，实现见 src/codec.rs
+// 也可以从 `move 10 20` 这样的文字命令解析出来，实现见 src/command.rs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    // 无数据的变体
+    Quit,
+    // 包含匿名结构体的变体
+    Move { x: i32, y: i32 },
+    // 包含单个 String 类型的变体
+    Write(String),
+    // 包含一个元组结构体的变体（Color 定义在第 6 课，通道是 u8）
+    ChangeColor(Color),
+}
+
+// 处理消息的 trait：每个变体对应一个方法
+// call 用打印的 Printer；网络服务（src/messaging.rs）收到消息后交给自己的实现
+pub trait Handler {
+    fn quit(&mut self);
+    fn move_to(&mut self, x: i32, y: i32);
+    fn write(&mut self, text: &str);
+    fn change_color(&mut self, color: Color);
+}
+
+// 为枚举实现方法
+impl Message {
+    /// 把消息分发给 handler 中对应的方法
+    pub fn dispatch<H: Handler + ?Sized>(&self, handler: &mut H) {
+        // 使用 match 表达式处理不同变体
+        match self {
+            Message::Quit => handler.quit(),
+            Message::Move { x, y } => handler.move_to(*x, *y),
+            Message::Write(text) => handler.write(text),
+            Message::ChangeColor(color) => handler.change_color(*color),
+        }
+    }
+
+    /// 为 Message 枚举实现 call 方法：交给 Printer 打印
+    pub fn call(&self) {
+        self.dispatch(&mut Printer);
+    }
+}
+
+/// 把收到的消息打印出来的 Handler
+pub struct Printer;
+
+impl Handler for Printer {
+    fn quit(&mut self) {
+        println!("Message::Quit called");
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        println!("Message::Move called with x={}, y={}", x, y);
+    }
+
+    fn write(&mut self, text: &str) {
+        println!("Message::Write called with text={}", text);
+    }
+
+    // 模式可以出现在参数里：直接解构出 Color 的三个通道
+    fn change_color(&mut self, Color(r, g, b): Color) {
+        println!("Message::ChangeColor called with r={}, g={}, b={}", r, g, b);
+    }
+}
+
+// 示例 3: 嵌套枚举 - 更复杂的数据结构
+#[derive(Debug)]
+pub enum Device {
+    Laptop {
+        brand: String,
+        model: String,
+    },
+    Smartphone {
+        os: OperatingSystem,
+        storage: u32, // 存储容量（GB）
+    },
+}
+
+// 嵌套的操作系统枚举
+#[derive(Debug)]
+#[allow(non_camel_case_types)] // 保留 iOS 的惯用写法
+pub enum OperatingSystem {
+    Windows,
+    MacOS,
+    Linux,
+    iOS,
+    Android,
+}
+
+// 示例 4: 枚举与 trait 实现
+#[derive(Debug)]
+pub enum Shape {
+    Circle(f64),      // 半径
+    Square(f64),      // 边长
+    Rectangle(f64, f64), // 长和宽
+}
+
+// 为 Shape 实现计算面积的方法
+impl Shape {
+    pub fn area(&self) -> f64 {
+        match self {
+            Shape::Circle(radius) => std::f64::consts::PI * radius * radius,
+            Shape::Square(side) => side * side,
+            Shape::Rectangle(length, width) => length * width,
+        }
+    }
+}
+
+pub fn run() {
+    println!("{}", heading(&format!("===== {} =====", t("enums.basic"))));
+    // 创建 IpAddr 枚举的实例
+    let home = IpAddr::V4(Ipv4::new(127, 0, 0, 1));
+    let loopback = IpAddr::V6(Ipv6::LOCALHOST);
+    println!("Home IP: {:?}, Loopback IP: {:?}", home, loopback);
+    // 也可以从字符串解析，不合法的文字会得到错误而不是一个“地址”
+    for text in ["192.168.1.20", "FE80:0:0:0:0:0:0:1", "banana"] {
+        match text.parse::<IpAddr>() {
+            Ok(ip) => println!(
+                "{} -> {} (loopback: {}, private: {}, link-local: {})",
+                text, ip, ip.is_loopback(), ip.is_private(), ip.is_link_local()
+            ),
+            Err(_) => println!("{} -> not an IP address", text),
+        }
+    }
+    // 网段（CIDR）建立在 IpAddr 之上，实现见 src/cidr.rs，命令行工具是 `cargo run --bin cidr`
+    let office: Cidr = "192.168.1.0/24".parse().expect("示例网段是合法的");
+    let printer = IpAddr::V4(Ipv4::new(192, 168, 1, 20));
+    println!(
+        "{}: broadcast {}, {} hosts, contains {}: {}, contains {}: {}",
+        office, office.broadcast(), office.host_count(),
+        printer, office.contains(printer), home, office.contains(home)
+    );
+    let halves: Vec<String> = office
+        .subnets(25)
+        .into_iter()
+        .flatten()
+        .map(|net| net.to_string())
+        .collect();
+    println!("{} split into /25: {}", office, halves.join(", "));
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.methods"))));
+    // 创建 Message 枚举的不同实例并调用方法
+    let msg1 = Message::Quit;
+    let msg2 = Message::Move { x: 10, y: 20 };
+    let msg3 = Message::Write(String::from("Hello Rust!"));
+    let msg4 = Message::ChangeColor(Color(255, 0, 0));
+    
+    msg1.call();
+    msg2.call();
+    msg3.call();
+    msg4.call();
+
+    // 消息可以编码成字节，接收方再解码回来
+    for message in [&msg2, &msg3, &msg4] {
+        let bytes = message.encode().expect("示例消息不超过长度上限");
+        let hex: Vec<String> = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        println!("{:?} -> [{}] -> {:?}", message, hex.join(" "), Message::decode(&bytes));
+    }
+
+    // 也可以从文字命令解析出消息，出错时指出列号和期望的内容
+    for line in ["move 10 20", r#"write "Hi\tRust""#, "color 255 0"] {
+        match line.parse::<Message>() {
+            Ok(message) => println!("{:?} -> {:?}", line, message),
+            Err(e) => println!("{:?} -> {}", line, e),
+        }
+    }
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.option"))));
+    // Option 是 Rust 标准库中的枚举，用于表示可能存在或不存在的值
+    // Option<T> 有两个变体：Some(T) 和 None
+    
+    let some_number = Some(5);          // 类型自动推断为 Option<i32>
+    let some_string = Some("Hello");    // 类型自动推断为 Option<&str>
+    let absent_number: Option<i32> = None; // 必须显式指定类型
+    
+    println!("some_number: {:?}", some_number);
+    println!("some_string: {:?}", some_string);
+    println!("absent_number: {:?}", absent_number);
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.match"))));
+    // match 表达式必须覆盖所有可能的变体
+    match some_number {
+        Some(x) => println!("Got number: {}", x),
+        None => println!("No number provided"),
+    }
+
+    // 匹配 Message 枚举
+    let msg = Message::Write(String::from("Rust is awesome!"));
+    match msg {
+        Message::Quit => println!("Action: Quit"),
+        Message::Move { x, y } => println!("Action: Move to ({}, {})", x, y),
+        Message::Write(text) => println!("Action: Write '{}'", text),
+        Message::ChangeColor(Color(r, g, b)) => println!("Action: Change color to RGB({}, {}, {})", r, g, b),
+    }
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.if_let"))));
+    // 当只关心一种变体时，使用 if let 比 match 更简洁
+    if let Some(x) = some_number {
+        println!("if let: Got number {}", x);
+    } else {
+        println!("if let: No number");
+    }
+
+    // 另一个 if let 示例
+    let color = Message::ChangeColor(Color(0, 255, 0));
+    if let Message::ChangeColor(Color(r, g, b)) = color {
+        println!("if let: Changing color to RGB({}, {}, {})", r, g, b);
+    }
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.nested"))));
+    // 创建嵌套枚举实例
+    let laptop = Device::Laptop {
+        brand: String::from("Apple"),
+        model: String::from("MacBook Pro"),
+    };
+    
+    let smartphone = Device::Smartphone {
+        os: OperatingSystem::iOS,
+        storage: 256,
+    };
+    
+    println!("Device 1: {:?}", laptop);
+    println!("Device 2: {:?}", smartphone);
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.compute"))));
+    // 使用 Shape 枚举计算面积
+    let circle = Shape::Circle(5.0);
+    let square = Shape::Square(4.0);
+    let rectangle = Shape::Rectangle(3.0, 6.0);
+    
+    println!("Circle area: {:.2}", circle.area());
+    println!("Square area: {:.2}", square.area());
+    println!("Rectangle area: {:.2}", rectangle.area());
+
+    println!("\n{}", heading(&format!("===== {} =====", t("enums.result"))));
+    // Result 是 Rust 标准库中的枚举，用于表示可能失败的操作
+    // Result<T, E> 有两个变体：Ok(T) 表示成功，Err(E) 表示失败
+    
+    fn divide(a: f64, b: f64) -> Result<f64, String> {
+        if b == 0.0 {
+            Err(String::from("Division by zero"))
+        } else {
+            Ok(a / b)
+        }
+    }
+    
+    match divide(10.0, 2.0) {
+        Ok(result) => println!("10.0 / 2.0 = {}", result),
+        Err(error) => println!("Error: {}", error),
+    }
+    
+    match divide(5.0, 0.0) {
+        Ok(result) => println!("5.0 / 0.0 = {}", result),
+        Err(error) => println!("Error: {}", error),
+    }
+}
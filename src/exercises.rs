@@ -0,0 +1,350 @@
+//! 练习模式：每节课配有几道故意写错或没写完的练习
+//!
+//! 练习文件在 `exercises/<课程>/` 下，参考答案在 `exercises/solutions/` 下的同名路径。
+//! 每道练习都自带 `#[cfg(test)]` 测试：用 rustc 以 `--test` 编译并运行，
+//! 编译通过且测试全部通过才算完成。
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 测试运行超过这个时间就视为卡死（例如写出了死循环）
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 一道练习
+pub struct Exercise {
+    pub name: &'static str,
+    /// 对应课程的编号，见 [`crate::lessons::LESSONS`]
+    pub lesson: u8,
+    /// 相对 `exercises/` 目录的路径
+    pub path: &'static str,
+    pub hint: &'static str,
+}
+
+impl Exercise {
+    /// 练习文件的完整路径
+    pub fn file(&self) -> PathBuf {
+        exercises_dir().join(self.path)
+    }
+
+    /// 参考答案的完整路径
+    pub fn solution(&self) -> PathBuf {
+        exercises_dir().join("solutions").join(self.path)
+    }
+}
+
+/// 检查一道练习的结果
+#[derive(Debug)]
+pub enum Outcome {
+    Passed,
+    /// 编译失败，附带 rustc 的诊断输出
+    CompileError(String),
+    /// 测试失败，附带测试输出
+    TestFailed(String),
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Outcome::Passed)
+    }
+
+    /// 编译器或测试的原始输出
+    pub fn output(&self) -> &str {
+        match self {
+            Outcome::CompileError(output) | Outcome::TestFailed(output) => output,
+            Outcome::Passed | Outcome::TimedOut => "",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Outcome::Passed => "通过",
+            Outcome::CompileError(_) => "编译失败",
+            Outcome::TestFailed(_) => "测试失败",
+            Outcome::TimedOut => "超时",
+        };
+        f.write_str(text)
+    }
+}
+
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "variables1",
+        lesson: 1,
+        path: "01_variables/variables1.rs",
+        hint: "变量默认不可变，声明时加上 mut 才能重新赋值。",
+    },
+    Exercise {
+        name: "variables2",
+        lesson: 1,
+        path: "01_variables/variables2.rs",
+        hint: "mut 只允许改值不允许改类型；用 let 再声明一次同名变量（遮蔽）。",
+    },
+    Exercise {
+        name: "data_types1",
+        lesson: 2,
+        path: "02_data_types/data_types1.rs",
+        hint: "let (number, letter) = pair; 然后返回 (letter, number)。",
+    },
+    Exercise {
+        name: "data_types2",
+        lesson: 2,
+        path: "02_data_types/data_types2.rs",
+        hint: "下标从 0 开始，长度为 5 的数组最后一个下标是 4。",
+    },
+    Exercise {
+        name: "functions1",
+        lesson: 3,
+        path: "03_functions/functions1.rs",
+        hint: "加了分号的 x + 1; 是语句，值为 ()；去掉分号它才是返回值。",
+    },
+    Exercise {
+        name: "functions2",
+        lesson: 3,
+        path: "03_functions/functions2.rs",
+        hint: "参数写成 x: i32。",
+    },
+    Exercise {
+        name: "control_flow1",
+        lesson: 4,
+        path: "04_control_flow/control_flow1.rs",
+        hint: "没有 else 时 if 的值是 ()，补上 else 分支返回 b。",
+    },
+    Exercise {
+        name: "control_flow2",
+        lesson: 4,
+        path: "04_control_flow/control_flow2.rs",
+        hint: "break 后面可以跟一个值，它就是整个 loop 表达式的值：break candidate;",
+    },
+    Exercise {
+        name: "ownership1",
+        lesson: 5,
+        path: "05_ownership/ownership1.rs",
+        hint: "需要两份独立的数据时，用 clone() 深拷贝。",
+    },
+    Exercise {
+        name: "ownership2",
+        lesson: 5,
+        path: "05_ownership/ownership2.rs",
+        hint: "参数改成 &str（或 &String），调用时传 &s。",
+    },
+    Exercise {
+        name: "ownership3",
+        lesson: 5,
+        path: "05_ownership/ownership3.rs",
+        hint: "修改借来的值需要 &mut String。",
+    },
+    Exercise {
+        name: "structs1",
+        lesson: 6,
+        path: "06_structs/structs1.rs",
+        hint: "结构体字面量缺少 sign_in_count 字段。",
+    },
+    Exercise {
+        name: "structs2",
+        lesson: 6,
+        path: "06_structs/structs2.rs",
+        hint: "split('@').next() 取的是 @ 前面的部分；试试 split_once('@')。",
+    },
+    Exercise {
+        name: "structs3",
+        lesson: 6,
+        path: "06_structs/structs3.rs",
+        hint: "仿照 impl Task<Running> 中的 pause，写一个 impl Task<Paused> { pub fn resume(self) -> Task<Running> }。",
+    },
+    Exercise {
+        name: "enums1",
+        lesson: 7,
+        path: "07_enums/enums1.rs",
+        hint: "补上 Message::ChangeColor(r, g, b) 分支。",
+    },
+    Exercise {
+        name: "enums2",
+        lesson: 7,
+        path: "07_enums/enums2.rs",
+        hint: "if b == 0 { None } else { Some(a / b) }",
+    },
+    Exercise {
+        name: "collections1",
+        lesson: 8,
+        path: "08_collections/collections1.rs",
+        hint: "result 需要声明为 mut，然后用 result.push(*n)。",
+    },
+    Exercise {
+        name: "collections2",
+        lesson: 8,
+        path: "08_collections/collections2.rs",
+        hint: "*counts.entry(word.to_string()).or_insert(0) += 1;",
+    },
+    Exercise {
+        name: "modules1",
+        lesson: 9,
+        path: "09_modules/modules1.rs",
+        hint: "hosting 模块是私有的，给它加上 pub。",
+    },
+    Exercise {
+        name: "modules2",
+        lesson: 9,
+        path: "09_modules/modules2.rs",
+        hint: "在 impl Breakfast 中添加 pub fn summer(toast: &str) -> Breakfast。",
+    },
+    Exercise {
+        name: "error_handling1",
+        lesson: 10,
+        path: "10_error_handling/error_handling1.rs",
+        hint: "把 unwrap() 换成 ? 操作符。",
+    },
+    Exercise {
+        name: "error_handling2",
+        lesson: 10,
+        path: "10_error_handling/error_handling2.rs",
+        hint: "在 parse::<i32>() 后面加上 ?。",
+    },
+    Exercise {
+        name: "generics_traits1",
+        lesson: 11,
+        path: "11_generics_traits/generics_traits1.rs",
+        hint: "用 list.first()? 取第一个元素，空切片会直接返回 None；最后返回 Some(largest)。",
+    },
+    Exercise {
+        name: "generics_traits2",
+        lesson: 11,
+        path: "11_generics_traits/generics_traits2.rs",
+        hint: "在 impl Summary for Tweet 中实现 fn summarize(&self) -> String。",
+    },
+    Exercise {
+        name: "lifetimes1",
+        lesson: 12,
+        path: "12_lifetimes/lifetimes1.rs",
+        hint: "fn longest<'a>(x: &'a str, y: &'a str) -> &'a str",
+    },
+    Exercise {
+        name: "lifetimes2",
+        lesson: 12,
+        path: "12_lifetimes/lifetimes2.rs",
+        hint: "struct ImportantExcerpt<'a> { part: &'a str }，返回类型写成 ImportantExcerpt<'_>。",
+    },
+    Exercise {
+        name: "macro1",
+        lesson: 13,
+        path: "13_macro/macro1.rs",
+        hint: "把 macro_rules! 定义移到 greeting 之前。",
+    },
+    Exercise {
+        name: "macro2",
+        lesson: 13,
+        path: "13_macro/macro2.rs",
+        hint: "展开为 0 $(+ $x)*。",
+    },
+    Exercise {
+        name: "trait1",
+        lesson: 14,
+        path: "14_trait/trait1.rs",
+        hint: "先写 type Item = u32;，再参考课程中 Counter 的 next 实现。",
+    },
+    Exercise {
+        name: "trait2",
+        lesson: 14,
+        path: "14_trait/trait2.rs",
+        hint: "#[derive(Debug, PartialEq, Clone, Copy)]",
+    },
+];
+
+/// `exercises/` 目录所在位置
+pub fn exercises_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("exercises")
+}
+
+/// 按名称查找练习
+pub fn find(name: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|exercise| exercise.name == name)
+}
+
+/// 检查一道练习当前的完成情况
+pub fn check(exercise: &Exercise) -> io::Result<Outcome> {
+    check_file(&exercise.file())
+}
+
+/// 用 rustc 以测试模式编译一个源文件并运行其中的测试
+pub fn check_file(source: &Path) -> io::Result<Outcome> {
+    static BUILD_ID: AtomicUsize = AtomicUsize::new(0);
+
+    let build_dir = env::temp_dir().join("rust-learning-exercises");
+    fs::create_dir_all(&build_dir)?;
+    let stem = source.file_stem().unwrap_or_default().to_string_lossy();
+    let binary = build_dir.join(format!(
+        "{}-{}-{}",
+        stem,
+        std::process::id(),
+        BUILD_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let compiled = Command::new(rustc)
+        .args(["--edition", "2024", "--test", "--color", "never"])
+        .arg("-o")
+        .arg(&binary)
+        .arg(source)
+        .output()?;
+    if !compiled.status.success() {
+        return Ok(Outcome::CompileError(
+            String::from_utf8_lossy(&compiled.stderr).into_owned(),
+        ));
+    }
+
+    let child = Command::new(&binary)
+        .args(["--color", "never"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let outcome = wait_with_timeout(child);
+    let _ = fs::remove_file(&binary);
+    outcome
+}
+
+/// 等待测试进程结束，超时则杀掉它
+fn wait_with_timeout(mut child: Child) -> io::Result<Outcome> {
+    // 在后台线程读取输出，避免管道写满导致子进程阻塞
+    let mut stdout = child.stdout.take().expect("stdout 已设置为 piped");
+    let mut stderr = child.stderr.take().expect("stderr 已设置为 piped");
+    let stdout_reader = thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = stdout.read_to_string(&mut buf);
+        buf
+    });
+    let stderr_reader = thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = stderr.read_to_string(&mut buf);
+        buf
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() > TIMEOUT {
+            child.kill()?;
+            child.wait()?;
+            return Ok(Outcome::TimedOut);
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+
+    let mut output = stdout_reader.join().unwrap_or_default();
+    output.push_str(&stderr_reader.join().unwrap_or_default());
+    if status.success() {
+        Ok(Outcome::Passed)
+    } else {
+        Ok(Outcome::TestFailed(output))
+    }
+}
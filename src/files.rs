@@ -0,0 +1,34 @@
+//! 库内共用的文件操作
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// 用 `contents` 整体替换文件：写临时文件并同步到磁盘，改名覆盖原文件，再同步所在目录
+///
+/// 不同步临时文件，改名可能先于数据落盘，断电后留下空文件；不同步目录，改名本身可能丢失。
+/// 临时文件是同目录下把扩展名换成 `tmp` 的文件。
+pub(crate) fn atomic_write(path: &Path, contents: &str) -> io::Result<()> {
+    let temp = path.with_extension("tmp");
+    let mut file = File::create(&temp)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&temp, path)?;
+    sync_dir(path)
+}
+
+/// 同步文件所在的目录，让改名持久化；只有类 Unix 系统可以这样打开目录
+#[cfg(unix)]
+fn sync_dir(path: &Path) -> io::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
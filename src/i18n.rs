@@ -0,0 +1,161 @@
+//! 中英文消息目录：课程打印的标题、总结以及课程注册表的文字都从这里取
+//!
+//! 目录文件在 `locales/` 下，每种语言一个，编译时嵌入：
+//!
+//! ```text
+//! # 注释
+//! enums.basic = 基本枚举使用
+//! learn.banner = 第 {} 课：{}（{}）
+//! ```
+//!
+//! 值中的 `{}` 是按顺序填入的占位符，见 [`tf`]。语言按以下顺序决定：
+//! 程序里调用过的 [`set_lang`]（例如 `learn --lang en`）、环境变量 `LEARN_LANG`、默认中文。
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 支持的语言
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lang {
+    #[default]
+    Zh,
+    En,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::Zh, Lang::En];
+
+    /// 语言代码，也是 `locales/` 下的文件名
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Zh => "zh",
+            Lang::En => "en",
+        }
+    }
+
+    /// 解析 `zh`、`en` 这样的语言代码，也接受 `zh_CN.UTF-8`、`en-US` 这类写法
+    pub fn parse(code: &str) -> Option<Lang> {
+        let code = code.trim().to_ascii_lowercase();
+        let primary = code.split(['_', '-', '.']).next().unwrap_or("");
+        match primary {
+            "zh" | "cn" => Some(Lang::Zh),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    /// 该语言的目录文件原文
+    pub fn catalog_source(self) -> &'static str {
+        match self {
+            Lang::Zh => include_str!("../locales/zh.txt"),
+            Lang::En => include_str!("../locales/en.txt"),
+        }
+    }
+}
+
+/// 0 表示没有调用过 [`set_lang`]
+static OVERRIDE: AtomicU8 = AtomicU8::new(0);
+
+/// 在程序内指定语言，优先于环境变量
+pub fn set_lang(lang: Lang) {
+    let value = match lang {
+        Lang::Zh => 1,
+        Lang::En => 2,
+    };
+    OVERRIDE.store(value, Ordering::Relaxed);
+}
+
+/// 当前使用的语言
+pub fn lang() -> Lang {
+    match OVERRIDE.load(Ordering::Relaxed) {
+        1 => Lang::Zh,
+        2 => Lang::En,
+        _ => env::var("LEARN_LANG")
+            .ok()
+            .and_then(|code| Lang::parse(&code))
+            .unwrap_or_default(),
+    }
+}
+
+/// 目录文件的一行无法解析
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "第 {} 行: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+/// 解析目录文件，按出现顺序返回 (键, 值)；值中的 `\n` 转义为换行
+pub fn parse_catalog(source: &str) -> Result<Vec<(String, String)>, CatalogError> {
+    let mut entries = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let error = |message: &str| CatalogError {
+            line: index + 1,
+            message: message.to_string(),
+        };
+        let (key, value) = trimmed
+            .split_once(" = ")
+            .ok_or_else(|| error("应为 `键 = 值`"))?;
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(error("键不能为空或包含空白"));
+        }
+        entries.push((key.to_string(), value.trim().replace("\\n", "\n")));
+    }
+    Ok(entries)
+}
+
+fn catalog(lang: Lang) -> &'static HashMap<String, String> {
+    static CATALOGS: OnceLock<[HashMap<String, String>; 2]> = OnceLock::new();
+    let catalogs = CATALOGS.get_or_init(|| {
+        Lang::ALL.map(|lang| {
+            parse_catalog(lang.catalog_source())
+                .unwrap_or_else(|e| panic!("locales/{}.txt {}", lang.code(), e))
+                .into_iter()
+                .collect()
+        })
+    });
+    &catalogs[lang as usize]
+}
+
+/// 在指定语言的目录中查找
+pub fn lookup(lang: Lang, key: &str) -> Option<&'static str> {
+    catalog(lang).get(key).map(String::as_str)
+}
+
+/// 当前语言下 `key` 对应的文字；缺失时退回中文，再退回键本身
+pub fn t(key: &str) -> &str {
+    lookup(lang(), key)
+        .or_else(|| lookup(Lang::Zh, key))
+        .unwrap_or(key)
+}
+
+/// 取出文字后依次用 `args` 替换其中的 `{}`
+pub fn tf(key: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut out = String::new();
+    let mut args = args.iter();
+    let mut rest = t(key);
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        if let Some(arg) = args.next() {
+            out.push_str(&arg.to_string());
+        }
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,533 @@
+//! IP 地址：解析、格式化和分类 IPv4 / IPv6 地址
+//!
+//! 第 7 课的 `IpAddr::V4`、`IpAddr::V6` 携带这里的 [`Ipv4`]、[`Ipv6`]，而不是任意字符串。
+//! 解析规则与标准库 `std::net` 一致：
+//!
+//! - IPv4 是点分十进制 `192.168.1.20`，正好 4 段，每段 0..=255，不允许前导零（`01` 可能被当成八进制）
+//! - IPv6 是 8 段 1 到 4 位的十六进制数，用 `:` 分隔，不区分大小写
+//! - IPv6 中可以用一个 `::` 省略连续的全零段，例如 `fe80::1`、`::1`、`::`
+//! - IPv6 的最后 32 位可以写成内嵌的 IPv4，例如 `::ffff:192.0.2.1`、`64:ff9b::192.0.2.33`
+//! - 不接受前后空白、`[::1]` 这样的方括号和 `%eth0` 这样的区域标识
+//!
+//! 格式化按 RFC 5952：十六进制小写、去掉前导零，最长的一串（至少两段）全零段写成 `::`，
+//! IPv4 映射地址写成 `::ffff:a.b.c.d`。
+//!
+//! ```
+//! use rust_learning::enums::IpAddr;
+//! use rust_learning::ip::Ipv6;
+//!
+//! let addr: IpAddr = "2001:DB8:0:0:0:0:0:1".parse().unwrap();
+//! assert_eq!(addr.to_string(), "2001:db8::1");
+//! assert!("192.168.1.20".parse::<IpAddr>().unwrap().is_private());
+//! assert_eq!("::1".parse(), Ok(IpAddr::V6(Ipv6::LOCALHOST)));
+//! ```
+
+use std::fmt;
+use std::net;
+use std::str::FromStr;
+
+use crate::enums::IpAddr;
+
+/// IP 地址解析错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpError {
+    Empty,
+    /// IPv4 地址不是 4 段
+    OctetCount(usize),
+    /// IPv4 的一段为空、含非数字字符或有前导零
+    InvalidOctet(String),
+    OctetOutOfRange(String),
+    /// IPv6 的一段不是 1 到 4 位十六进制数
+    InvalidGroup(String),
+    /// 没有 `::` 时不是 8 段，或者有 `::` 时已经有 8 段
+    GroupCount(usize),
+    MultipleCompressions,
+    /// 以单个 `:` 开头或结尾，例如 `:1::2`、`1::2:`
+    MisplacedColon,
+    /// 内嵌的 IPv4 不在末尾，例如 `::1.2.3.4:5`
+    MisplacedIpv4,
+}
+
+impl fmt::Display for IpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpError::Empty => write!(f, "地址为空"),
+            IpError::OctetCount(count) => write!(f, "IPv4 地址应有 4 段，实际 {} 段", count),
+            IpError::InvalidOctet(octet) => {
+                write!(f, "IPv4 的 {:?} 不是不带前导零的十进制数", octet)
+            }
+            IpError::OctetOutOfRange(octet) => write!(f, "IPv4 的 {} 超出 0..=255", octet),
+            IpError::InvalidGroup(group) => {
+                write!(f, "IPv6 的 {:?} 不是 1 到 4 位十六进制数", group)
+            }
+            IpError::GroupCount(count) => write!(
+                f,
+                "IPv6 地址应有 8 段（用 :: 省略时少于 8 段），实际 {} 段",
+                count
+            ),
+            IpError::MultipleCompressions => write!(f, "IPv6 地址中只能有一个 ::"),
+            IpError::MisplacedColon => write!(f, "IPv6 地址不能以单个 : 开头或结尾"),
+            IpError::MisplacedIpv4 => write!(f, "内嵌的 IPv4 地址只能出现在末尾"),
+        }
+    }
+}
+
+impl std::error::Error for IpError {}
+
+/// IPv4 地址，按网络字节序保存 4 个字节
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ipv4 {
+    octets: [u8; 4],
+}
+
+impl Ipv4 {
+    pub const UNSPECIFIED: Ipv4 = Ipv4::new(0, 0, 0, 0);
+    pub const LOCALHOST: Ipv4 = Ipv4::new(127, 0, 0, 1);
+    pub const BROADCAST: Ipv4 = Ipv4::new(255, 255, 255, 255);
+
+    pub const fn new(a: u8, b: u8, c: u8, d: u8) -> Ipv4 {
+        Ipv4 {
+            octets: [a, b, c, d],
+        }
+    }
+
+    /// 解析点分十进制地址
+    pub fn parse(input: &str) -> Result<Ipv4, IpError> {
+        if input.is_empty() {
+            return Err(IpError::Empty);
+        }
+        let parts: Vec<&str> = input.split('.').collect();
+        if parts.len() != 4 {
+            return Err(IpError::OctetCount(parts.len()));
+        }
+        let mut octets = [0; 4];
+        for (octet, part) in octets.iter_mut().zip(parts) {
+            *octet = parse_octet(part)?;
+        }
+        Ok(Ipv4 { octets })
+    }
+
+    pub const fn octets(self) -> [u8; 4] {
+        self.octets
+    }
+
+    /// 地址对应的 32 位整数，`1.2.3.4` 是 `0x01020304`
+    pub const fn to_bits(self) -> u32 {
+        u32::from_be_bytes(self.octets)
+    }
+
+    pub const fn from_bits(bits: u32) -> Ipv4 {
+        Ipv4 {
+            octets: bits.to_be_bytes(),
+        }
+    }
+
+    /// `0.0.0.0`
+    pub fn is_unspecified(self) -> bool {
+        self == Ipv4::UNSPECIFIED
+    }
+
+    /// `127.0.0.0/8`
+    pub fn is_loopback(self) -> bool {
+        self.octets[0] == 127
+    }
+
+    /// RFC 1918 私有地址：`10.0.0.0/8`、`172.16.0.0/12`、`192.168.0.0/16`
+    pub fn is_private(self) -> bool {
+        match self.octets {
+            [10, ..] => true,
+            [172, b, ..] => (16..=31).contains(&b),
+            [192, 168, ..] => true,
+            _ => false,
+        }
+    }
+
+    /// 链路本地地址 `169.254.0.0/16`
+    pub fn is_link_local(self) -> bool {
+        matches!(self.octets, [169, 254, ..])
+    }
+
+    /// 组播地址 `224.0.0.0/4`
+    pub fn is_multicast(self) -> bool {
+        self.octets[0] & 0xf0 == 224
+    }
+
+    /// 受限广播地址 `255.255.255.255`
+    pub fn is_broadcast(self) -> bool {
+        self == Ipv4::BROADCAST
+    }
+
+    /// IPv4 映射的 IPv6 地址 `::ffff:a.b.c.d`
+    pub fn to_ipv6_mapped(self) -> Ipv6 {
+        let [a, b, c, d] = self.octets;
+        Ipv6::new(
+            0,
+            0,
+            0,
+            0,
+            0,
+            0xffff,
+            u16::from_be_bytes([a, b]),
+            u16::from_be_bytes([c, d]),
+        )
+    }
+}
+
+/// 一段十进制数：不能为空、只能有数字、除 `0` 本身外不能以 `0` 开头
+fn parse_octet(part: &str) -> Result<u8, IpError> {
+    let invalid = || IpError::InvalidOctet(part.to_string());
+    if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    if part.len() > 1 && part.starts_with('0') {
+        return Err(invalid());
+    }
+    part.parse()
+        .map_err(|_| IpError::OctetOutOfRange(part.to_string()))
+}
+
+/// IPv6 地址，保存 8 个 16 位段
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ipv6 {
+    segments: [u16; 8],
+}
+
+impl Ipv6 {
+    pub const UNSPECIFIED: Ipv6 = Ipv6::new(0, 0, 0, 0, 0, 0, 0, 0);
+    pub const LOCALHOST: Ipv6 = Ipv6::new(0, 0, 0, 0, 0, 0, 0, 1);
+
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(a: u16, b: u16, c: u16, d: u16, e: u16, f: u16, g: u16, h: u16) -> Ipv6 {
+        Ipv6 {
+            segments: [a, b, c, d, e, f, g, h],
+        }
+    }
+
+    /// 解析冒号分隔的十六进制地址，支持 `::` 和末尾内嵌的 IPv4
+    pub fn parse(input: &str) -> Result<Ipv6, IpError> {
+        if input.is_empty() {
+            return Err(IpError::Empty);
+        }
+        let segments = match input.split_once("::") {
+            None => {
+                let groups = parse_groups(input, true)?;
+                if groups.len() != 8 {
+                    return Err(IpError::GroupCount(groups.len()));
+                }
+                groups
+            }
+            Some((head, tail)) => {
+                if tail.contains("::") {
+                    return Err(IpError::MultipleCompressions);
+                }
+                // 内嵌的 IPv4 只能在末尾，所以 `::` 前面不允许
+                let head = parse_groups(head, false)?;
+                let tail = parse_groups(tail, true)?;
+                let count = head.len() + tail.len();
+                // `::` 至少代表一个全零段
+                if count > 7 {
+                    return Err(IpError::GroupCount(count));
+                }
+                let mut segments = head;
+                segments.resize(8 - tail.len(), 0);
+                segments.extend(tail);
+                segments
+            }
+        };
+        let mut address = Ipv6::UNSPECIFIED;
+        address.segments.copy_from_slice(&segments);
+        Ok(address)
+    }
+
+    pub const fn segments(self) -> [u16; 8] {
+        self.segments
+    }
+
+    pub fn octets(self) -> [u8; 16] {
+        self.to_bits().to_be_bytes()
+    }
+
+    pub fn to_bits(self) -> u128 {
+        self.segments
+            .iter()
+            .fold(0, |bits, &segment| (bits << 16) | u128::from(segment))
+    }
+
+    pub fn from_bits(bits: u128) -> Ipv6 {
+        let mut segments = [0; 8];
+        for (i, segment) in segments.iter_mut().enumerate() {
+            *segment = (bits >> (112 - 16 * i)) as u16;
+        }
+        Ipv6 { segments }
+    }
+
+    /// `::`
+    pub fn is_unspecified(self) -> bool {
+        self == Ipv6::UNSPECIFIED
+    }
+
+    /// `::1`
+    pub fn is_loopback(self) -> bool {
+        self == Ipv6::LOCALHOST
+    }
+
+    /// 唯一本地地址（ULA）`fc00::/7`，IPv6 中相当于 IPv4 私有地址的范围
+    pub fn is_private(self) -> bool {
+        self.segments[0] & 0xfe00 == 0xfc00
+    }
+
+    /// 链路本地单播地址 `fe80::/10`
+    pub fn is_link_local(self) -> bool {
+        self.segments[0] & 0xffc0 == 0xfe80
+    }
+
+    /// 组播地址 `ff00::/8`
+    pub fn is_multicast(self) -> bool {
+        self.segments[0] & 0xff00 == 0xff00
+    }
+
+    /// `::ffff:a.b.c.d` 形式的地址对应的 IPv4 地址
+    pub fn to_ipv4_mapped(self) -> Option<Ipv4> {
+        match self.segments {
+            [0, 0, 0, 0, 0, 0xffff, high, low] => {
+                let [a, b] = high.to_be_bytes();
+                let [c, d] = low.to_be_bytes();
+                Some(Ipv4::new(a, b, c, d))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 解析 `::` 一侧冒号分隔的若干段；`allow_ipv4` 时最后一段可以是内嵌的 IPv4（算两段）
+fn parse_groups(text: &str, allow_ipv4: bool) -> Result<Vec<u16>, IpError> {
+    let mut groups = Vec::new();
+    if text.is_empty() {
+        return Ok(groups);
+    }
+    let parts: Vec<&str> = text.split(':').collect();
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() {
+            return Err(IpError::MisplacedColon);
+        }
+        if part.contains('.') {
+            if !allow_ipv4 || i + 1 != parts.len() {
+                return Err(IpError::MisplacedIpv4);
+            }
+            let [a, b, c, d] = Ipv4::parse(part)?.octets();
+            groups.push(u16::from_be_bytes([a, b]));
+            groups.push(u16::from_be_bytes([c, d]));
+        } else if (1..=4).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_hexdigit()) {
+            groups.push(u16::from_str_radix(part, 16).expect("已检查过是十六进制"));
+        } else {
+            return Err(IpError::InvalidGroup(part.to_string()));
+        }
+        if groups.len() > 8 {
+            return Err(IpError::GroupCount(groups.len()));
+        }
+    }
+    Ok(groups)
+}
+
+impl fmt::Display for Ipv4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d] = self.octets;
+        write!(f, "{}.{}.{}.{}", a, b, c, d)
+    }
+}
+
+impl fmt::Display for Ipv6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ipv4) = self.to_ipv4_mapped() {
+            return write!(f, "::ffff:{}", ipv4);
+        }
+
+        // 找最长的一串全零段（长度相同取第一串），只有一段时不压缩
+        let mut longest = 0..0;
+        let mut start = None;
+        for i in 0..=8 {
+            match (self.segments.get(i), start) {
+                (Some(0), None) => start = Some(i),
+                (Some(0), Some(_)) => {}
+                (_, Some(s)) => {
+                    if i - s > longest.len() {
+                        longest = s..i;
+                    }
+                    start = None;
+                }
+                (_, None) => {}
+            }
+        }
+
+        let write_groups = |f: &mut fmt::Formatter, groups: &[u16]| {
+            for (i, group) in groups.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ":")?;
+                }
+                write!(f, "{:x}", group)?;
+            }
+            Ok(())
+        };
+        if longest.len() < 2 {
+            write_groups(f, &self.segments)
+        } else {
+            write_groups(f, &self.segments[..longest.start])?;
+            write!(f, "::")?;
+            write_groups(f, &self.segments[longest.end..])
+        }
+    }
+}
+
+// 和 `Email` 一样按字符串显示，`{:?}` 打印为 `"127.0.0.1"`
+impl fmt::Debug for Ipv4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl fmt::Debug for Ipv6 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl FromStr for Ipv4 {
+    type Err = IpError;
+
+    fn from_str(s: &str) -> Result<Ipv4, IpError> {
+        Ipv4::parse(s)
+    }
+}
+
+impl FromStr for Ipv6 {
+    type Err = IpError;
+
+    fn from_str(s: &str) -> Result<Ipv6, IpError> {
+        Ipv6::parse(s)
+    }
+}
+
+// ===== 第 7 课的 IpAddr =====
+
+impl IpAddr {
+    /// 含 `:` 的按 IPv6 解析，否则按 IPv4 解析
+    pub fn parse(input: &str) -> Result<IpAddr, IpError> {
+        if input.contains(':') {
+            Ipv6::parse(input).map(IpAddr::V6)
+        } else {
+            Ipv4::parse(input).map(IpAddr::V4)
+        }
+    }
+
+    pub fn is_unspecified(&self) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_unspecified(),
+            IpAddr::V6(ip) => ip.is_unspecified(),
+        }
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_loopback(),
+            IpAddr::V6(ip) => ip.is_loopback(),
+        }
+    }
+
+    pub fn is_private(&self) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_private(),
+            IpAddr::V6(ip) => ip.is_private(),
+        }
+    }
+
+    pub fn is_link_local(&self) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_link_local(),
+            IpAddr::V6(ip) => ip.is_link_local(),
+        }
+    }
+
+    pub fn is_multicast(&self) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_multicast(),
+            IpAddr::V6(ip) => ip.is_multicast(),
+        }
+    }
+}
+
+impl fmt::Display for IpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpAddr::V4(ip) => ip.fmt(f),
+            IpAddr::V6(ip) => ip.fmt(f),
+        }
+    }
+}
+
+impl FromStr for IpAddr {
+    type Err = IpError;
+
+    fn from_str(s: &str) -> Result<IpAddr, IpError> {
+        IpAddr::parse(s)
+    }
+}
+
+impl From<Ipv4> for IpAddr {
+    fn from(ip: Ipv4) -> IpAddr {
+        IpAddr::V4(ip)
+    }
+}
+
+impl From<Ipv6> for IpAddr {
+    fn from(ip: Ipv6) -> IpAddr {
+        IpAddr::V6(ip)
+    }
+}
+
+// ===== 与标准库互转 =====
+
+impl From<Ipv4> for net::Ipv4Addr {
+    fn from(ip: Ipv4) -> net::Ipv4Addr {
+        net::Ipv4Addr::from(ip.octets)
+    }
+}
+
+impl From<net::Ipv4Addr> for Ipv4 {
+    fn from(ip: net::Ipv4Addr) -> Ipv4 {
+        Ipv4 {
+            octets: ip.octets(),
+        }
+    }
+}
+
+impl From<Ipv6> for net::Ipv6Addr {
+    fn from(ip: Ipv6) -> net::Ipv6Addr {
+        net::Ipv6Addr::from(ip.segments)
+    }
+}
+
+impl From<net::Ipv6Addr> for Ipv6 {
+    fn from(ip: net::Ipv6Addr) -> Ipv6 {
+        Ipv6 {
+            segments: ip.segments(),
+        }
+    }
+}
+
+impl From<IpAddr> for net::IpAddr {
+    fn from(ip: IpAddr) -> net::IpAddr {
+        match ip {
+            IpAddr::V4(ip) => net::IpAddr::V4(ip.into()),
+            IpAddr::V6(ip) => net::IpAddr::V6(ip.into()),
+        }
+    }
+}
+
+impl From<net::IpAddr> for IpAddr {
+    fn from(ip: net::IpAddr) -> IpAddr {
+        match ip {
+            net::IpAddr::V4(ip) => IpAddr::V4(ip.into()),
+            net::IpAddr::V6(ip) => IpAddr::V6(ip.into()),
+        }
+    }
+}
@@ -0,0 +1,444 @@
+//! 任务日志：把 `Task` 的每次状态转换追加写入文件，启动时重放以恢复任务
+//!
+//! 每行一条记录，先写日志、再做转换（write-ahead）；转换规则和第 6 课的 `Task` 方法相同，但不打印。
+//! 空行和 `#` 开头的行会被忽略：
+//!
+//! ```text
+//! # rust-learning task journal
+//! 1 new Backup
+//! 1 start
+//! 1 pause
+//! 1 resume
+//! 1 complete
+//! 2 new Upload
+//! 2 start
+//! 2 fail disk full
+//! 3 new Report
+//! 3 cancel
+//! next 4
+//! ```
+//!
+//! 重放时仍处于 Running 的任务说明上次运行中途退出，会被追加一条 `requeue` 放回 Ready。
+//! 写到一半的最后一行（没有换行符）视为崩溃时的残留，恢复时丢弃。
+//! [`Journal::compact`] 去掉已结束的任务，只保留还活着的任务到达当前状态所需的最少记录。
+//!
+//! 每条记录写入后都会 `sync_data`，确认落盘后才做转换；整体重写文件时先把临时文件同步到磁盘，
+//! 改名后再同步所在目录，断电也不会留下空的或截断的日志。
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::files::atomic_write;
+use crate::structs::{
+    Active, Cancelled, Completed, Failed, Paused, Ready, Running, State, Task, TaskState,
+};
+
+const HEADER: &str = "# rust-learning task journal";
+
+/// 日志中的一个事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    New(String),
+    Start,
+    Pause,
+    Resume,
+    Complete,
+    Fail(String),
+    Cancel,
+    /// 恢复时把中断的 Running 任务放回 Ready
+    Requeue,
+}
+
+/// 日志的一行：`<编号> <事件> [参数]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub id: u64,
+    pub event: Event,
+}
+
+impl Entry {
+    pub fn to_line(&self) -> String {
+        let id = self.id;
+        match &self.event {
+            Event::New(name) => format!("{} new {}", id, name),
+            Event::Start => format!("{} start", id),
+            Event::Pause => format!("{} pause", id),
+            Event::Resume => format!("{} resume", id),
+            Event::Complete => format!("{} complete", id),
+            Event::Fail(reason) => format!("{} fail {}", id, reason),
+            Event::Cancel => format!("{} cancel", id),
+            Event::Requeue => format!("{} requeue", id),
+        }
+    }
+
+    /// 解析一行；不认识的事件返回 `None`
+    pub fn parse(line: &str) -> Option<Entry> {
+        let (id, rest) = line.split_once(' ')?;
+        let id = id.parse().ok()?;
+        let (event, arg) = rest.split_once(' ').unwrap_or((rest, ""));
+        let event = match (event, arg) {
+            ("new", name) => Event::New(name.to_string()),
+            ("fail", reason) => Event::Fail(reason.to_string()),
+            ("start", "") => Event::Start,
+            ("pause", "") => Event::Pause,
+            ("resume", "") => Event::Resume,
+            ("complete", "") => Event::Complete,
+            ("cancel", "") => Event::Cancel,
+            ("requeue", "") => Event::Requeue,
+            _ => return None,
+        };
+        Some(Entry { id, event })
+    }
+}
+
+/// 重放得到的一个任务的当前状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub name: String,
+    pub state: TaskState,
+    /// Failed 任务的失败原因
+    pub reason: Option<String>,
+}
+
+impl Record {
+    /// 应用一个事件；不合法的转换返回 `None`
+    fn apply(&self, event: &Event) -> Option<Record> {
+        use TaskState::*;
+        let state = match (self.state, event) {
+            (Ready, Event::Start) | (Paused, Event::Resume) => Running,
+            (Running, Event::Requeue) => Ready,
+            (Running, Event::Pause) => Paused,
+            (Running, Event::Complete) => Completed,
+            (Running, Event::Fail(_)) => Failed,
+            (Ready | Running | Paused, Event::Cancel) => Cancelled,
+            _ => return None,
+        };
+        let reason = match event {
+            Event::Fail(reason) => Some(reason.clone()),
+            _ => None,
+        };
+        Some(Record {
+            name: self.name.clone(),
+            state,
+            reason,
+        })
+    }
+}
+
+/// 日志中登记过的任务，带上它在日志里的编号
+///
+/// 只能由 [`Journal`] 创建，字段不公开，免得拼出一个和日志对不上的任务
+#[derive(Debug)]
+pub struct Tracked<S: State> {
+    id: u64,
+    task: Task<S>,
+}
+
+impl<S: State> Tracked<S> {
+    /// 任务在日志里的编号
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn task(&self) -> &Task<S> {
+        &self.task
+    }
+
+    pub fn into_task(self) -> Task<S> {
+        self.task
+    }
+}
+
+/// 启动时恢复出来、还需要继续处理的任务
+#[derive(Debug, Default)]
+pub struct Recovery {
+    /// 等待执行的任务，包括被重新排队的任务
+    pub ready: Vec<Tracked<Ready>>,
+    pub paused: Vec<Tracked<Paused>>,
+    /// 上次中断在 Running、这次被放回 Ready 的任务编号
+    pub requeued: Vec<u64>,
+}
+
+fn invalid(line_no: usize, line: &str, reason: &str) -> io::Error {
+    io::Error::new(
+        ErrorKind::InvalidData,
+        format!("任务日志第 {} 行{}: {}", line_no, reason, line),
+    )
+}
+
+/// 重放日志文本，返回每个任务的状态和下一个可用编号
+pub fn replay(text: &str) -> io::Result<(BTreeMap<u64, Record>, u64)> {
+    let mut tasks: BTreeMap<u64, Record> = BTreeMap::new();
+    let mut next_id = 1;
+    // lines() 只去掉行尾的 \n 或 \r\n；不能再 trim，任务名和失败原因末尾的空格也是内容
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(next) = line.strip_prefix("next ") {
+            let next: u64 = next
+                .parse()
+                .map_err(|_| invalid(index + 1, line, "无法解析"))?;
+            next_id = next_id.max(next);
+            continue;
+        }
+        let entry = Entry::parse(line).ok_or_else(|| invalid(index + 1, line, "无法解析"))?;
+        let record = match (&entry.event, tasks.get(&entry.id)) {
+            (Event::New(name), None) => Record {
+                name: name.clone(),
+                state: TaskState::Ready,
+                reason: None,
+            },
+            (Event::New(_), Some(_)) => return Err(invalid(index + 1, line, "重复的任务编号")),
+            (_, None) => return Err(invalid(index + 1, line, "引用了未登记的任务")),
+            (event, Some(record)) => record
+                .apply(event)
+                .ok_or_else(|| invalid(index + 1, line, "不合法的状态转换"))?,
+        };
+        tasks.insert(entry.id, record);
+        next_id = next_id.max(entry.id + 1);
+    }
+    Ok((tasks, next_id))
+}
+
+/// 追加写入的任务日志
+pub struct Journal {
+    path: PathBuf,
+    file: File,
+    tasks: BTreeMap<u64, Record>,
+    next_id: u64,
+    /// 文件中的记录行数，用来判断是否值得压缩
+    entries: usize,
+}
+
+impl Journal {
+    /// 打开（或创建）日志并重放；中断的 Running 任务会被重新排队
+    pub fn open(path: &Path) -> io::Result<(Journal, Recovery)> {
+        let mut text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        // 崩溃时可能只写了半行：丢弃最后一个换行符之后的内容
+        let complete = text.rfind('\n').map_or(0, |end| end + 1);
+        let torn = complete < text.len();
+        text.truncate(complete);
+
+        let (tasks, next_id) = replay(&text)?;
+        let entries = text
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .count();
+        if text.is_empty() || torn {
+            let content = if text.is_empty() {
+                format!("{}\n", HEADER)
+            } else {
+                text
+            };
+            atomic_write(path, &content)?;
+        }
+        let file = OpenOptions::new().append(true).open(path)?;
+        let mut journal = Journal {
+            path: path.to_path_buf(),
+            file,
+            tasks,
+            next_id,
+            entries,
+        };
+
+        let mut recovery = Recovery::default();
+        let interrupted: Vec<u64> = journal
+            .tasks
+            .iter()
+            .filter(|(_, record)| record.state == TaskState::Running)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in interrupted {
+            journal.append(id, Event::Requeue)?;
+            recovery.requeued.push(id);
+        }
+        for (&id, record) in &journal.tasks {
+            match record.state {
+                TaskState::Ready => recovery.ready.push(Tracked {
+                    id,
+                    task: Task::<Ready>::restore(&record.name, ()),
+                }),
+                TaskState::Paused => recovery.paused.push(Tracked {
+                    id,
+                    task: Task::<Paused>::restore(&record.name, ()),
+                }),
+                _ => {}
+            }
+        }
+        Ok((journal, recovery))
+    }
+
+    /// 每个任务的当前状态
+    pub fn tasks(&self) -> &BTreeMap<u64, Record> {
+        &self.tasks
+    }
+
+    /// 文件中的记录行数
+    pub fn len(&self) -> usize {
+        self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries == 0
+    }
+
+    /// 检查转换合法后写入一条记录，再更新内存中的状态
+    ///
+    /// 先检查再写：不合法的记录一旦落盘，下次重放就会失败，日志再也打不开。
+    /// 来自别的日志或已经过时的 `Tracked` 会在这里被拒绝。
+    fn append(&mut self, id: u64, event: Event) -> io::Result<()> {
+        let record = match (&event, self.tasks.get(&id)) {
+            (Event::New(name), None) => Some(Record {
+                name: name.clone(),
+                state: TaskState::Ready,
+                reason: None,
+            }),
+            (Event::New(_), Some(_)) => None,
+            (event, record) => record.and_then(|record| record.apply(event)),
+        };
+        let entry = Entry { id, event };
+        let Some(record) = record else {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("任务 {} 不能执行 `{}`", id, entry.to_line()),
+            ));
+        };
+        writeln!(self.file, "{}", entry.to_line())?;
+        self.file.flush()?;
+        // flush 只是交给操作系统，sync_data 才保证断电后记录还在
+        self.file.sync_data()?;
+        self.entries += 1;
+        self.tasks.insert(id, record);
+        Ok(())
+    }
+
+    /// 登记一个新任务
+    pub fn create(&mut self, name: &str) -> io::Result<Tracked<Ready>> {
+        // 重放时 lines() 会去掉行尾的 \r，所以 \r 也不能出现
+        if name.contains(['\n', '\r']) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "任务名不能包含换行符或回车符",
+            ));
+        }
+        let id = self.next_id;
+        self.append(id, Event::New(name.to_string()))?;
+        self.next_id += 1;
+        Ok(Tracked {
+            id,
+            task: Task::new(name),
+        })
+    }
+
+    pub fn start(&mut self, tracked: Tracked<Ready>) -> io::Result<Tracked<Running>> {
+        self.append(tracked.id, Event::Start)?;
+        Ok(Tracked {
+            id: tracked.id,
+            task: tracked.task.start_quietly(),
+        })
+    }
+
+    pub fn pause(&mut self, tracked: Tracked<Running>) -> io::Result<Tracked<Paused>> {
+        self.append(tracked.id, Event::Pause)?;
+        Ok(Tracked {
+            id: tracked.id,
+            task: tracked.task.pause_quietly(),
+        })
+    }
+
+    pub fn resume(&mut self, tracked: Tracked<Paused>) -> io::Result<Tracked<Running>> {
+        self.append(tracked.id, Event::Resume)?;
+        Ok(Tracked {
+            id: tracked.id,
+            task: tracked.task.resume_quietly(),
+        })
+    }
+
+    pub fn complete(&mut self, tracked: Tracked<Running>) -> io::Result<Tracked<Completed>> {
+        self.append(tracked.id, Event::Complete)?;
+        Ok(Tracked {
+            id: tracked.id,
+            task: tracked.task.complete_quietly(),
+        })
+    }
+
+    pub fn fail(&mut self, tracked: Tracked<Running>, reason: &str) -> io::Result<Tracked<Failed>> {
+        // 原因占据行尾，换行符和回车符替换为空格
+        let reason = reason.replace(['\n', '\r'], " ");
+        self.append(tracked.id, Event::Fail(reason.clone()))?;
+        Ok(Tracked {
+            id: tracked.id,
+            task: tracked.task.fail_quietly(reason),
+        })
+    }
+
+    pub fn cancel<S: Active>(&mut self, tracked: Tracked<S>) -> io::Result<Tracked<Cancelled>> {
+        self.append(tracked.id, Event::Cancel)?;
+        Ok(Tracked {
+            id: tracked.id,
+            task: tracked.task.cancel_quietly(),
+        })
+    }
+
+    /// 压缩：去掉已结束的任务，其余任务只保留到达当前状态的最少记录。
+    /// 先写临时文件并同步到磁盘再改名，中途崩溃不会损坏原日志。
+    pub fn compact(&mut self) -> io::Result<()> {
+        let mut lines = vec![HEADER.to_string()];
+        let mut live = BTreeMap::new();
+        for (&id, record) in &self.tasks {
+            let events: &[Event] = match record.state {
+                TaskState::Ready => &[],
+                TaskState::Running => &[Event::Start],
+                TaskState::Paused => &[Event::Start, Event::Pause],
+                _ => continue,
+            };
+            lines.push(
+                Entry {
+                    id,
+                    event: Event::New(record.name.clone()),
+                }
+                .to_line(),
+            );
+            for event in events {
+                lines.push(
+                    Entry {
+                        id,
+                        event: event.clone(),
+                    }
+                    .to_line(),
+                );
+            }
+            live.insert(id, record.clone());
+        }
+        // 保留编号计数，避免压缩后重复使用已结束任务的编号
+        lines.push(format!("next {}", self.next_id));
+
+        let entries = lines.len() - 1;
+        atomic_write(&self.path, &(lines.join("\n") + "\n"))?;
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.tasks = live;
+        self.entries = entries;
+        Ok(())
+    }
+
+    /// 记录行数超过活着的任务数的 `factor` 倍（且至少 `min` 行）时压缩，返回是否压缩了
+    pub fn compact_if_needed(&mut self, min: usize, factor: usize) -> io::Result<bool> {
+        let live = self
+            .tasks
+            .values()
+            .filter(|record| !record.state.is_terminal())
+            .count();
+        if self.entries < min || self.entries <= live.max(1) * factor {
+            return Ok(false);
+        }
+        self.compact()?;
+        Ok(true)
+    }
+}
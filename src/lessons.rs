@@ -0,0 +1,202 @@
+//! 课程注册表：每节课的编号、阶段和入口函数都只在这里登记一次
+//!
+//! 主题和简介是要翻译的文字，放在 `locales/` 的消息目录里，键为 `lesson.<短名称>.topic`
+//! 和 `lesson.<短名称>.summary`。
+
+use crate::i18n::{self, Lang};
+
+/// README「学习路径建议」中的四个阶段
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    #[default]
+    Basics,
+    Core,
+    Abstraction,
+    Advanced,
+}
+
+impl Section {
+    /// 按学习顺序排列的全部阶段
+    pub const ALL: [Section; 4] = [
+        Section::Basics,
+        Section::Core,
+        Section::Abstraction,
+        Section::Advanced,
+    ];
+
+    /// 消息目录中的键
+    pub fn key(self) -> &'static str {
+        match self {
+            Section::Basics => "section.basics",
+            Section::Core => "section.core",
+            Section::Abstraction => "section.abstraction",
+            Section::Advanced => "section.advanced",
+        }
+    }
+
+    /// 与 README 保持一致的阶段名称（当前语言）
+    pub fn title(self) -> &'static str {
+        i18n::t(self.key())
+    }
+}
+
+/// 一节课：`src/bin` 下的一个二进制，代码在库中的一个模块里
+pub struct Lesson {
+    pub number: u8,
+    pub name: &'static str,
+    pub section: Section,
+    /// 课程代码所在的源文件（相对仓库根目录）
+    pub source: &'static str,
+    pub run: fn(),
+}
+
+impl Lesson {
+    /// 去掉编号前缀的短名称，例如 `06_structs` -> `structs`
+    pub fn short_name(&self) -> &'static str {
+        self.name
+            .split_once('_')
+            .map_or(self.name, |(_, rest)| rest)
+    }
+
+    /// 主题（当前语言），例如「结构体」
+    pub fn topic(&self) -> &'static str {
+        self.text("topic")
+    }
+
+    /// 内容简介（当前语言）
+    pub fn summary(&self) -> &'static str {
+        self.text("summary")
+    }
+
+    /// 消息目录中本课文字的键，`field` 为 `topic` 或 `summary`
+    pub fn key(&self, field: &str) -> String {
+        format!("lesson.{}.{}", self.short_name(), field)
+    }
+
+    fn text(&self, field: &str) -> &'static str {
+        let key = self.key(field);
+        i18n::lookup(i18n::lang(), &key)
+            .or_else(|| i18n::lookup(Lang::Zh, &key))
+            .unwrap_or(self.name)
+    }
+}
+
+pub const LESSONS: &[Lesson] = &[
+    Lesson {
+        number: 1,
+        name: "01_variables",
+        section: Section::Basics,
+        source: "src/variables.rs",
+        run: crate::variables::run,
+    },
+    Lesson {
+        number: 2,
+        name: "02_data_types",
+        section: Section::Basics,
+        source: "src/data_types.rs",
+        run: crate::data_types::run,
+    },
+    Lesson {
+        number: 3,
+        name: "03_functions",
+        section: Section::Basics,
+        source: "src/functions.rs",
+        run: crate::functions::run,
+    },
+    Lesson {
+        number: 4,
+        name: "04_control_flow",
+        section: Section::Basics,
+        source: "src/control_flow.rs",
+        run: crate::control_flow::run,
+    },
+    Lesson {
+        number: 5,
+        name: "05_ownership",
+        section: Section::Core,
+        source: "src/ownership.rs",
+        run: crate::ownership::run,
+    },
+    Lesson {
+        number: 6,
+        name: "06_structs",
+        section: Section::Core,
+        source: "src/structs.rs",
+        run: crate::structs::run,
+    },
+    Lesson {
+        number: 7,
+        name: "07_enums",
+        section: Section::Core,
+        source: "src/enums.rs",
+        run: crate::enums::run,
+    },
+    Lesson {
+        number: 8,
+        name: "08_collections",
+        section: Section::Core,
+        source: "src/collections.rs",
+        run: crate::collections::run,
+    },
+    Lesson {
+        number: 9,
+        name: "09_modules",
+        section: Section::Abstraction,
+        source: "src/modules/mod.rs",
+        run: crate::modules::run,
+    },
+    Lesson {
+        number: 10,
+        name: "10_error_handling",
+        section: Section::Advanced,
+        source: "src/error_handling.rs",
+        run: crate::error_handling::run,
+    },
+    Lesson {
+        number: 11,
+        name: "11_generics_traits",
+        section: Section::Abstraction,
+        source: "src/generics_traits.rs",
+        run: crate::generics_traits::run,
+    },
+    Lesson {
+        number: 12,
+        name: "12_lifetimes",
+        section: Section::Abstraction,
+        source: "src/lifetimes.rs",
+        run: crate::lifetimes::run,
+    },
+    Lesson {
+        number: 13,
+        name: "13_macro",
+        section: Section::Advanced,
+        source: "src/macros.rs",
+        run: crate::macros::run,
+    },
+    Lesson {
+        number: 14,
+        name: "14_trait",
+        section: Section::Abstraction,
+        source: "src/traits.rs",
+        run: crate::traits::run,
+    },
+];
+
+/// 按编号（`6`、`06`）、完整名称（`06_structs`）或短名称（`structs`）查找课程
+pub fn find(query: &str) -> Option<&'static Lesson> {
+    if let Ok(number) = query.parse::<u8>() {
+        return LESSONS.iter().find(|lesson| lesson.number == number);
+    }
+    LESSONS
+        .iter()
+        .find(|lesson| lesson.name == query || lesson.short_name() == query)
+}
+
+/// 按 README「学习路径建议」的顺序（先按阶段，阶段内按编号）排列的课程
+pub fn learning_path() -> impl Iterator<Item = &'static Lesson> {
+    Section::ALL.into_iter().flat_map(|section| {
+        LESSONS
+            .iter()
+            .filter(move |lesson| lesson.section == section)
+    })
+}
@@ -0,0 +1,43 @@
+//! Rust 学习指南的课程代码库
+//!
+//! 每节课是一个公开模块，`src/bin` 下的二进制只是调用对应模块的 `run`。
+//! 课程中定义的类型都可以直接复用，例如 `rust_learning::structs::User`。
+
+pub mod ansi;
+pub mod auth;
+pub mod cidr;
+pub mod codec;
+pub mod color;
+pub mod command;
+pub mod compare;
+pub mod crypto;
+pub mod email;
+pub mod exercises;
+pub mod i18n;
+pub mod ip;
+pub mod journal;
+pub mod lessons;
+pub mod messaging;
+pub mod progress;
+pub mod quiz;
+pub mod scheduler;
+pub mod site;
+pub mod users;
+pub mod vector;
+
+mod files;
+
+pub mod variables;
+pub mod data_types;
+pub mod functions;
+pub mod control_flow;
+pub mod ownership;
+pub mod structs;
+pub mod enums;
+pub mod collections;
+pub mod modules;
+pub mod error_handling;
+pub mod generics_traits;
+pub mod lifetimes;
+pub mod macros;
+pub mod traits;
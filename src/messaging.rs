@@ -0,0 +1,274 @@
+//! 消息服务：通过 TCP 收发第 7 课的 `Message`
+//!
+//! 客户端把消息按 [`crate::codec`] 的格式编码后发给服务端；服务端每解出一条完整的消息，
+//! 就交给 [`Handler`] 处理，再回复一条 [`Reply`]：
+//!
+//! - `Ack(n)`：第 n 条消息已处理（每个连接从 1 开始计数），编码为 `0x80` + `n: u32`
+//! - `Error(text)`：数据无法解码，随后服务端关闭连接，编码为 `0x81` + 长度 `u32` + UTF-8 文字
+//!
+//! 收到 `Quit` 时服务端先回复 `Ack`，然后关闭这个连接。
+//!
+//! ```bash
+//! cargo run --bin message_server                    # 监听 127.0.0.1:7878
+//! cargo run --bin message_client -- move 10 20 write "Hello Rust!" quit
+//! ```
+
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::thread;
+use std::time::Duration;
+
+use crate::codec::{CodecError, Decoder, MAX_TEXT_LEN};
+use crate::enums::{Handler, Message};
+
+/// 默认端口
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// 接受连接失败后默认等待多久再试，见 [`Server::set_accept_retry`]
+pub const ACCEPT_RETRY: Duration = Duration::from_millis(100);
+
+const TAG_ACK: u8 = 0x80;
+const TAG_ERROR: u8 = 0x81;
+
+/// 服务端对每条消息的回复
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    Ack(u32),
+    Error(String),
+}
+
+impl Reply {
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Reply::Ack(n) => {
+                let mut out = vec![TAG_ACK];
+                out.extend_from_slice(&n.to_be_bytes());
+                out
+            }
+            Reply::Error(text) => {
+                let mut out = vec![TAG_ERROR];
+                out.extend_from_slice(&(text.len() as u32).to_be_bytes());
+                out.extend_from_slice(text.as_bytes());
+                out
+            }
+        }
+    }
+
+    /// 从数据流中读取一条回复，阻塞直到读完
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Reply, ClientError> {
+        let mut tag = [0];
+        reader.read_exact(&mut tag)?;
+        let mut number = [0; 4];
+        reader.read_exact(&mut number)?;
+        let number = u32::from_be_bytes(number);
+        match tag[0] {
+            TAG_ACK => Ok(Reply::Ack(number)),
+            TAG_ERROR => {
+                // 和解码消息一样限制长度，不能让一个错误的长度字段占用大量内存
+                if number as usize > MAX_TEXT_LEN {
+                    return Err(CodecError::TextTooLong(number as usize).into());
+                }
+                let mut text = Vec::new();
+                reader.take(u64::from(number)).read_to_end(&mut text)?;
+                if text.len() != number as usize {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+                }
+                Ok(Reply::Error(String::from_utf8_lossy(&text).into_owned()))
+            }
+            tag => Err(ClientError::InvalidReply(tag)),
+        }
+    }
+}
+
+/// 一个连接是怎样结束的
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Closed {
+    /// 收到了 `Quit`
+    Quit,
+    /// 客户端关闭了连接；`pending` 是最后没凑成完整消息的字节数
+    Eof { pending: usize },
+    /// 数据无法解码
+    Corrupt(CodecError),
+}
+
+/// 在一个连接上收消息、分发给 `handler` 并回复，直到连接结束
+///
+/// `stream` 可以是 `&TcpStream`，也可以是任何实现了 `Read + Write` 的类型。
+pub fn serve_connection<S, H>(mut stream: S, handler: &mut H) -> io::Result<Closed>
+where
+    S: Read + Write,
+    H: Handler + ?Sized,
+{
+    let mut decoder = Decoder::new();
+    let mut buffer = [0; 4096];
+    let mut handled: u32 = 0;
+    loop {
+        // 先处理缓存里已经完整的消息，再读新的数据
+        loop {
+            match decoder.next_message() {
+                Ok(Some(message)) => {
+                    message.dispatch(handler);
+                    handled += 1;
+                    stream.write_all(&Reply::Ack(handled).encode())?;
+                    if message == Message::Quit {
+                        stream.flush()?;
+                        return Ok(Closed::Quit);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    stream.write_all(&Reply::Error(e.to_string()).encode())?;
+                    stream.flush()?;
+                    return Ok(Closed::Corrupt(e));
+                }
+            }
+        }
+        stream.flush()?;
+        match stream.read(&mut buffer) {
+            Ok(0) => {
+                return Ok(Closed::Eof {
+                    pending: decoder.buffered(),
+                });
+            }
+            Ok(n) => decoder.feed(&buffer[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// 监听 TCP 端口的消息服务端
+#[derive(Debug)]
+pub struct Server {
+    listener: TcpListener,
+    accept_retry: Duration,
+}
+
+impl Server {
+    /// 绑定地址，例如 `127.0.0.1:7878`；端口写 0 时由系统挑一个空闲端口
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            accept_retry: ACCEPT_RETRY,
+        })
+    }
+
+    /// 接受连接失败后等待多久再试，默认 [`ACCEPT_RETRY`]
+    ///
+    /// 文件描述符用完这类错误不会马上消失，不等待的话接受循环会空转占满 CPU。
+    pub fn set_accept_retry(&mut self, delay: Duration) {
+        self.accept_retry = delay;
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// 一直接受连接，每个连接一个线程，不会返回
+    ///
+    /// `make_handler` 在新线程里为每个连接创建一个 Handler，参数是客户端地址；
+    /// 连接结束时调用 `on_close` 报告结束方式。
+    /// 接受连接失败（例如文件描述符用完）时把错误交给 `on_accept_error`，
+    /// 等待 [`Server::set_accept_retry`] 设置的时长后继续；是否打印由调用方决定。
+    pub fn run<F, H, C, E>(self, make_handler: F, on_close: C, mut on_accept_error: E)
+    where
+        F: Fn(SocketAddr) -> H + Send + Sync + Clone + 'static,
+        H: Handler,
+        C: Fn(SocketAddr, io::Result<Closed>) + Send + Sync + Clone + 'static,
+        E: FnMut(io::Error),
+    {
+        for stream in self.listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                // 单个连接握手失败不影响继续接受其他连接
+                Err(e) if e.kind() == io::ErrorKind::ConnectionAborted => continue,
+                // 其他错误多半是暂时的，等一会儿再试，避免空转
+                Err(e) => {
+                    on_accept_error(e);
+                    thread::sleep(self.accept_retry);
+                    continue;
+                }
+            };
+            // 客户端可能在握手后立刻断开，这时拿不到对方地址
+            let Ok(peer) = stream.peer_addr() else {
+                continue;
+            };
+            let make_handler = make_handler.clone();
+            let on_close = on_close.clone();
+            thread::spawn(move || {
+                let mut handler = make_handler(peer);
+                on_close(peer, serve_connection(&stream, &mut handler));
+            });
+        }
+    }
+}
+
+/// 客户端错误
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    /// 消息无法编码，或服务端回复的文字太长
+    Codec(CodecError),
+    /// 服务端回复了 `Error`
+    Rejected(String),
+    /// 服务端回复了未知的标签
+    InvalidReply(u8),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Io(e) => write!(f, "网络错误: {}", e),
+            ClientError::Codec(e) => write!(f, "无法编码消息: {}", e),
+            ClientError::Rejected(text) => write!(f, "服务端拒绝: {}", text),
+            ClientError::InvalidReply(tag) => write!(f, "未知的回复标签 0x{:02x}", tag),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Io(e) => Some(e),
+            ClientError::Codec(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> ClientError {
+        ClientError::Io(e)
+    }
+}
+
+impl From<CodecError> for ClientError {
+    fn from(e: CodecError) -> ClientError {
+        ClientError::Codec(e)
+    }
+}
+
+/// 消息客户端：每发一条消息，等待服务端的确认
+#[derive(Debug)]
+pub struct Client {
+    stream: TcpStream,
+}
+
+impl Client {
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Client> {
+        let stream = TcpStream::connect(addr)?;
+        // 消息很小，关掉 Nagle 算法，避免每条消息都等一会儿才发出
+        stream.set_nodelay(true)?;
+        Ok(Client { stream })
+    }
+
+    /// 发送一条消息，返回服务端确认的序号
+    pub fn send(&mut self, message: &Message) -> Result<u32, ClientError> {
+        self.stream.write_all(&message.encode()?)?;
+        match Reply::read_from(&mut self.stream)? {
+            Reply::Ack(n) => Ok(n),
+            Reply::Error(text) => Err(ClientError::Rejected(text)),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+// 文件路径: src/modules/front_of_house/hosting.rs
+
+pub fn add_to_waitlist() {
+    println!("Added to waitlist!");
+}
@@ -0,0 +1,4 @@
+// 文件路径: src/modules/front_of_house/mod.rs
+
+// 子模块 hosting 的内容在同目录的 hosting.rs 中
+pub mod hosting;
@@ -0,0 +1,24 @@
+// 文件路径: src/modules/mod.rs
+
+//! Rust 的模块系统用于组织代码
+//! 本例展示如何定义和使用模块
+
+// 声明子模块：编译器会去 front_of_house/mod.rs 中寻找它的内容
+pub mod front_of_house;
+
+// 使用 use 简化路径（从 crate 根开始的绝对路径）
+use crate::modules::front_of_house::hosting;
+
+pub fn run() {
+    hosting::add_to_waitlist();
+}
+
+// 📂 项目结构（本模块自身就是按这个布局拆分的）：
+// src/
+//   lib.rs              // 库 crate 入口：pub mod modules;
+//   bin/09_modules.rs   // 二进制 crate 入口
+//   modules/
+//     mod.rs            // 本文件
+//     front_of_house/
+//       mod.rs          // 模块定义：pub mod hosting;
+//       hosting.rs      // 子模块
@@ -0,0 +1,336 @@
+//! 学习进度：记录看过的课程、通过的练习和测验成绩，保存在本地文本文件中
+//!
+//! 文件每行一条记录，空行和 `#` 开头的行会被忽略：
+//!
+//! ```text
+//! # rust-learning progress
+//! lesson 6 1760601600
+//! exercise functions1 1760601900
+//! quiz 1 3/4 1760602200
+//! ```
+//!
+//! 时间戳是 Unix 秒数。同一课程/练习/测验重复记录时，以最后一条为准。
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::exercises::EXERCISES;
+use crate::files::atomic_write;
+use crate::lessons::{self, LESSONS, Section};
+
+/// 默认的进度文件名（位于当前目录）
+pub const DEFAULT_FILE: &str = ".learn-progress";
+
+const HEADER: &str = "# rust-learning progress";
+
+/// 一次测验的得分
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuizScore {
+    pub correct: u32,
+    pub total: u32,
+    pub at: u64,
+}
+
+/// 一位学习者的全部进度
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// 课程编号 -> 最近一次查看的时间
+    pub lessons: BTreeMap<u8, u64>,
+    /// 练习名 -> 通过的时间
+    pub exercises: BTreeMap<String, u64>,
+    /// 课程编号 -> 最近一次测验成绩
+    pub quizzes: BTreeMap<u8, QuizScore>,
+}
+
+/// 进度文件路径：环境变量 `LEARN_PROGRESS_FILE` 优先，否则为当前目录下的 [`DEFAULT_FILE`]
+pub fn default_path() -> PathBuf {
+    env::var_os("LEARN_PROGRESS_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_FILE))
+}
+
+/// 读取进度文件、修改后写回；读取失败时不会覆盖原文件
+pub fn update(path: &Path, change: impl FnOnce(&mut Progress)) -> io::Result<()> {
+    let mut progress = Progress::load(path)?;
+    change(&mut progress);
+    progress.save(path)
+}
+
+/// 当前 Unix 时间（秒）
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// 把 Unix 时间格式化为 `YYYY-MM-DD HH:MM`（UTC）
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let (hour, minute) = ((secs % 86_400) / 3_600, (secs % 3_600) / 60);
+
+    // 公历日期换算（Howard Hinnant 的 civil_from_days 算法）
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year, month, day, hour, minute
+    )
+}
+
+fn invalid(line_no: usize, line: &str) -> io::Error {
+    io::Error::new(
+        ErrorKind::InvalidData,
+        format!("进度文件第 {} 行无法解析: {}", line_no, line),
+    )
+}
+
+impl Progress {
+    /// 读取进度文件；文件不存在时返回空进度
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        match fs::read_to_string(path) {
+            Ok(text) => Progress::parse(&text),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Progress::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 从文本解析进度
+    pub fn parse(text: &str) -> io::Result<Progress> {
+        let mut progress = Progress::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let bad = || invalid(index + 1, line);
+            match fields.as_slice() {
+                ["lesson", number, at] => {
+                    let number = number.parse().map_err(|_| bad())?;
+                    let at = at.parse().map_err(|_| bad())?;
+                    progress.lessons.insert(number, at);
+                }
+                ["exercise", name, at] => {
+                    let at = at.parse().map_err(|_| bad())?;
+                    progress.exercises.insert(name.to_string(), at);
+                }
+                ["quiz", number, score, at] => {
+                    let number = number.parse().map_err(|_| bad())?;
+                    let (correct, total) = score.split_once('/').ok_or_else(bad)?;
+                    let score = QuizScore {
+                        correct: correct.parse().map_err(|_| bad())?,
+                        total: total.parse().map_err(|_| bad())?,
+                        at: at.parse().map_err(|_| bad())?,
+                    };
+                    progress.quizzes.insert(number, score);
+                }
+                _ => return Err(bad()),
+            }
+        }
+        Ok(progress)
+    }
+
+    /// 序列化为进度文件格式
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", HEADER);
+        for (number, at) in &self.lessons {
+            let _ = writeln!(text, "lesson {} {}", number, at);
+        }
+        for (name, at) in &self.exercises {
+            let _ = writeln!(text, "exercise {} {}", name, at);
+        }
+        for (number, score) in &self.quizzes {
+            let _ = writeln!(
+                text,
+                "quiz {} {}/{} {}",
+                number, score.correct, score.total, score.at
+            );
+        }
+        text
+    }
+
+    /// 先写临时文件并同步到磁盘再改名，写到一半失败或断电时原来的进度文件保持不变
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        atomic_write(path, &self.to_text())
+    }
+
+    pub fn record_lesson(&mut self, number: u8, at: u64) {
+        self.lessons.insert(number, at);
+    }
+
+    pub fn record_exercise(&mut self, name: &str, at: u64) {
+        self.exercises.insert(name.to_string(), at);
+    }
+
+    pub fn record_quiz(&mut self, number: u8, correct: u32, total: u32, at: u64) {
+        self.quizzes
+            .insert(number, QuizScore { correct, total, at });
+    }
+
+    /// 按学习顺序第一节还没看过的课程编号
+    pub fn next_lesson(&self) -> Option<u8> {
+        lessons::learning_path()
+            .map(|lesson| lesson.number)
+            .find(|number| !self.lessons.contains_key(number))
+    }
+
+    /// 最近一次活动的时间
+    pub fn last_activity(&self) -> Option<u64> {
+        let lessons = self.lessons.values().copied();
+        let exercises = self.exercises.values().copied();
+        let quizzes = self.quizzes.values().map(|score| score.at);
+        lessons.chain(exercises).chain(quizzes).max()
+    }
+
+    /// 某个阶段的完成情况
+    pub fn section_status(&self, section: Section) -> SectionStatus {
+        let mut status = SectionStatus {
+            section,
+            ..SectionStatus::default()
+        };
+        for lesson in LESSONS.iter().filter(|lesson| lesson.section == section) {
+            status.lessons_total += 1;
+            if self.lessons.contains_key(&lesson.number) {
+                status.lessons_viewed += 1;
+            }
+            for exercise in EXERCISES.iter().filter(|e| e.lesson == lesson.number) {
+                status.exercises_total += 1;
+                if self.exercises.contains_key(exercise.name) {
+                    status.exercises_passed += 1;
+                }
+            }
+            if let Some(score) = self.quizzes.get(&lesson.number) {
+                status.quiz_correct += score.correct;
+                status.quiz_total += score.total;
+            }
+        }
+        status
+    }
+
+    /// `learn status` 的完整报告
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        let mut overall = SectionStatus::default();
+
+        for section in Section::ALL {
+            let status = self.section_status(section);
+            let _ = writeln!(
+                out,
+                "【{}】 {:>3}%  课程 {}/{}  练习 {}/{}  测验 {}",
+                section.title(),
+                status.percent(),
+                status.lessons_viewed,
+                status.lessons_total,
+                status.exercises_passed,
+                status.exercises_total,
+                status.quiz_summary()
+            );
+            for lesson in LESSONS.iter().filter(|lesson| lesson.section == section) {
+                let viewed = self.lessons.get(&lesson.number);
+                let exercises: Vec<_> = EXERCISES
+                    .iter()
+                    .filter(|e| e.lesson == lesson.number)
+                    .collect();
+                let passed = exercises
+                    .iter()
+                    .filter(|e| self.exercises.contains_key(e.name))
+                    .count();
+                let mark = if viewed.is_some() && passed == exercises.len() {
+                    "✅"
+                } else if viewed.is_some() || passed > 0 {
+                    "🔶"
+                } else {
+                    "⬜"
+                };
+                let _ = write!(
+                    out,
+                    "  {} {:>2}. {:<20} 练习 {}/{}",
+                    mark,
+                    lesson.number,
+                    lesson.name,
+                    passed,
+                    exercises.len()
+                );
+                if let Some(score) = self.quizzes.get(&lesson.number) {
+                    let _ = write!(out, "  测验 {}/{}", score.correct, score.total);
+                }
+                if let Some(&at) = viewed {
+                    let _ = write!(out, "  查看于 {}", format_timestamp(at));
+                }
+                out.push('\n');
+            }
+            overall.add(&status);
+        }
+
+        let _ = writeln!(
+            out,
+            "\n总计 {}%：课程 {}/{}，练习 {}/{}，测验 {}",
+            overall.percent(),
+            overall.lessons_viewed,
+            overall.lessons_total,
+            overall.exercises_passed,
+            overall.exercises_total,
+            overall.quiz_summary()
+        );
+        match self.last_activity() {
+            Some(at) => {
+                let _ = writeln!(out, "最近活动：{} UTC", format_timestamp(at));
+            }
+            None => out.push_str("还没有任何学习记录，运行 `learn next` 开始第一课\n"),
+        }
+        out
+    }
+}
+
+/// 一个学习阶段的汇总
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SectionStatus {
+    pub section: Section,
+    pub lessons_viewed: usize,
+    pub lessons_total: usize,
+    pub exercises_passed: usize,
+    pub exercises_total: usize,
+    pub quiz_correct: u32,
+    pub quiz_total: u32,
+}
+
+impl SectionStatus {
+    /// 完成百分比：看过的课程和通过的练习各算一项
+    pub fn percent(&self) -> usize {
+        let total = self.lessons_total + self.exercises_total;
+        if total == 0 {
+            return 0;
+        }
+        (self.lessons_viewed + self.exercises_passed) * 100 / total
+    }
+
+    fn quiz_summary(&self) -> String {
+        if self.quiz_total == 0 {
+            String::from("—")
+        } else {
+            format!("{}/{}", self.quiz_correct, self.quiz_total)
+        }
+    }
+
+    fn add(&mut self, other: &SectionStatus) {
+        self.lessons_viewed += other.lessons_viewed;
+        self.lessons_total += other.lessons_total;
+        self.exercises_passed += other.exercises_passed;
+        self.exercises_total += other.exercises_total;
+        self.quiz_correct += other.quiz_correct;
+        self.quiz_total += other.quiz_total;
+    }
+}
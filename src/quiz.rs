@@ -0,0 +1,453 @@
+//! 课程测验：从题库文件加载选择题和「这段代码打印什么」题，逐题提问并评分
+//!
+//! 题库放在 `quizzes/<课程>.toml`，格式是 TOML 的一个小子集：
+//!
+//! ```toml
+//! # 注释
+//! [[question]]
+//! kind = "choice"             # choice（选择题）或 output（写出输出）
+//! prompt = "题目"
+//! code = """
+//! 可选的代码片段，可以跨多行
+//! """
+//! choices = ["A 选项", "B 选项"]  # 仅 choice
+//! answer = "B"                  # choice 写选项字母，output 写期望输出（中间不能有空行）
+//! explain = "答案解析"
+//! see = "src/variables.rs"      # 可选：解析中引用的课程代码位置
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::lessons::Lesson;
+
+/// 一道题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    pub prompt: String,
+    pub code: Option<String>,
+    pub kind: QuestionKind,
+    pub explain: String,
+    pub see: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuestionKind {
+    /// 选择题，`answer` 是正确选项的下标
+    Choice { choices: Vec<String>, answer: usize },
+    /// 写出程序输出，比较时忽略首尾空行和每行两端的空白
+    Output { expected: String },
+}
+
+/// 题库解析错误，带行号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for QuizError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "第 {} 行: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for QuizError {}
+
+fn error(line: usize, message: impl Into<String>) -> QuizError {
+    QuizError {
+        line,
+        message: message.into(),
+    }
+}
+
+/// 题库中的值：字符串、整数或字符串数组
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Str(String),
+    Int(i64),
+    List(Vec<String>),
+}
+
+/// 一道题已读到的字段：字段名 -> (所在行号, 值)
+type Fields = HashMap<String, (usize, Value)>;
+
+/// 题库目录
+pub fn quizzes_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("quizzes")
+}
+
+/// 某节课的题库文件路径
+pub fn bank_path(lesson: &Lesson) -> PathBuf {
+    quizzes_dir().join(format!("{}.toml", lesson.name))
+}
+
+/// 读取并解析某节课的题库
+pub fn load(lesson: &Lesson) -> io::Result<Vec<Question>> {
+    let path = bank_path(lesson);
+    let text = fs::read_to_string(&path)?;
+    parse(&text).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+/// 解析题库文本
+pub fn parse(text: &str) -> Result<Vec<Question>, QuizError> {
+    let mut questions = Vec::new();
+    // 当前题目的字段，以及 [[question]] 所在行号
+    let mut current: Option<(usize, Fields)> = None;
+    let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+    while let Some((line_no, line)) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed == "[[question]]" {
+            if let Some((start, fields)) = current.take() {
+                questions.push(build_question(start, fields)?);
+            }
+            current = Some((line_no, HashMap::new()));
+            continue;
+        }
+
+        let Some((_, fields)) = current.as_mut() else {
+            return Err(error(line_no, "字段必须写在 [[question]] 之后"));
+        };
+        let (key, raw) = trimmed
+            .split_once('=')
+            .ok_or_else(|| error(line_no, format!("应为 key = value，实际是 `{}`", trimmed)))?;
+        let key = key.trim();
+        let raw = raw.trim();
+
+        let value = if let Some(rest) = raw.strip_prefix("\"\"\"") {
+            Value::Str(parse_multiline(line_no, rest, &mut lines)?)
+        } else {
+            parse_value(line_no, raw)?
+        };
+        if fields.insert(key.to_string(), (line_no, value)).is_some() {
+            return Err(error(line_no, format!("字段 `{}` 重复", key)));
+        }
+    }
+
+    if let Some((start, fields)) = current {
+        questions.push(build_question(start, fields)?);
+    }
+    Ok(questions)
+}
+
+/// 解析 `"""` 多行字符串；紧跟开头 `"""` 的换行会被去掉
+fn parse_multiline<'a>(
+    start: usize,
+    first: &str,
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<String, QuizError> {
+    if let Some(end) = first.find("\"\"\"") {
+        return Ok(first[..end].to_string());
+    }
+    let mut text = String::from(first);
+    let mut first_line = first.is_empty();
+    for (_, line) in lines {
+        if let Some(end) = line.find("\"\"\"") {
+            if !first_line {
+                text.push('\n');
+            }
+            text.push_str(&line[..end]);
+            return Ok(text);
+        }
+        if !first_line {
+            text.push('\n');
+        }
+        first_line = false;
+        text.push_str(line);
+    }
+    Err(error(start, "多行字符串缺少结尾的 \"\"\""))
+}
+
+fn parse_value(line: usize, raw: &str) -> Result<Value, QuizError> {
+    let mut chars = raw.char_indices().peekable();
+    let value = match raw.chars().next() {
+        Some('"') => Value::Str(parse_string(line, raw, &mut chars)?),
+        Some('[') => {
+            chars.next();
+            let mut items = Vec::new();
+            loop {
+                skip_whitespace(&mut chars);
+                match chars.peek() {
+                    Some((_, ']')) => {
+                        chars.next();
+                        break;
+                    }
+                    Some((_, '"')) => items.push(parse_string(line, raw, &mut chars)?),
+                    _ => return Err(error(line, "数组只能包含字符串")),
+                }
+                skip_whitespace(&mut chars);
+                match chars.next() {
+                    Some((_, ',')) => continue,
+                    Some((_, ']')) => break,
+                    _ => return Err(error(line, "数组元素之间应以逗号分隔")),
+                }
+            }
+            Value::List(items)
+        }
+        Some(_) => {
+            let end = raw.find('#').unwrap_or(raw.len());
+            let number = raw[..end].trim();
+            let value = number
+                .parse()
+                .map_err(|_| error(line, format!("无法识别的值 `{}`", number)))?;
+            return Ok(Value::Int(value));
+        }
+        None => return Err(error(line, "缺少值")),
+    };
+
+    // 值后面只允许空白和注释
+    skip_whitespace(&mut chars);
+    match chars.peek() {
+        None | Some((_, '#')) => Ok(value),
+        Some((i, _)) => Err(error(line, format!("值后面有多余内容 `{}`", &raw[*i..]))),
+    }
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::CharIndices>) {
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+}
+
+/// 解析一个带转义（`\"`、`\\`、`\n`、`\t`）的双引号字符串
+fn parse_string(
+    line: usize,
+    raw: &str,
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+) -> Result<String, QuizError> {
+    chars.next(); // 开头的引号
+    let mut text = String::new();
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '"' => return Ok(text),
+            '\\' => match chars.next() {
+                Some((_, '"')) => text.push('"'),
+                Some((_, '\\')) => text.push('\\'),
+                Some((_, 'n')) => text.push('\n'),
+                Some((_, 't')) => text.push('\t'),
+                Some((_, other)) => {
+                    return Err(error(line, format!("不支持的转义 `\\{}`", other)));
+                }
+                None => break,
+            },
+            _ => text.push(c),
+        }
+    }
+    Err(error(line, format!("字符串缺少结尾引号: {}", raw)))
+}
+
+fn build_question(start: usize, mut fields: Fields) -> Result<Question, QuizError> {
+    let mut take_str = |key: &str, required: bool| -> Result<Option<String>, QuizError> {
+        match fields.remove(key) {
+            Some((_, Value::Str(text))) => Ok(Some(text)),
+            Some((line, _)) => Err(error(line, format!("`{}` 应为字符串", key))),
+            None if required => Err(error(start, format!("题目缺少 `{}`", key))),
+            None => Ok(None),
+        }
+    };
+
+    let kind_name = take_str("kind", true)?.unwrap_or_default();
+    let prompt = take_str("prompt", true)?.unwrap_or_default();
+    let code = take_str("code", false)?;
+    let explain = take_str("explain", true)?.unwrap_or_default();
+    let see = take_str("see", false)?;
+
+    let kind = match kind_name.as_str() {
+        "choice" => {
+            let choices = match fields.remove("choices") {
+                Some((_, Value::List(items))) if items.len() >= 2 => items,
+                Some((line, _)) => return Err(error(line, "`choices` 应为至少两个字符串的数组")),
+                None => return Err(error(start, "选择题缺少 `choices`")),
+            };
+            let (line, answer) = fields
+                .remove("answer")
+                .ok_or_else(|| error(start, "题目缺少 `answer`"))?;
+            let answer = match answer {
+                Value::Str(letter) => parse_choice(&letter, choices.len()),
+                Value::Int(n) => n
+                    .checked_sub(1)
+                    .and_then(|i| usize::try_from(i).ok())
+                    .filter(|&i| i < choices.len()),
+                Value::List(_) => None,
+            }
+            .ok_or_else(|| error(line, "`answer` 不是有效的选项"))?;
+            QuestionKind::Choice { choices, answer }
+        }
+        "output" => match fields.remove("answer") {
+            // 作答时空行表示输入结束，中间有空行的输出永远答不对
+            Some((line, Value::Str(expected))) => {
+                if normalize_output(&expected).lines().any(str::is_empty) {
+                    return Err(error(line, "输出题的 `answer` 中间不能有空行"));
+                }
+                QuestionKind::Output { expected }
+            }
+            Some((line, _)) => return Err(error(line, "输出题的 `answer` 应为字符串")),
+            None => return Err(error(start, "题目缺少 `answer`")),
+        },
+        other => {
+            return Err(error(
+                start,
+                format!("未知题型 `{}`，应为 choice 或 output", other),
+            ));
+        }
+    };
+
+    if let Some((key, (line, _))) = fields.into_iter().min_by_key(|(_, (line, _))| *line) {
+        return Err(error(line, format!("未知字段 `{}`", key)));
+    }
+    Ok(Question {
+        prompt,
+        code,
+        kind,
+        explain,
+        see,
+    })
+}
+
+/// 把选项字母（A/b）或序号（1/2）转成下标
+fn parse_choice(input: &str, count: usize) -> Option<usize> {
+    let input = input.trim();
+    let index = if let Ok(n) = input.parse::<usize>() {
+        n.checked_sub(1)?
+    } else {
+        let mut chars = input.chars();
+        let letter = chars.next()?.to_ascii_uppercase();
+        if chars.next().is_some() || !letter.is_ascii_uppercase() {
+            return None;
+        }
+        (letter as u8 - b'A') as usize
+    };
+    (index < count).then_some(index)
+}
+
+fn choice_letter(index: usize) -> char {
+    (b'A' + index as u8) as char
+}
+
+/// 比较输出时忽略首尾空行和每行两端的空白
+fn normalize_output(text: &str) -> String {
+    text.trim()
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Question {
+    /// 判断一个答案是否正确
+    pub fn is_correct(&self, answer: &str) -> bool {
+        match &self.kind {
+            QuestionKind::Choice {
+                choices,
+                answer: right,
+            } => parse_choice(answer, choices.len()) == Some(*right),
+            QuestionKind::Output { expected } => {
+                normalize_output(answer) == normalize_output(expected)
+            }
+        }
+    }
+
+    /// 正确答案的展示形式
+    pub fn answer_text(&self) -> String {
+        match &self.kind {
+            QuestionKind::Choice { choices, answer } => {
+                format!("{}. {}", choice_letter(*answer), choices[*answer])
+            }
+            QuestionKind::Output { expected } => expected.clone(),
+        }
+    }
+}
+
+/// 一次测验的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuizResult {
+    pub correct: u32,
+    pub total: u32,
+}
+
+/// 读取一个答案：选择题读一行；输出题读到空行（或单独一行 `.`）为止
+fn read_answer(question: &Question, input: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            if lines.is_empty() {
+                return Ok(None);
+            }
+            break;
+        }
+        let line = line.trim_end_matches(['\r', '\n']).to_string();
+        if matches!(question.kind, QuestionKind::Choice { .. }) {
+            return Ok(Some(line));
+        }
+        if line.trim().is_empty() || line.trim() == "." {
+            break;
+        }
+        lines.push(line);
+    }
+    Ok(Some(lines.join("\n")))
+}
+
+/// 逐题提问并评分。`input` 可以是标准输入，也可以是预先写好的答案文件
+pub fn run(
+    questions: &[Question],
+    source: &str,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<QuizResult> {
+    let mut result = QuizResult {
+        correct: 0,
+        total: questions.len() as u32,
+    };
+
+    for (index, question) in questions.iter().enumerate() {
+        writeln!(
+            out,
+            "\n第 {}/{} 题：{}",
+            index + 1,
+            questions.len(),
+            question.prompt
+        )?;
+        if let Some(code) = &question.code {
+            writeln!(out, "\n```rust\n{}\n```", code.trim_end())?;
+        }
+        match &question.kind {
+            QuestionKind::Choice { choices, .. } => {
+                for (i, choice) in choices.iter().enumerate() {
+                    writeln!(out, "  {}. {}", choice_letter(i), choice)?;
+                }
+                write!(out, "你的答案（字母或序号）：")?;
+            }
+            QuestionKind::Output { .. } => {
+                writeln!(out, "写出程序的输出（以空行结束）：")?;
+            }
+        }
+        out.flush()?;
+
+        let Some(answer) = read_answer(question, input)? else {
+            writeln!(out, "\n（没有更多输入，测验结束）")?;
+            break;
+        };
+        if question.is_correct(&answer) {
+            result.correct += 1;
+            writeln!(out, "✅ 正确！")?;
+        } else {
+            writeln!(out, "❌ 不对，正确答案：{}", question.answer_text())?;
+        }
+        writeln!(out, "💡 {}", question.explain)?;
+        writeln!(out, "   参见 {}", question.see.as_deref().unwrap_or(source))?;
+    }
+
+    writeln!(out, "\n得分：{}/{}", result.correct, result.total)?;
+    Ok(result)
+}
@@ -0,0 +1,326 @@
+//! 任务调度器：在固定大小的线程池上执行 `Task<Ready>` 携带的工作
+//!
+//! 每个提交的任务带一个闭包和优先级，进入共享的优先队列。工作线程取出任务后把它转为 Running，
+//! 执行闭包，再按结果转为 Completed 或 Failed（附带失败原因），最终状态通过 channel 以 [`Report`] 发回。
+//! 这些转换和第 6 课的 `start()`/`complete()`/`fail()` 相同，只是不向标准输出打印。还在排队的任务可以暂停、恢复或取消；
+//! [`Scheduler::shutdown`] 会执行完队列中的任务、取消暂停中的任务后再退出。
+//!
+//! ```
+//! use rust_learning::scheduler::{Priority, Scheduler};
+//! use rust_learning::structs::{Task, TaskState};
+//!
+//! let scheduler = Scheduler::new(2);
+//! scheduler.submit(Task::new("Backup"), Priority::Normal, || Ok(()));
+//! let report = scheduler.results().recv().unwrap();
+//! assert_eq!(report.task.state(), TaskState::Completed);
+//! scheduler.shutdown();
+//! ```
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+use crate::structs::{AnyTask, Ready, Task};
+
+/// 任务要执行的工作；返回 `Err(原因)` 表示失败
+pub type Work = Box<dyn FnOnce() -> Result<(), String> + Send>;
+
+/// 调度器分配的任务编号
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskId(pub u64);
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// 优先级：高优先级先执行，同一优先级按提交顺序
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// 一个任务的最终结果：`task` 处于 Completed、Failed 或 Cancelled
+#[derive(Debug)]
+pub struct Report {
+    pub id: TaskId,
+    pub priority: Priority,
+    pub task: AnyTask,
+}
+
+/// 队列中的一项
+struct Queued {
+    id: TaskId,
+    priority: Priority,
+    task: Task<Ready>,
+    work: Work,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Queued {}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Queued {
+    // BinaryHeap 是大顶堆：优先级高的在前，编号小（先提交）的在前
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+/// 受互斥锁保护的调度状态
+struct State {
+    queue: BinaryHeap<Queued>,
+    paused: BTreeMap<TaskId, Queued>,
+    running: usize,
+    max_concurrency: usize,
+    next_id: u64,
+    shutting_down: bool,
+}
+
+impl State {
+    /// 从优先队列中取出指定任务（BinaryHeap 不支持按值删除，只能重建）
+    fn take_queued(&mut self, id: TaskId) -> Option<Queued> {
+        let queue = std::mem::take(&mut self.queue);
+        let (found, rest): (Vec<Queued>, Vec<Queued>) =
+            queue.into_iter().partition(|queued| queued.id == id);
+        self.queue = rest.into();
+        found.into_iter().next()
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    changed: Condvar,
+    results: Sender<Report>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // 工作闭包的 panic 在锁外被捕获，锁不会因此中毒；即便中毒，状态本身仍然一致
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn report(&self, id: TaskId, priority: Priority, task: AnyTask) {
+        // 调用方已经不再接收结果时直接丢弃
+        let _ = self.results.send(Report { id, priority, task });
+    }
+}
+
+/// 固定大小线程池上的任务调度器
+pub struct Scheduler {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+    results: Receiver<Report>,
+}
+
+impl Scheduler {
+    /// 启动 `workers` 个工作线程（至少 1 个），最大并发数默认等于线程数
+    pub fn new(workers: usize) -> Scheduler {
+        let workers = workers.max(1);
+        let (sender, results) = mpsc::channel();
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                queue: BinaryHeap::new(),
+                paused: BTreeMap::new(),
+                running: 0,
+                max_concurrency: workers,
+                next_id: 1,
+                shutting_down: false,
+            }),
+            changed: Condvar::new(),
+            results: sender,
+        });
+        let workers = (0..workers)
+            .map(|index| {
+                let shared = Arc::clone(&shared);
+                thread::Builder::new()
+                    .name(format!("scheduler-worker-{}", index))
+                    .spawn(move || worker(&shared))
+                    .expect("无法创建工作线程")
+            })
+            .collect();
+        Scheduler {
+            shared,
+            workers,
+            results,
+        }
+    }
+
+    /// 同时执行的任务数上限，可以在运行中调整；超过线程数时以线程数为准
+    pub fn set_max_concurrency(&self, max: usize) {
+        self.shared.lock().max_concurrency = max.max(1);
+        self.shared.changed.notify_all();
+    }
+
+    /// 提交一个任务，返回它的编号
+    pub fn submit<F>(&self, task: Task<Ready>, priority: Priority, work: F) -> TaskId
+    where
+        F: FnOnce() -> Result<(), String> + Send + 'static,
+    {
+        let mut state = self.shared.lock();
+        let id = TaskId(state.next_id);
+        state.next_id += 1;
+        state.queue.push(Queued {
+            id,
+            priority,
+            task,
+            work: Box::new(work),
+        });
+        drop(state);
+        self.shared.changed.notify_one();
+        id
+    }
+
+    /// 暂停一个还在排队的任务；已经开始执行或不存在时返回 false
+    pub fn pause(&self, id: TaskId) -> bool {
+        let mut state = self.shared.lock();
+        match state.take_queued(id) {
+            Some(queued) => {
+                state.paused.insert(id, queued);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 把暂停的任务放回队列
+    pub fn resume(&self, id: TaskId) -> bool {
+        let mut state = self.shared.lock();
+        let Some(queued) = state.paused.remove(&id) else {
+            return false;
+        };
+        state.queue.push(queued);
+        drop(state);
+        self.shared.changed.notify_one();
+        true
+    }
+
+    /// 取消一个排队中或暂停中的任务，结果以 Cancelled 报告
+    pub fn cancel(&self, id: TaskId) -> bool {
+        let mut state = self.shared.lock();
+        let queued = state.paused.remove(&id).or_else(|| state.take_queued(id));
+        drop(state);
+        match queued {
+            Some(queued) => {
+                let cancelled = queued.task.cancel_quietly();
+                self.shared
+                    .report(queued.id, queued.priority, cancelled.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 排队中（含暂停）的任务数
+    pub fn pending(&self) -> usize {
+        let state = self.shared.lock();
+        state.queue.len() + state.paused.len()
+    }
+
+    /// 任务结果的接收端
+    pub fn results(&self) -> &Receiver<Report> {
+        &self.results
+    }
+
+    /// 优雅关闭：不再接受任务，执行完队列中的任务，取消暂停中的任务，
+    /// 等待全部工作线程退出后返回还没被取走的结果
+    pub fn shutdown(mut self) -> Vec<Report> {
+        self.stop();
+        self.results.try_iter().collect()
+    }
+
+    fn stop(&mut self) {
+        let paused: Vec<Queued> = {
+            let mut state = self.shared.lock();
+            state.shutting_down = true;
+            std::mem::take(&mut state.paused).into_values().collect()
+        };
+        for queued in paused {
+            let cancelled = queued.task.cancel_quietly();
+            self.shared
+                .report(queued.id, queued.priority, cancelled.into());
+        }
+        self.shared.changed.notify_all();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// 工作线程：取出可以执行的任务，直到关闭且队列为空
+fn worker(shared: &Shared) {
+    loop {
+        let queued = {
+            let mut state = shared.lock();
+            loop {
+                if state.running < state.max_concurrency
+                    && let Some(queued) = state.queue.pop()
+                {
+                    state.running += 1;
+                    break queued;
+                }
+                if state.shutting_down && state.queue.is_empty() {
+                    return;
+                }
+                state = shared
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(|e| e.into_inner());
+            }
+        };
+
+        let Queued {
+            id,
+            priority,
+            task,
+            work,
+        } = queued;
+        // 库代码不打印，用安静的转换代替 start()/complete()/fail()
+        let task = task.start_quietly();
+        let finished: AnyTask = match panic::catch_unwind(AssertUnwindSafe(work)) {
+            Ok(Ok(())) => task.complete_quietly().into(),
+            Ok(Err(reason)) => task.fail_quietly(reason).into(),
+            Err(payload) => task.fail_quietly(panic_message(payload.as_ref())).into(),
+        };
+        shared.report(id, priority, finished);
+
+        shared.lock().running -= 1;
+        shared.changed.notify_all();
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic");
+    format!("panicked: {}", message)
+}
@@ -0,0 +1,580 @@
+//! 静态站点：把课程源码渲染成可离线浏览的 HTML
+//!
+//! 每节课的源码按 `// ===== 标题 =====` 和三行的
+//! `// ====...` / `// 标题` / `// ====...` 分隔注释切成若干段；段内的文档注释
+//! （`///`、`//!`）渲染为正文，其余代码渲染为高亮的代码块。页面末尾嵌入课程的运行输出，
+//! 取自 `tests/snapshots/` 中由黄金测试校验过的快照。导航顺序与 README「学习路径建议」一致。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::i18n::{self, Lang};
+use crate::lessons::{self, Lesson, Section};
+
+/// 段落中的一块内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// 文档注释，已去掉 `///` / `//!` 前缀
+    Prose(String),
+    /// 源码原文
+    Code(String),
+}
+
+/// 源码中两个分隔注释之间的一段
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Part {
+    /// 分隔注释里的标题；文件开头第一个分隔之前的内容没有标题
+    pub title: Option<String>,
+    pub blocks: Vec<Block>,
+}
+
+/// 只由 `=` 组成的分隔行（至少 5 个），例如 `// ==========`
+fn is_rule(comment: &str) -> bool {
+    comment.len() >= 5 && comment.chars().all(|c| c == '=')
+}
+
+/// `// ===== 标题 =====` 形式的单行分隔，返回标题
+fn inline_title(comment: &str) -> Option<&str> {
+    let inner = comment.strip_prefix("=====")?.trim_start_matches('=');
+    let title = inner.trim_end_matches('=').trim();
+    (!title.is_empty()).then_some(title)
+}
+
+/// 普通 `//` 注释的内容（不含文档注释）
+fn plain_comment(line: &str) -> Option<&str> {
+    let rest = line.trim().strip_prefix("//")?;
+    if rest.starts_with('/') || rest.starts_with('!') {
+        return None;
+    }
+    Some(rest.trim())
+}
+
+fn doc_comment(line: &str) -> Option<&str> {
+    let trimmed = line.trim();
+    let rest = trimmed
+        .strip_prefix("///")
+        .or_else(|| trimmed.strip_prefix("//!"))?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+/// 把课程源码按分隔注释切成段；只有顶层（不缩进）的文档注释会成为正文
+pub fn split(source: &str) -> Vec<Part> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut parts = vec![Part::default()];
+    let mut prose: Vec<&str> = Vec::new();
+    let mut code: Vec<&str> = Vec::new();
+
+    fn flush(part: &mut Part, prose: &mut Vec<&str>, code: &mut Vec<&str>) {
+        if !prose.is_empty() {
+            part.blocks
+                .push(Block::Prose(prose.join("\n").trim().to_string()));
+            prose.clear();
+        }
+        let start = code.iter().position(|l| !l.trim().is_empty());
+        let end = code.iter().rposition(|l| !l.trim().is_empty());
+        if let (Some(start), Some(end)) = (start, end) {
+            part.blocks.push(Block::Code(code[start..=end].join("\n")));
+        }
+        code.clear();
+    }
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let comment = plain_comment(line);
+
+        let title = match comment {
+            Some(c) if is_rule(c) => {
+                // 三行的横幅分隔：规则行、标题行、规则行
+                let banner = lines
+                    .get(i + 1)
+                    .and_then(|l| plain_comment(l))
+                    .filter(|t| !t.is_empty() && !is_rule(t))
+                    .filter(|_| {
+                        lines
+                            .get(i + 2)
+                            .and_then(|l| plain_comment(l))
+                            .is_some_and(is_rule)
+                    });
+                match banner {
+                    Some(title) => {
+                        i += 3;
+                        Some(title)
+                    }
+                    None => {
+                        // 孤立的规则行只是装饰
+                        i += 1;
+                        continue;
+                    }
+                }
+            }
+            Some(c) => inline_title(c).inspect(|_| i += 1),
+            None => None,
+        };
+
+        if let Some(title) = title {
+            flush(parts.last_mut().unwrap(), &mut prose, &mut code);
+            parts.push(Part {
+                title: Some(title.to_string()),
+                blocks: Vec::new(),
+            });
+            continue;
+        }
+
+        // 缩进的文档注释（方法、字段上的）留在代码块里，不打断代码
+        let top_level = !line.starts_with(char::is_whitespace);
+        match doc_comment(line).filter(|_| top_level) {
+            Some(text) => {
+                if !code.iter().all(|l| l.trim().is_empty()) {
+                    flush(parts.last_mut().unwrap(), &mut prose, &mut code);
+                }
+                code.clear();
+                prose.push(text);
+            }
+            None => {
+                if !prose.is_empty() {
+                    flush(parts.last_mut().unwrap(), &mut prose, &mut code);
+                }
+                code.push(line);
+            }
+        }
+        i += 1;
+    }
+    flush(parts.last_mut().unwrap(), &mut prose, &mut code);
+    parts.retain(|part| part.title.is_some() || !part.blocks.is_empty());
+    parts
+}
+
+/// HTML 转义
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// 把一段 Rust 代码转成带 `<span class="…">` 的 HTML
+///
+/// 类名：`kw` 关键字、`str` 字符串和字符、`com` 注释、`num` 数字、`mac` 宏调用、
+/// `ty` 类型（大写开头的标识符）、`lt` 生命周期、`attr` 属性。
+pub fn highlight(code: &str) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    let span = |out: &mut String, class: &str, text: &[char]| {
+        let text: String = text.iter().collect();
+        let _ = write!(out, "<span class=\"{}\">{}</span>", class, escape(&text));
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let start = i;
+
+        if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            span(&mut out, "com", &chars[start..i]);
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            while i < chars.len() && !(chars[i - 1] == '*' && chars[i] == '/') {
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            span(&mut out, "com", &chars[start..i]);
+        } else if c == '"' || (c == 'r' && matches!(next, Some('"') | Some('#'))) {
+            i = string_end(&chars, i);
+            span(&mut out, "str", &chars[start..i]);
+        } else if c == '\'' {
+            let is_char = next == Some('\\') || chars.get(i + 2) == Some(&'\'');
+            if is_char {
+                i += 1;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i = (i + 1).min(chars.len());
+                span(&mut out, "str", &chars[start..i]);
+            } else {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                span(&mut out, "lt", &chars[start..i]);
+            }
+        } else if c == '#' && matches!(next, Some('[') | Some('!')) {
+            let mut depth = 0;
+            while i < chars.len() {
+                match chars[i] {
+                    '[' => depth += 1,
+                    ']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            i += 1;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            span(&mut out, "attr", &chars[start..i]);
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            // 小数部分，但不把 `0..5` 或 `t.0.len()` 里的点吞掉
+            if i + 1 < chars.len() && chars[i] == '.' && chars[i + 1].is_ascii_digit() {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+            }
+            span(&mut out, "num", &chars[start..i]);
+        } else if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                i += 1;
+                span(&mut out, "mac", &chars[start..i]);
+            } else if KEYWORDS.contains(&word.as_str()) {
+                span(&mut out, "kw", &chars[start..i]);
+            } else if c.is_uppercase() {
+                span(&mut out, "ty", &chars[start..i]);
+            } else {
+                out.push_str(&escape(&word));
+            }
+        } else {
+            out.push_str(&escape(&c.to_string()));
+            i += 1;
+        }
+    }
+    out
+}
+
+/// 字符串字面量（含原始字符串 `r#"…"#`）结束后的位置
+fn string_end(chars: &[char], start: usize) -> usize {
+    let mut i = start;
+    if chars[i] == 'r' {
+        i += 1;
+        let mut hashes = 0;
+        while chars.get(i) == Some(&'#') {
+            hashes += 1;
+            i += 1;
+        }
+        if chars.get(i) != Some(&'"') {
+            // 只是以 r 开头的标识符后跟 #，例如 r#type
+            return start + 1;
+        }
+        i += 1;
+        while i < chars.len() {
+            if chars[i] == '"' && (1..=hashes).all(|k| chars.get(i + k) == Some(&'#')) {
+                return i + 1 + hashes;
+            }
+            i += 1;
+        }
+        return chars.len();
+    }
+    i += 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// 行内的 `代码` 渲染为 `<code>`
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    for (index, piece) in text.split('`').enumerate() {
+        if index % 2 == 1 {
+            let _ = write!(out, "<code>{}</code>", escape(piece));
+        } else {
+            out.push_str(&escape(piece));
+        }
+    }
+    out
+}
+
+/// 列表项的正文：`- 项`、`* 项` 或 `1. 项`
+fn list_item(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    if let Some(rest) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some(rest);
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    (digits > 0)
+        .then(|| line[digits..].strip_prefix(". "))
+        .flatten()
+}
+
+/// 把文档注释渲染为 HTML：空行分段，支持列表、行内代码和 ``` 代码块
+pub fn render_prose(text: &str) -> String {
+    let mut out = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut items: Vec<&str> = Vec::new();
+    let mut fence: Option<Vec<&str>> = None;
+
+    fn flush(out: &mut String, paragraph: &mut Vec<&str>, items: &mut Vec<&str>) {
+        if !paragraph.is_empty() {
+            let _ = writeln!(out, "<p>{}</p>", inline(&paragraph.join(" ")));
+            paragraph.clear();
+        }
+        if !items.is_empty() {
+            out.push_str("<ul>\n");
+            for item in items.iter() {
+                let _ = writeln!(out, "<li>{}</li>", inline(item));
+            }
+            out.push_str("</ul>\n");
+            items.clear();
+        }
+    }
+
+    for line in text.lines() {
+        if let Some(code) = fence.as_mut() {
+            if line.trim_start().starts_with("```") {
+                let _ = writeln!(
+                    out,
+                    "<pre><code>{}</code></pre>",
+                    highlight(&code.join("\n"))
+                );
+                fence = None;
+            } else {
+                code.push(line);
+            }
+            continue;
+        }
+        if line.trim_start().starts_with("```") {
+            flush(&mut out, &mut paragraph, &mut items);
+            fence = Some(Vec::new());
+        } else if line.trim().is_empty() {
+            flush(&mut out, &mut paragraph, &mut items);
+        } else if let Some(item) = list_item(line) {
+            if !paragraph.is_empty() {
+                flush(&mut out, &mut paragraph, &mut items);
+            }
+            items.push(item);
+        } else {
+            if !items.is_empty() {
+                flush(&mut out, &mut paragraph, &mut items);
+            }
+            paragraph.push(line.trim());
+        }
+    }
+    if let Some(code) = fence {
+        let _ = writeln!(
+            out,
+            "<pre><code>{}</code></pre>",
+            highlight(&code.join("\n"))
+        );
+    }
+    flush(&mut out, &mut paragraph, &mut items);
+    out
+}
+
+/// 页面文件名，例如 `06_structs.html`
+pub fn page_name(lesson: &Lesson) -> String {
+    format!("{}.html", lesson.name)
+}
+
+fn page(title: &str, nav: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"{lang}\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n</head>\n\
+         <body>\n<nav>\n{nav}</nav>\n<main>\n{body}</main>\n</body>\n</html>\n",
+        lang = i18n::lang().code(),
+        title = escape(title),
+        nav = nav,
+        body = body,
+    )
+}
+
+/// 侧边导航：按学习阶段分组，`current` 为当前课程
+fn navigation(current: Option<&Lesson>) -> String {
+    let mut nav = format!(
+        "<p><a href=\"index.html\">{}</a></p>\n",
+        escape(i18n::t("site.title"))
+    );
+    for section in Section::ALL {
+        let _ = writeln!(nav, "<h3>{}</h3>\n<ol>", escape(section.title()));
+        for lesson in lessons::learning_path().filter(|l| l.section == section) {
+            let class = if current.is_some_and(|c| c.number == lesson.number) {
+                " class=\"current\""
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                nav,
+                "<li{}><a href=\"{}\">{:02}. {}</a></li>",
+                class,
+                page_name(lesson),
+                lesson.number,
+                escape(lesson.topic())
+            );
+        }
+        nav.push_str("</ol>\n");
+    }
+    nav
+}
+
+/// 渲染一节课的页面；`output` 为课程的运行输出
+pub fn render_lesson(lesson: &Lesson, source: &str, output: Option<&str>) -> String {
+    let mut body = format!(
+        "<h1>{:02}. {}</h1>\n<p class=\"summary\">{}</p>\n<p class=\"source\"><code>{}</code></p>\n",
+        lesson.number,
+        escape(lesson.topic()),
+        escape(lesson.summary()),
+        escape(lesson.source)
+    );
+    for part in split(source) {
+        if let Some(title) = &part.title {
+            let _ = writeln!(body, "<h2>{}</h2>", escape(title));
+        }
+        for block in &part.blocks {
+            match block {
+                Block::Prose(text) => {
+                    let _ = writeln!(body, "<div class=\"prose\">\n{}</div>", render_prose(text));
+                }
+                Block::Code(code) => {
+                    let _ = writeln!(
+                        body,
+                        "<pre class=\"code\"><code>{}</code></pre>",
+                        highlight(code)
+                    );
+                }
+            }
+        }
+    }
+    if let Some(output) = output {
+        let _ = writeln!(
+            body,
+            "<h2>{}</h2>\n<pre class=\"output\">{}</pre>",
+            escape(i18n::t("site.output")),
+            escape(output.trim_end())
+        );
+    }
+
+    let path: Vec<&Lesson> = lessons::learning_path().collect();
+    let index = path.iter().position(|l| l.number == lesson.number);
+    body.push_str("<p class=\"pager\">");
+    if let Some(prev) = index.and_then(|i| i.checked_sub(1)).map(|i| path[i]) {
+        let _ = write!(
+            body,
+            "<a href=\"{}\">← {}</a> ",
+            page_name(prev),
+            escape(prev.topic())
+        );
+    }
+    if let Some(next) = index.and_then(|i| path.get(i + 1)) {
+        let _ = write!(
+            body,
+            "<a href=\"{}\">{} →</a>",
+            page_name(next),
+            escape(next.topic())
+        );
+    }
+    body.push_str("</p>\n");
+
+    page(
+        &format!("{:02}. {}", lesson.number, lesson.topic()),
+        &navigation(Some(lesson)),
+        &body,
+    )
+}
+
+/// 渲染首页：按学习阶段列出全部课程
+pub fn render_index() -> String {
+    let mut body = format!("<h1>{}</h1>\n", escape(i18n::t("site.title")));
+    for section in Section::ALL {
+        let _ = writeln!(body, "<h2>{}</h2>\n<ul>", escape(section.title()));
+        for lesson in lessons::learning_path().filter(|l| l.section == section) {
+            let _ = writeln!(
+                body,
+                "<li><a href=\"{}\">{:02}. {}</a> —— {}</li>",
+                page_name(lesson),
+                lesson.number,
+                escape(lesson.topic()),
+                escape(lesson.summary())
+            );
+        }
+        body.push_str("</ul>\n");
+    }
+    page(i18n::t("site.title"), &navigation(None), &body)
+}
+
+const STYLE: &str = "\
+body { display: flex; margin: 0; font-family: sans-serif; line-height: 1.6; }
+nav { width: 16em; padding: 1em; background: #f6f6f6; border-right: 1px solid #ddd; }
+nav ol { padding-left: 1.2em; }
+nav li.current a { font-weight: bold; }
+main { flex: 1; max-width: 60em; padding: 1em 2em; }
+pre { background: #fafafa; border: 1px solid #eee; padding: 0.8em; overflow-x: auto; }
+pre.output { background: #1e1e1e; color: #ddd; }
+.summary { color: #555; }
+.pager { display: flex; justify-content: space-between; margin-top: 2em; }
+.kw { color: #a626a4; }
+.str { color: #50a14f; }
+.com { color: #a0a1a7; font-style: italic; }
+.num { color: #986801; }
+.mac { color: #4078f2; }
+.ty { color: #c18401; }
+.lt { color: #e45649; }
+.attr { color: #0184bc; }
+";
+
+/// 课程的运行输出：当前语言的黄金快照，没有时退回中文快照
+pub fn captured_output(root: &Path, lesson: &Lesson) -> Option<String> {
+    let snapshots = root.join("tests/snapshots");
+    let file = format!("{}.stdout", lesson.name);
+    let localized = match i18n::lang() {
+        Lang::Zh => None,
+        lang => fs::read_to_string(snapshots.join(lang.code()).join(&file)).ok(),
+    };
+    localized.or_else(|| fs::read_to_string(snapshots.join(&file)).ok())
+}
+
+/// 生成整个站点到 `out` 目录，返回写出的文件
+pub fn build(root: &Path, out: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(out)?;
+    let mut written = Vec::new();
+    let mut write = |name: &str, content: &str| -> io::Result<()> {
+        let path = out.join(name);
+        fs::write(&path, content)?;
+        written.push(path);
+        Ok(())
+    };
+
+    write("style.css", STYLE)?;
+    write("index.html", &render_index())?;
+    for lesson in lessons::learning_path() {
+        let source = fs::read_to_string(root.join(lesson.source))?;
+        let output = captured_output(root, lesson);
+        write(
+            &page_name(lesson),
+            &render_lesson(lesson, &source, output.as_deref()),
+        )?;
+    }
+    Ok(written)
+}
@@ -0,0 +1,670 @@
+use std::marker::PhantomData;
+
+use crate::email::{Email, EmailError};
+use crate::vector::Vec3;
+
+/// 结构体：自定义数据类型（类似 Java class，但无方法继承）
+#[derive(Debug, Clone, PartialEq, Eq)] // 自动实现 Debug trait，方便打印
+pub struct User {
+    active: bool,
+    username: String,
+    email: Email, // 构造时已校验过的邮箱，见 src/email.rs
+    sign_in_count: u64,
+    last_login: Option<u64>, // 最近一次成功登录的 Unix 时间，见 src/auth.rs
+    role: Role,
+}
+
+// 元组结构体（Tuple Struct）—— 有名字段但无名称
+// 通道用 u8，类型本身就保证了 0..=255；颜色相关的方法见 src/color.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub u8, pub u8, pub u8);
+#[derive(Debug)]
+pub struct Point(pub i32, pub i32, pub i32);
+
+// 单元结构体（Unit-like Struct）
+// 定义不同的状态（单元结构体）
+#[derive(Debug)]
+pub struct Ready;
+#[derive(Debug)]
+pub struct Running;
+#[derive(Debug)]
+pub struct Paused;
+#[derive(Debug)]
+pub struct Completed;
+#[derive(Debug)]
+pub struct Cancelled;
+
+#[derive(Debug)]
+pub struct Failed;
+
+/// 任务状态；`Data` 是任务处于这个状态时额外携带的数据
+/// 单元结构体状态不带数据（`()` 的大小也是 0），只有 Failed 带着失败原因
+pub trait State {
+    type Data: std::fmt::Debug;
+}
+
+macro_rules! marker_states {
+    ($($state:ident),*) => {
+        $(
+            impl State for $state {
+                type Data = ();
+            }
+        )*
+    };
+}
+
+marker_states!(Ready, Running, Paused, Completed, Cancelled);
+
+impl State for Failed {
+    type Data = String;
+}
+
+/// 还没有结束的状态（Ready、Running、Paused），只有它们可以被取消
+pub trait Active: State {}
+impl Active for Ready {}
+impl Active for Running {}
+impl Active for Paused {}
+
+// 任务结构体，状态由泛型参数标记
+// PhantomData 大小为 0，所以 Task<Ready> 和只有 name 的结构体一样大
+#[derive(Debug)]
+pub struct Task<S: State> {
+    name: String,
+    state: PhantomData<S>, // PhantomData 用于持有类型标记
+    data: S::Data,         // 状态携带的数据，见 State
+}
+
+// 为不同状态实现方法
+impl Task<Ready> {
+    pub fn new(name: &str) -> Self {
+        Task {
+            name: name.to_string(),
+            state: PhantomData,
+            data: (),
+        }
+    }
+    
+    // 从 Ready 状态切换到 Running 状态
+    pub fn start(self) -> Task<Running> {
+        println!("Starting task: {}", self.name);
+        self.start_quietly()
+    }
+
+    /// 同 `start`，但不打印；库内的调度器和任务日志使用，下同
+    pub(crate) fn start_quietly(self) -> Task<Running> {
+        Task {
+            name: self.name,
+            state: PhantomData,
+            data: (),
+        }
+    }
+}
+
+impl Task<Running> {
+    // 从 Running 状态切换到 Paused 状态
+    pub fn pause(self) -> Task<Paused> {
+        println!("Pausing task: {}", self.name);
+        self.pause_quietly()
+    }
+
+    pub(crate) fn pause_quietly(self) -> Task<Paused> {
+        Task {
+            name: self.name,
+            state: PhantomData,
+            data: (),
+        }
+    }
+
+    // 正常结束：进入终止状态 Completed
+    pub fn complete(self) -> Task<Completed> {
+        println!("Completing task: {}", self.name);
+        self.complete_quietly()
+    }
+
+    pub(crate) fn complete_quietly(self) -> Task<Completed> {
+        Task {
+            name: self.name,
+            state: PhantomData,
+            data: (),
+        }
+    }
+
+    // 执行出错：进入终止状态 Failed，并记录原因
+    pub fn fail(self, reason: &str) -> Task<Failed> {
+        println!("Failing task: {} ({})", self.name, reason);
+        self.fail_quietly(reason.to_string())
+    }
+
+    pub(crate) fn fail_quietly(self, reason: String) -> Task<Failed> {
+        Task {
+            name: self.name,
+            state: PhantomData,
+            data: reason,
+        }
+    }
+}
+
+impl Task<Paused> {
+    // 从 Paused 状态回到 Running 状态
+    pub fn resume(self) -> Task<Running> {
+        println!("Resuming task: {}", self.name);
+        self.resume_quietly()
+    }
+
+    pub(crate) fn resume_quietly(self) -> Task<Running> {
+        Task {
+            name: self.name,
+            state: PhantomData,
+            data: (),
+        }
+    }
+}
+
+impl Task<Failed> {
+    /// 失败原因
+    pub fn reason(&self) -> &str {
+        &self.data
+    }
+}
+
+// 泛型约束：只有实现了 Active 的状态才有 cancel 方法
+impl<S: Active> Task<S> {
+    pub fn cancel(self) -> Task<Cancelled> {
+        println!("Cancelling task: {}", self.name);
+        self.cancel_quietly()
+    }
+
+    pub(crate) fn cancel_quietly(self) -> Task<Cancelled> {
+        Task {
+            name: self.name,
+            state: PhantomData,
+            data: (),
+        }
+    }
+}
+
+impl<S: State> Task<S> {
+    /// 任何状态下都可以读取任务名
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 按已知状态直接重建任务，不打印、不经过转换；只给库内从持久化记录恢复时使用
+    pub(crate) fn restore(name: &str, data: S::Data) -> Task<S> {
+        Task {
+            name: name.to_string(),
+            state: PhantomData,
+            data,
+        }
+    }
+}
+
+/// 运行时才知道状态的任务：把不同状态的 Task 放进同一个集合时使用
+#[derive(Debug)]
+pub enum AnyTask {
+    Ready(Task<Ready>),
+    Running(Task<Running>),
+    Paused(Task<Paused>),
+    Completed(Task<Completed>),
+    Failed(Task<Failed>),
+    Cancelled(Task<Cancelled>),
+}
+
+/// `AnyTask` 所处的状态，不带任务本身，方便比较和统计
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskState {
+    Ready,
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl TaskState {
+    /// Completed、Failed、Cancelled 是终止状态，之后不能再转换
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            TaskState::Completed | TaskState::Failed | TaskState::Cancelled
+        )
+    }
+}
+
+impl std::fmt::Display for TaskState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl AnyTask {
+    pub fn name(&self) -> &str {
+        match self {
+            AnyTask::Ready(task) => task.name(),
+            AnyTask::Running(task) => task.name(),
+            AnyTask::Paused(task) => task.name(),
+            AnyTask::Completed(task) => task.name(),
+            AnyTask::Failed(task) => task.name(),
+            AnyTask::Cancelled(task) => task.name(),
+        }
+    }
+
+    pub fn state(&self) -> TaskState {
+        match self {
+            AnyTask::Ready(_) => TaskState::Ready,
+            AnyTask::Running(_) => TaskState::Running,
+            AnyTask::Paused(_) => TaskState::Paused,
+            AnyTask::Completed(_) => TaskState::Completed,
+            AnyTask::Failed(_) => TaskState::Failed,
+            AnyTask::Cancelled(_) => TaskState::Cancelled,
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.state().is_terminal()
+    }
+
+    /// 取消任务；已经结束的任务原样返回 `Err`
+    pub fn cancel(self) -> Result<Task<Cancelled>, AnyTask> {
+        match self {
+            AnyTask::Ready(task) => Ok(task.cancel()),
+            AnyTask::Running(task) => Ok(task.cancel()),
+            AnyTask::Paused(task) => Ok(task.cancel()),
+            finished => Err(finished),
+        }
+    }
+}
+
+// 用 From 把具体状态的 Task 装进 AnyTask：`let any: AnyTask = task.into();`
+macro_rules! any_task_from {
+    ($($state:ident),*) => {
+        $(
+            impl From<Task<$state>> for AnyTask {
+                fn from(task: Task<$state>) -> Self {
+                    AnyTask::$state(task)
+                }
+            }
+        )*
+    };
+}
+
+any_task_from!(Ready, Running, Paused, Completed, Failed, Cancelled);
+
+fn test_unit_struct() {
+    let task = Task::new("Backup");
+    let task = task.start(); // 只能在 Ready 状态调用 start
+    let task = task.pause(); // 只能在 Running 状态调用 pause
+    println!("task: {:?}", task);
+    // task.start(); // 编译错误：Task<Paused> 没有 start 方法（E0599，见 tests/ui/06_structs_paused_task_start.rs）
+
+    // 完整的生命周期：暂停后恢复，再正常完成
+    let task = task.resume();
+    let task = task.complete();
+    println!("task: {:?}", task);
+    // task.cancel(); // 编译错误：Completed 没有实现 Active（E0599，见 tests/ui/06_structs_cancel_completed_task.rs）
+
+    // 不同状态的任务类型不同，放进同一个 Vec 需要先装进 AnyTask
+    let tasks: Vec<AnyTask> = vec![
+        Task::new("Index").into(),
+        Task::new("Upload").start().into(),
+        Task::new("Report").start().fail("disk full").into(),
+        Task::new("Cleanup").cancel().into(),
+        task.into(),
+    ];
+    for task in &tasks {
+        println!("{:<8} {}", task.name(), task.state());
+    }
+}
+
+impl User {
+    /// 关联函数（associated function）:
+    /// - 定义时没有 `self` 参数。
+    /// - 通过 `Type::function()` 调用（使用 `::`）。
+    /// - 常用于构造器/工厂方法（比如 `new`、`from_*`）。
+    /// - 因为没有 `self`，不能访问实例字段，只有类型级别的逻辑。
+    pub fn new(email: Email, username: String) -> User {
+        User {
+            email,
+            username,
+            active: true,
+            sign_in_count: 0,
+            last_login: None,
+            role: Role::Viewer,
+        }
+    }
+
+    /// 另一个关联函数示例：从 email 生成 User（演示命名习惯）
+    /// 邮箱不合法时返回错误；用户名取 `+` 标签之前的本地部分
+    pub fn from_email(email: &str) -> Result<User, EmailError> {
+        let email = Email::parse(email)?;
+        let username = email.without_tag().local().to_string();
+        Ok(User::new(email, username))
+    }
+
+    /// 方法（第一个参数是 self）
+    /// 方法（method）：第一个参数是 `self`（有三种形式）
+    /// - `self`：方法获取所有权，调用后原值被移动。
+    /// - `&self`：通过不可变引用读取，不修改所有者。
+    /// - `&mut self`：通过可变引用修改实例，需要 `mut` 绑定。
+    ///
+    /// 方法通过实例用点运算调用（`instance.method()`）。
+    ///
+    /// 这里是 `&mut self`：把角色提升一级（Viewer → Editor → Admin），
+    /// 已经是 Admin 时不变并返回 false
+    pub fn promote(&mut self) -> bool {
+        match self.role.child() {
+            Some(role) => {
+                self.role = role;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 把角色降低一级，已经是 Viewer 时不变并返回 false
+    pub fn demote(&mut self) -> bool {
+        match self.role.parent() {
+            Some(role) => {
+                self.role = role;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// 激活用户账号
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    /// 停用用户账号
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    /// 检查用户是否激活
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// 不可变引用方法示例：返回 email 的域名（不改变实例）
+    pub fn email_domain(&self) -> &str {
+        self.email.domain()
+    }
+
+    /// 用户名
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// 邮箱地址
+    pub fn email(&self) -> &str {
+        self.email.as_str()
+    }
+
+    /// 解析后的邮箱，可以进一步取本地部分、域名和标签
+    pub fn email_address(&self) -> &Email {
+        &self.email
+    }
+
+    /// 成功登录的次数
+    pub fn sign_in_count(&self) -> u64 {
+        self.sign_in_count
+    }
+
+    /// 最近一次成功登录的时间（Unix 秒），从未登录时为 `None`
+    pub fn last_login(&self) -> Option<u64> {
+        self.last_login
+    }
+
+    /// 记录一次成功登录；只由 `crate::auth` 在校验密码通过后调用
+    pub(crate) fn record_sign_in(&mut self, at: u64) {
+        self.sign_in_count += 1;
+        self.last_login = Some(at);
+    }
+
+    /// 通过 `self` 获取所有权的示例：消费自己并返回用户名
+    pub fn into_username(self) -> String {
+        self.username
+    }
+
+    /// 按保存下来的字段还原用户（供 `crate::users` 从文件加载）
+    pub(crate) fn restore(
+        username: &str,
+        email: Email,
+        active: bool,
+        role: Role,
+        sign_in_count: u64,
+        last_login: Option<u64>,
+    ) -> User {
+        User {
+            active,
+            username: username.to_string(),
+            email,
+            sign_in_count,
+            last_login,
+            role,
+        }
+    }
+}
+
+// 角色与权限：用枚举表示有限的几种取值，用 match 把规则写成穷尽的表
+/// 用户角色，权限从低到高；高一级的角色继承低一级的全部权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    Viewer,
+    Editor,
+    Admin,
+}
+
+/// 需要授权的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    Read,
+    Comment,
+    Edit,
+    Publish,
+    Delete,
+    ManageUsers,
+}
+
+impl Action {
+    pub const ALL: [Action; 6] = [
+        Action::Read,
+        Action::Comment,
+        Action::Edit,
+        Action::Publish,
+        Action::Delete,
+        Action::ManageUsers,
+    ];
+}
+
+impl Role {
+    pub const ALL: [Role; 3] = [Role::Viewer, Role::Editor, Role::Admin];
+
+    /// 直接继承的角色（低一级）
+    pub fn parent(self) -> Option<Role> {
+        match self {
+            Role::Viewer => None,
+            Role::Editor => Some(Role::Viewer),
+            Role::Admin => Some(Role::Editor),
+        }
+    }
+
+    /// 高一级的角色
+    pub fn child(self) -> Option<Role> {
+        Role::ALL.into_iter().find(|role| role.parent() == Some(self))
+    }
+
+    /// 这个角色自己新增的权限（不含继承来的）
+    pub fn grants(self) -> &'static [Action] {
+        match self {
+            Role::Viewer => &[Action::Read, Action::Comment],
+            Role::Editor => &[Action::Edit, Action::Publish],
+            Role::Admin => &[Action::Delete, Action::ManageUsers],
+        }
+    }
+
+    /// 自己和沿继承链向下的所有角色，例如 Admin → Editor → Viewer
+    pub fn lineage(self) -> impl Iterator<Item = Role> {
+        std::iter::successors(Some(self), |role| role.parent())
+    }
+
+    /// 是否拥有某个权限（含继承）
+    pub fn allows(self, action: Action) -> bool {
+        self.lineage().any(|role| role.grants().contains(&action))
+    }
+
+    /// 文件里保存的名字
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Editor => "editor",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// 解析 [`Role::name`] 的结果，忽略大小写
+    pub fn parse(name: &str) -> Option<Role> {
+        Role::ALL
+            .into_iter()
+            .find(|role| role.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// 授权检查：停用的用户什么都不能做，否则看角色（含继承）是否允许
+pub fn can(user: &User, action: Action) -> bool {
+    user.is_active() && user.role().allows(action)
+}
+
+fn test_roles() {
+    let mut user = User::from_email("editor@example.org").expect("示例邮箱是合法的");
+    println!("{} starts as {:?}", user.username(), user.role());
+    user.promote();
+    println!("after promote: {:?}", user);
+
+    // 打印每个角色的权限表
+    let header: Vec<String> = Action::ALL
+        .iter()
+        .map(|action| format!("{:<12}", format!("{:?}", action)))
+        .collect();
+    println!("{:<8} {}", "", header.join(" ").trim_end());
+    for role in Role::ALL {
+        let cells: Vec<String> = Action::ALL
+            .iter()
+            .map(|&action| format!("{:<12}", if role.allows(action) { "yes" } else { "-" }))
+            .collect();
+        println!("{:<8} {}", format!("{:?}", role), cells.join(" ").trim_end());
+    }
+
+    println!("editor can publish: {}", can(&user, Action::Publish));
+    user.deactivate();
+    println!("deactivated editor can read: {}", can(&user, Action::Read));
+}
+
+pub fn run() {
+    // 测试单元结构体
+    println!("test_unit_struct:");
+    test_unit_struct();
+
+    // 测试用户结构体
+    println!("\n\n");
+    println!("test_user:");
+    
+    let mut user1 = User::new(
+        Email::parse("someone@example.com").expect("示例邮箱是合法的"),
+        String::from("someusername123")
+    );
+    println!("user1 active status: {}", user1.is_active());
+    println!("new user: {:?}", user1);
+
+    // 使用不可变方法读取信息（不会消耗所有权）
+    println!("email domain: {}", user1.email_domain());
+
+    // 演示停用和重新激活用户
+    user1.deactivate();
+    println!("after deactivate: {:?}", user1);
+    println!("user1 active status: {}", user1.is_active());
+    
+    user1.activate();
+    println!("after activate: {:?}", user1);
+    println!("user1 active status: {}", user1.is_active());
+
+    // 使用 by-value 方法会移动（消费）实例
+    let username = user1.into_username();
+    println!("moved out username: {}", username);
+    // println!("try to use user1, {:?}", user1);
+    // 此处不能再使用 user1，因为它已被移动到 into_username()（E0382，见 tests/ui/06_structs_use_after_into_username.rs）
+
+    // 使用关联函数构造另一个实例
+    println!("\n\n");
+    println!("test_user_from_email:");
+    
+    let user2 = User::from_email("alice@example.org").expect("示例邮箱是合法的");
+    println!("user2: {:?}", user2);
+    println!("user2 active status: {}", user2.is_active());
+
+    // 不合法的邮箱不会得到一个「unknown」用户，而是返回 Err
+    for email in ["a@b@c", "@example.org", ""] {
+        match User::from_email(email) {
+            Ok(user) => println!("{:?} -> {:?}", email, user),
+            Err(e) => println!("{:?} -> error: {}", email, e),
+        }
+    }
+
+    // 角色和权限
+    println!("\n\n");
+    println!("test_roles:");
+    test_roles();
+
+
+    // 测试元组结构体
+    println!("\n\n");
+    println!("test_tuple_struct:");
+
+    let black: Color = Color(0, 0, 0);
+    let origin = Point(0, 0, 0);
+    println!("black = {:?}, origin = {:?}", black, origin);
+    println!("black.0 = {}", black.0);
+    println!("black.1 = {}", black.1);
+    println!("black.2 = {}", black.2);
+    println!("origin.0 = {}", origin.0);
+    println!("origin.1 = {}", origin.1);
+    println!("origin.2 = {}", origin.2);
+
+    // 元组结构体也可以有方法（实现在 src/color.rs）
+    let orange: Color = "#ff8800".parse().expect("示例颜色是合法的");
+    let hsl = orange.to_hsl();
+    println!("orange = {:?} = {}, hsl = ({:.0}, {:.2}, {:.2})", orange, orange, hsl.h, hsl.s, hsl.l);
+    println!("orange over black at 50% = {}", orange.blend(black, 0.5));
+    println!("contrast with white = {:.2}", orange.contrast_ratio(Color::WHITE));
+    match Color::try_from((300, 0, 0)) {
+        Ok(color) => println!("Color from (300, 0, 0) = {:?}", color),
+        Err(e) => println!("Color from (300, 0, 0) -> error: {}", e),
+    }
+
+    // 转换成 Vec3 后可以用 + - * 运算（运算符重载，实现在 src/vector.rs）
+    let a = Vec3::from(Point(1, 2, 3));
+    let b = Vec3::from(Point(4, 5, 6));
+    println!("a + b = {}, b - a = {}, a * 2 = {}, -a = {}", a + b, b - a, a * 2, -a);
+    println!("a . b = {}, a x b = {}", a.dot(b), a.cross(b));
+    println!("|a| = {:.3}, distance(a, b) = {:.3}", a.length(), a.distance(b));
+    if let Some(unit) = a.normalize() {
+        println!("a normalized = {:.3}", unit);
+    }
+    println!("back to Point: {:?}", Point::from(a + b));
+    
+    // 关联函数与方法的要点总结（简明）:
+    // - 调用方式：关联函数 `Type::fn(...)`，方法 `value.fn(...)`。
+    // - 是否有 `self`：关联函数没有 `self`，方法第一个参数必须是 `self`/`&self`/`&mut self`。
+    // - 访问字段：只有方法（有 `self`）可以访问或修改实例字段；关联函数不能直接访问实例字段。
+    // - 用途：关联函数常作构造器/工厂/辅助函数；方法用于与实例交互（读/写/消费）。
+}
@@ -0,0 +1,303 @@
+//! Trait（特质）：Rust 中的接口系统
+//!
+//! Trait 是 Rust 中实现代码复用和多态的核心机制，类似于其他语言中的接口（interface）。
+//! 它定义了一组方法签名，类型可以实现这些方法来提供特定的行为。
+
+use crate::ansi::heading;
+use crate::i18n::t;
+use crate::vector::Vec2;
+
+// ===============================================================================
+// 示例 1: 基本 Trait 定义
+// ===============================================================================
+
+/// 定义一个 `Summary` trait，包含一个 `summarize` 方法
+pub trait Summary {
+    /// 返回一个摘要字符串
+    fn summarize(&self) -> String;
+}
+
+// ===============================================================================
+// 示例 2: 为类型实现 Trait
+// ===============================================================================
+
+/// 新闻文章结构体
+pub struct NewsArticle {
+    pub headline: String,
+    pub location: String,
+    pub author: String,
+    pub content: String,
+}
+
+/// 为 `NewsArticle` 实现 `Summary` trait
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+}
+
+/// 推文结构体
+pub struct Tweet {
+    pub username: String,
+    pub content: String,
+    pub reply: bool,
+    pub retweet: bool,
+}
+
+/// 为 `Tweet` 实现 `Summary` trait
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+// ===============================================================================
+// 示例 3: Trait 方法的默认实现
+// ===============================================================================
+
+/// 定义一个带有默认实现的 `DefaultSummary` trait
+pub trait DefaultSummary {
+    /// 返回一个摘要字符串（默认实现）
+    fn summarize(&self) -> String {
+        String::from("(Read more...)")
+    }
+}
+
+/// 为 `NewsArticle` 实现 `DefaultSummary` trait（使用默认实现）
+impl DefaultSummary for NewsArticle {
+    // 这里没有重写 summarize 方法，将使用默认实现
+}
+
+/// 为 `Tweet` 实现 `DefaultSummary` trait（重写默认实现）
+impl DefaultSummary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {} (custom default)", self.username, self.content)
+    }
+}
+
+// ===============================================================================
+// 示例 4: Trait 作为参数
+// ===============================================================================
+
+/// 接受任何实现了 `Summary` trait 的类型
+pub fn notify(item: &impl Summary) {
+    println!("Breaking news! {}", item.summarize());
+}
+
+/// 使用 trait 约束的语法（与上面等价）
+pub fn notify_generic<T: Summary>(item: &T) {
+    println!("Breaking news! {}", item.summarize());
+}
+
+/// 多个 trait 约束（使用 + 号）
+pub fn notify_multiple<T: Summary + Default>(item: &T) {
+    println!("Breaking news! {}", item.summarize());
+}
+
+/// 使用 where 子句简化多个 trait 约束
+pub fn notify_where<T>(item: &T) where T: Summary + Default {
+    println!("Breaking news! {}", item.summarize());
+}
+
+// ===============================================================================
+// 示例 5: Trait 作为返回类型
+// ===============================================================================
+
+/// 返回一个实现了 `Summary` trait 的类型
+pub fn returns_summarizable() -> impl Summary {
+    Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("of course, as you probably already know, people"),
+        reply: false,
+        retweet: false,
+    }
+}
+
+// 注意：返回 impl Trait 时，所有可能的返回值必须是同一类型
+// 下面的函数会编译失败（E0308），因为它可能返回两种不同类型，见 tests/ui/14_trait_impl_trait_two_types.rs
+/*
+fn returns_summarizable_condition(switch: bool) -> impl Summary {
+    if switch {
+        NewsArticle {
+            headline: String::from("Penguins win the Stanley Cup Championship!"),
+            location: String::from("Pittsburgh, PA, USA"),
+            author: String::from("Iceburgh"),
+            content: String::from("The Pittsburgh Penguins once again are the best hockey team in the NHL."),
+        }
+    } else {
+        Tweet {
+            username: String::from("horse_ebooks"),
+            content: String::from("of course, as you probably already know, people"),
+            reply: false,
+            retweet: false,
+        }
+    }
+}
+*/
+
+// ===============================================================================
+// 示例 6: 关联类型（Associated Types）
+// ===============================================================================
+
+/// 定义一个带有关联类型的 `Iterator` trait（简化版）
+pub trait Iterator {
+    /// 关联类型，表示迭代器产生的元素类型
+    type Item;
+    
+    /// 下一个元素
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+/// 实现一个简单的计数器迭代器
+pub struct Counter {
+    pub count: u32,
+    pub max: u32,
+}
+
+/// 为 `Counter` 实现 `Iterator` trait
+impl Iterator for Counter {
+    // 指定关联类型 `Item` 为 `u32`
+    type Item = u32;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < self.max {
+            let current = self.count;
+            self.count += 1;
+            Some(current)
+        } else {
+            None
+        }
+    }
+}
+
+// ===============================================================================
+// 示例 7: Derive Trait（派生特质）
+// ===============================================================================
+
+/// 使用 #[derive] 属性自动实现常见的 trait
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+// ===============================================================================
+// 示例 8: 特质继承
+// ===============================================================================
+
+/// 定义一个 `Drawable` trait
+pub trait Drawable {
+    fn draw(&self);
+}
+
+/// 定义一个 `Clickable` trait，继承自 `Drawable`
+pub trait Clickable: Drawable {
+    fn click(&self);
+}
+
+/// 按钮结构体
+pub struct Button {
+    pub label: String,
+}
+
+/// 为 `Button` 实现 `Drawable` trait
+impl Drawable for Button {
+    fn draw(&self) {
+        println!("Drawing button: {}", self.label);
+    }
+}
+
+/// 为 `Button` 实现 `Clickable` trait
+impl Clickable for Button {
+    fn click(&self) {
+        println!("Clicking button: {}", self.label);
+    }
+}
+
+// ===============================================================================
+// 示例 9: 运算符重载
+// ===============================================================================
+
+// `+`、`-`、`*` 等运算符背后是 std::ops 里的 trait（Add、Sub、Mul、Neg……），
+// 实现了它们的类型就能使用对应的运算符。Point 只是数据，
+// 转换成 src/vector.rs 中的泛型 Vec2<T> 后就可以做向量运算：
+//
+//     impl<T: Add<Output = T>> Add for Vec2<T> {
+//         type Output = Vec2<T>;
+//         fn add(self, other: Vec2<T>) -> Vec2<T> { ... }
+//     }
+
+// ===============================================================================
+// 课程入口：测试所有示例
+// ===============================================================================
+
+/// 按顺序演示所有示例
+pub fn run() {
+    println!("{}", heading(&format!("=== {} ===", t("traits.example_1_2"))));
+    let article: NewsArticle = NewsArticle {
+        headline: String::from("Penguins win the Stanley Cup Championship!"),
+        location: String::from("Pittsburgh, PA, USA"),
+        author: String::from("Iceburgh"),
+        content: String::from("The Pittsburgh Penguins once again are the best hockey team in the NHL."),
+    };
+    
+    let tweet = Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("of course, as you probably already know, people"),
+        reply: false,
+        retweet: false,
+    };
+    
+    println!("Article summary: {}", Summary::summarize(&article));
+    println!("Tweet summary: {}", Summary::summarize(&tweet));
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_3"))));
+    println!("Article default summary: {}", <NewsArticle as DefaultSummary>::summarize(&article));
+    println!("Tweet default summary: {}", <Tweet as DefaultSummary>::summarize(&tweet));
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_4"))));
+    notify(&article);
+    notify_generic(&tweet);
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_5"))));
+    let summarizable = returns_summarizable();
+    println!("Returned summarizable: {}", summarizable.summarize());
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_6"))));
+    let mut counter = Counter { count: 0, max: 5 };
+    println!("Counter values:");
+    while let Some(value) = counter.next() {
+        println!("  {}", value);
+    }
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_7"))));
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = Point { x: 1, y: 2 };
+    let p3 = Point { x: 3, y: 4 };
+    println!("p1: {:?}", p1);
+    println!("p1 == p2: {}", p1 == p2);
+    println!("p1 == p3: {}", p1 == p3);
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_8"))));
+    let button = Button { label: String::from("Submit") };
+    button.draw();
+    button.click();
+    
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.example_9"))));
+    let start = Vec2::from(p1);
+    let step = Vec2::from(p3);
+    println!("start + step = {}", start + step);
+    println!("start - step = {}", start - step);
+    println!("step * 3 = {}, -step = {}", step * 3, -step);
+    println!("start . step = {}", start.dot(step));
+    println!("distance = {:.3}", start.distance(step));
+    let end: Point = (start + step * 2).into();
+    println!("end: {:?}", end);
+    let third = start.to_f64() + (step.to_f64() - start.to_f64()) * (1.0 / 3.0);
+    println!("one third of the way = {:.3} -> rounded {}", third, third.round());
+
+    println!("\n{}", heading(&format!("=== {} ===", t("traits.summary"))));
+    for n in 1..=9 {
+        println!("{}", t(&format!("traits.summary_{}", n)));
+    }
+}
@@ -0,0 +1,433 @@
+//! 用户仓库：存取第 6 课的 `User`，保证用户名和邮箱都不重复
+//!
+//! [`UserRepository`] 定义增删改查接口，[`MemoryUserRepository`] 把用户放在内存里，
+//! [`FileUserRepository`] 在它的基础上把每次修改写回文本文件。
+//! 文件每行一个用户，空行和 `#` 开头的行会被忽略：
+//!
+//! ```text
+//! # rust-learning users
+//! version 2
+//! user alice alice@example.org active admin 3 1760601600 pbkdf2-sha256$100000$<盐>$<哈希>
+//! user bob bob@example.com inactive viewer 0 - -
+//! ```
+//!
+//! 字段依次是用户名、邮箱、是否激活、角色、登录次数、最近一次登录的 Unix 时间（`-` 表示从未登录）
+//! 和密码哈希（格式见 [`PasswordHash`]，`-` 表示没有设置密码）。用户名不能为空或包含空白；
+//! 邮箱比较时忽略大小写。
+//!
+//! `version` 行标明格式版本，写在所有用户之前。没有这一行的是早期版本写的文件，按字段个数识别，
+//! 缺少的字段取默认值（角色 `viewer`、从未登录、没有密码）：
+//!
+//! ```text
+//! user alice alice@example.org active 3
+//! user alice alice@example.org active 3 1760601600
+//! user alice alice@example.org active admin 3 1760601600
+//! ```
+//!
+//! 下一次修改时文件会以当前版本整体写回。
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::auth::PasswordHash;
+use crate::email::Email;
+use crate::files::atomic_write;
+use crate::structs::{Role, User};
+
+const HEADER: &str = "# rust-learning users";
+
+/// 当前的用户文件格式版本；没有 `version` 行的旧文件视为版本 1
+const VERSION: u32 = 2;
+
+/// 用户仓库的错误
+#[derive(Debug)]
+pub enum UserError {
+    /// 用户名为空或包含空白字符
+    InvalidField {
+        field: &'static str,
+        value: String,
+    },
+    UsernameTaken(String),
+    EmailTaken(String),
+    NotFound(String),
+    /// 用户文件无法解析，带行号
+    Parse {
+        line: usize,
+        message: String,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserError::InvalidField { field, value } => write!(f, "无效的{}: {:?}", field, value),
+            UserError::UsernameTaken(username) => write!(f, "用户名已存在: {}", username),
+            UserError::EmailTaken(email) => write!(f, "邮箱已被使用: {}", email),
+            UserError::NotFound(username) => write!(f, "用户不存在: {}", username),
+            UserError::Parse { line, message } => {
+                write!(f, "用户文件第 {} 行: {}", line, message)
+            }
+            UserError::Io(e) => write!(f, "读写用户文件失败: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for UserError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UserError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for UserError {
+    fn from(e: io::Error) -> Self {
+        UserError::Io(e)
+    }
+}
+
+/// 用户的存取接口，以用户名作为主键
+pub trait UserRepository {
+    /// 新增用户；用户名或邮箱已被占用时失败
+    fn create(&mut self, user: User) -> Result<(), UserError>;
+
+    fn find_by_username(&self, username: &str) -> Option<&User>;
+
+    /// 按邮箱查找（忽略大小写）
+    fn find_by_email(&self, email: &str) -> Option<&User>;
+
+    /// 用同名用户替换已有记录，返回旧记录；新邮箱不能与其他用户重复
+    fn update(&mut self, user: User) -> Result<User, UserError>;
+
+    /// 删除并返回指定用户，密码哈希一并删除
+    fn delete(&mut self, username: &str) -> Result<User, UserError>;
+
+    /// 用户的密码哈希；用户不存在或还没有设置密码时为 `None`
+    fn password_hash(&self, username: &str) -> Option<&PasswordHash>;
+
+    /// 设置已有用户的密码哈希
+    fn set_password_hash(&mut self, username: &str, hash: PasswordHash) -> Result<(), UserError>;
+
+    /// 全部用户，按用户名排序
+    fn all(&self) -> Vec<&User>;
+
+    /// 激活状态的用户，按用户名排序
+    fn list_active(&self) -> Vec<&User> {
+        self.all()
+            .into_iter()
+            .filter(|user| user.is_active())
+            .collect()
+    }
+}
+
+fn same_email(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+/// 用户名要能写进一行、用空白分隔（邮箱在构造 `User` 时已经校验过）
+fn validate(user: &User) -> Result<(), UserError> {
+    let username = user.username();
+    if username.is_empty() || username.contains(char::is_whitespace) {
+        return Err(UserError::InvalidField {
+            field: "用户名",
+            value: username.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// 内存中的用户仓库
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryUserRepository {
+    users: BTreeMap<String, User>,
+    /// 密码哈希和用户放在一起，删除用户时不会留下旧密码给以后同名的新用户
+    passwords: BTreeMap<String, PasswordHash>,
+}
+
+impl MemoryUserRepository {
+    pub fn new() -> MemoryUserRepository {
+        MemoryUserRepository::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    /// 邮箱是否被 `except` 以外的用户占用
+    fn email_taken(&self, email: &str, except: &str) -> bool {
+        self.users
+            .values()
+            .any(|user| user.username() != except && same_email(user.email(), email))
+    }
+
+    /// 从用户文件格式解析
+    pub fn parse(text: &str) -> Result<MemoryUserRepository, UserError> {
+        let mut repo = MemoryUserRepository::new();
+        let mut version = None;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| UserError::Parse {
+                line: index + 1,
+                message,
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if let ["version", number] = fields.as_slice() {
+                if version.is_some() || !repo.is_empty() {
+                    return Err(error(
+                        "version 行只能出现一次，且要在所有用户之前".to_string(),
+                    ));
+                }
+                let number = number
+                    .parse()
+                    .ok()
+                    .filter(|number| (1..=VERSION).contains(number))
+                    .ok_or_else(|| error(format!("不支持的文件版本: {}", number)))?;
+                version = Some(number);
+                continue;
+            }
+            // 旧版本的行缺少后来加入的字段，补上默认值
+            let legacy = version.unwrap_or(1) < VERSION;
+            let [username, email, status, role, count, last_login, password] =
+                match *fields.as_slice() {
+                    [
+                        "user",
+                        username,
+                        email,
+                        status,
+                        role,
+                        count,
+                        last_login,
+                        password,
+                    ] => [username, email, status, role, count, last_login, password],
+                    ["user", username, email, status, count] if legacy => {
+                        [username, email, status, "viewer", count, "-", "-"]
+                    }
+                    ["user", username, email, status, count, last_login] if legacy => {
+                        [username, email, status, "viewer", count, last_login, "-"]
+                    }
+                    ["user", username, email, status, role, count, last_login] if legacy => {
+                        [username, email, status, role, count, last_login, "-"]
+                    }
+                    _ => return Err(error(format!("无法解析: {}", line))),
+                };
+            let active = match status {
+                "active" => true,
+                "inactive" => false,
+                other => return Err(error(format!("未知的状态: {}", other))),
+            };
+            let role = Role::parse(role).ok_or_else(|| error(format!("未知的角色: {}", role)))?;
+            let count = count
+                .parse()
+                .map_err(|_| error(format!("登录次数不是整数: {}", count)))?;
+            let last_login = match last_login {
+                "-" => None,
+                at => Some(
+                    at.parse()
+                        .map_err(|_| error(format!("登录时间不是整数: {}", at)))?,
+                ),
+            };
+            let password = match password {
+                "-" => None,
+                hash => Some(
+                    hash.parse::<PasswordHash>()
+                        .map_err(|e| error(format!("密码哈希: {}", e)))?,
+                ),
+            };
+            let email = Email::parse(email).map_err(|e| error(format!("邮箱 {}: {}", email, e)))?;
+            repo.create(User::restore(
+                username, email, active, role, count, last_login,
+            ))
+            .map_err(|e| error(e.to_string()))?;
+            if let Some(hash) = password {
+                repo.passwords.insert(username.to_string(), hash);
+            }
+        }
+        Ok(repo)
+    }
+
+    /// 序列化为用户文件格式
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\nversion {}\n", HEADER, VERSION);
+        for user in self.users.values() {
+            let status = if user.is_active() {
+                "active"
+            } else {
+                "inactive"
+            };
+            let last_login = user
+                .last_login()
+                .map_or_else(|| "-".to_string(), |at| at.to_string());
+            let password = self
+                .passwords
+                .get(user.username())
+                .map_or_else(|| "-".to_string(), |hash| hash.to_string());
+            let _ = writeln!(
+                text,
+                "user {} {} {} {} {} {} {}",
+                user.username(),
+                user.email(),
+                status,
+                user.role().name(),
+                user.sign_in_count(),
+                last_login,
+                password
+            );
+        }
+        text
+    }
+}
+
+impl UserRepository for MemoryUserRepository {
+    fn create(&mut self, user: User) -> Result<(), UserError> {
+        validate(&user)?;
+        if self.users.contains_key(user.username()) {
+            return Err(UserError::UsernameTaken(user.username().to_string()));
+        }
+        if self.email_taken(user.email(), "") {
+            return Err(UserError::EmailTaken(user.email().to_string()));
+        }
+        self.users.insert(user.username().to_string(), user);
+        Ok(())
+    }
+
+    fn find_by_username(&self, username: &str) -> Option<&User> {
+        self.users.get(username)
+    }
+
+    fn find_by_email(&self, email: &str) -> Option<&User> {
+        self.users
+            .values()
+            .find(|user| same_email(user.email(), email))
+    }
+
+    fn update(&mut self, user: User) -> Result<User, UserError> {
+        validate(&user)?;
+        if !self.users.contains_key(user.username()) {
+            return Err(UserError::NotFound(user.username().to_string()));
+        }
+        if self.email_taken(user.email(), user.username()) {
+            return Err(UserError::EmailTaken(user.email().to_string()));
+        }
+        let old = self.users.insert(user.username().to_string(), user);
+        Ok(old.expect("上面已确认用户存在"))
+    }
+
+    fn delete(&mut self, username: &str) -> Result<User, UserError> {
+        let user = self
+            .users
+            .remove(username)
+            .ok_or_else(|| UserError::NotFound(username.to_string()))?;
+        self.passwords.remove(username);
+        Ok(user)
+    }
+
+    fn password_hash(&self, username: &str) -> Option<&PasswordHash> {
+        self.passwords.get(username)
+    }
+
+    fn set_password_hash(&mut self, username: &str, hash: PasswordHash) -> Result<(), UserError> {
+        if !self.users.contains_key(username) {
+            return Err(UserError::NotFound(username.to_string()));
+        }
+        self.passwords.insert(username.to_string(), hash);
+        Ok(())
+    }
+
+    fn all(&self) -> Vec<&User> {
+        self.users.values().collect()
+    }
+}
+
+/// 保存在文本文件中的用户仓库：每次修改都整体写回文件
+///
+/// 修改先在副本上进行，写文件成功后才生效；写入通过临时文件加重命名完成，
+/// 中途失败不会留下写了一半的文件。
+#[derive(Debug)]
+pub struct FileUserRepository {
+    path: PathBuf,
+    users: MemoryUserRepository,
+}
+
+impl FileUserRepository {
+    /// 打开用户文件；文件不存在时从空仓库开始（第一次修改时创建）
+    pub fn open(path: &Path) -> Result<FileUserRepository, UserError> {
+        let users = match fs::read_to_string(path) {
+            Ok(text) => MemoryUserRepository::parse(&text)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => MemoryUserRepository::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(FileUserRepository {
+            path: path.to_path_buf(),
+            users,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    fn commit<T>(
+        &mut self,
+        change: impl FnOnce(&mut MemoryUserRepository) -> Result<T, UserError>,
+    ) -> Result<T, UserError> {
+        let mut users = self.users.clone();
+        let value = change(&mut users)?;
+        atomic_write(&self.path, &users.to_text())?;
+        self.users = users;
+        Ok(value)
+    }
+}
+
+impl UserRepository for FileUserRepository {
+    fn create(&mut self, user: User) -> Result<(), UserError> {
+        self.commit(|users| users.create(user))
+    }
+
+    fn find_by_username(&self, username: &str) -> Option<&User> {
+        self.users.find_by_username(username)
+    }
+
+    fn find_by_email(&self, email: &str) -> Option<&User> {
+        self.users.find_by_email(email)
+    }
+
+    fn update(&mut self, user: User) -> Result<User, UserError> {
+        self.commit(|users| users.update(user))
+    }
+
+    fn delete(&mut self, username: &str) -> Result<User, UserError> {
+        self.commit(|users| users.delete(username))
+    }
+
+    fn password_hash(&self, username: &str) -> Option<&PasswordHash> {
+        self.users.password_hash(username)
+    }
+
+    fn set_password_hash(&mut self, username: &str, hash: PasswordHash) -> Result<(), UserError> {
+        self.commit(|users| users.set_password_hash(username, hash))
+    }
+
+    fn all(&self) -> Vec<&User> {
+        self.users.all()
+    }
+}
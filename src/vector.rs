@@ -0,0 +1,272 @@
+//! 向量：泛型的 `Vec2<T>` / `Vec3<T>`，演示用 trait 重载运算符
+//!
+//! `a + b`、`a - b`、`v * 2`、`-v` 只是 `Add::add`、`Sub::sub`、`Mul::mul`、`Neg::neg`
+//! 的语法糖：为类型实现 `std::ops` 里对应的 trait，就能对它使用这些运算符。
+//! 分量类型 `T` 只要自己支持相应的运算即可，所以同一份实现对 `i32` 和 `f64` 都适用。
+//!
+//! 第 6 课的元组结构体 `Point(x, y, z)` 和第 14 课的 `Point { x, y }` 只是数据，
+//! 这里提供和它们互相转换的 `From` 实现。
+//!
+//! ```
+//! use rust_learning::vector::Vec3;
+//!
+//! let a = Vec3::new(1, 2, 3);
+//! let b = Vec3::new(4, 5, 6);
+//! assert_eq!(a + b, Vec3::new(5, 7, 9));
+//! assert_eq!(a.dot(b), 32);
+//! assert_eq!(a.cross(b), Vec3::new(-3, 6, -3));
+//! assert_eq!(Vec3::new(3, 4, 0).length(), 5.0);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::structs;
+use crate::traits;
+
+/// 二维向量
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// 三维向量
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+}
+
+// ===== 运算符重载 =====
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// 数乘：`v * k`，右边的类型参数就是 `Mul<T>` 里的 `T`
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, k: T) -> Vec2<T> {
+        Vec2::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, k: T) -> Vec3<T> {
+        Vec3::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Vec3<T> {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+// ===== 点积、叉积 =====
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Vec2<T> {
+    pub fn dot(self, other: Vec2<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// 二维叉积（z 分量），为正时 `other` 在 `self` 的逆时针方向
+    pub fn cross(self, other: Vec2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Vec3<T> {
+    pub fn dot(self, other: Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// 叉积，结果同时垂直于 `self` 和 `other`
+    pub fn cross(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+// ===== 长度、归一化、距离 =====
+// 分量能无损转成 f64（i32、f32、f64 等）时才有长度，结果总是 f64
+
+impl<T: Copy + Into<f64>> Vec2<T> {
+    pub fn to_f64(self) -> Vec2<f64> {
+        Vec2::new(self.x.into(), self.y.into())
+    }
+
+    pub fn length(self) -> f64 {
+        let v = self.to_f64();
+        v.dot(v).sqrt()
+    }
+
+    /// 同方向的单位向量；零向量没有方向，返回 `None`
+    pub fn normalize(self) -> Option<Vec2<f64>> {
+        let length = self.length();
+        (length > 0.0).then(|| self.to_f64() * length.recip())
+    }
+
+    pub fn distance(self, other: Vec2<T>) -> f64 {
+        (self.to_f64() - other.to_f64()).length()
+    }
+}
+
+impl<T: Copy + Into<f64>> Vec3<T> {
+    pub fn to_f64(self) -> Vec3<f64> {
+        Vec3::new(self.x.into(), self.y.into(), self.z.into())
+    }
+
+    pub fn length(self) -> f64 {
+        let v = self.to_f64();
+        v.dot(v).sqrt()
+    }
+
+    /// 同方向的单位向量；零向量没有方向，返回 `None`
+    pub fn normalize(self) -> Option<Vec3<f64>> {
+        let length = self.length();
+        (length > 0.0).then(|| self.to_f64() * length.recip())
+    }
+
+    pub fn distance(self, other: Vec3<T>) -> f64 {
+        (self.to_f64() - other.to_f64()).length()
+    }
+}
+
+// ===== 整数与浮点互转 =====
+
+/// 四舍五入到最近的整数，超出 `i32` 范围时取边界值，NaN 变成 0
+fn round_i32(value: f64) -> i32 {
+    value.round() as i32
+}
+
+impl Vec2<f64> {
+    pub fn round(self) -> Vec2<i32> {
+        Vec2::new(round_i32(self.x), round_i32(self.y))
+    }
+}
+
+impl Vec3<f64> {
+    pub fn round(self) -> Vec3<i32> {
+        Vec3::new(round_i32(self.x), round_i32(self.y), round_i32(self.z))
+    }
+}
+
+impl From<Vec2<i32>> for Vec2<f64> {
+    fn from(v: Vec2<i32>) -> Vec2<f64> {
+        v.to_f64()
+    }
+}
+
+impl From<Vec3<i32>> for Vec3<f64> {
+    fn from(v: Vec3<i32>) -> Vec3<f64> {
+        v.to_f64()
+    }
+}
+
+// ===== 与课程中的 Point 互转 =====
+
+impl From<structs::Point> for Vec3<i32> {
+    fn from(structs::Point(x, y, z): structs::Point) -> Vec3<i32> {
+        Vec3::new(x, y, z)
+    }
+}
+
+impl From<Vec3<i32>> for structs::Point {
+    fn from(v: Vec3<i32>) -> structs::Point {
+        structs::Point(v.x, v.y, v.z)
+    }
+}
+
+impl From<traits::Point> for Vec2<i32> {
+    fn from(traits::Point { x, y }: traits::Point) -> Vec2<i32> {
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Vec2<i32>> for traits::Point {
+    fn from(v: Vec2<i32>) -> traits::Point {
+        traits::Point { x: v.x, y: v.y }
+    }
+}
+
+/// 按 `(x, y)` 显示；`{:.2}` 这样的格式参数会作用到每个分量上
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        self.x.fmt(f)?;
+        write!(f, ", ")?;
+        self.y.fmt(f)?;
+        write!(f, ")")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        self.x.fmt(f)?;
+        write!(f, ", ")?;
+        self.y.fmt(f)?;
+        write!(f, ", ")?;
+        self.z.fmt(f)?;
+        write!(f, ")")
+    }
+}
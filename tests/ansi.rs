@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use rust_learning::ansi::{self, ColorLevel, HEADING, RESET, Style};
+use rust_learning::structs::Color;
+
+fn detect(is_tty: bool, vars: &[(&str, &str)]) -> ColorLevel {
+    let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+    ColorLevel::detect(is_tty, |name| vars.get(name).map(|value| value.to_string()))
+}
+
+#[test]
+fn escapes_for_each_color_level() {
+    let orange = Style::new().fg(Color(255, 136, 0));
+    let cases = [
+        (ColorLevel::Plain, "text"),
+        (ColorLevel::Ansi16, "\x1b[33mtext\x1b[0m"),
+        (ColorLevel::Ansi256, "\x1b[38;5;208mtext\x1b[0m"),
+        (ColorLevel::TrueColor, "\x1b[38;2;255;136;0mtext\x1b[0m"),
+    ];
+    for (level, expected) in cases {
+        assert_eq!(orange.paint("text", level), expected, "{:?}", level);
+    }
+
+    let on_navy = Style::new().bg(Color(0, 0, 128));
+    assert_eq!(on_navy.paint("x", ColorLevel::Ansi16), "\x1b[44mx\x1b[0m");
+    assert_eq!(
+        on_navy.paint("x", ColorLevel::Ansi256),
+        "\x1b[48;5;18mx\x1b[0m"
+    );
+    assert_eq!(
+        on_navy.paint("x", ColorLevel::TrueColor),
+        "\x1b[48;2;0;0;128mx\x1b[0m"
+    );
+}
+
+#[test]
+fn effects_are_combined_in_one_sequence() {
+    let style = Style::new()
+        .underline()
+        .bold()
+        .fg(Color::WHITE)
+        .bg(Color(205, 0, 0));
+    assert_eq!(style.prefix(ColorLevel::Ansi16), "\x1b[1;4;97;41m");
+    assert_eq!(
+        style.prefix(ColorLevel::TrueColor),
+        "\x1b[1;4;38;2;255;255;255;48;2;205;0;0m"
+    );
+    assert_eq!(
+        Style::new().bold().paint("b", ColorLevel::Ansi16),
+        "\x1b[1mb\x1b[0m"
+    );
+    // 空样式和纯文本模式都不输出转义
+    assert_eq!(Style::new().paint("x", ColorLevel::TrueColor), "x");
+    assert_eq!(style.prefix(ColorLevel::Plain), "");
+    assert_eq!(
+        HEADING.paint("=== 示例 7: 派生 Trait ===", ColorLevel::Ansi16),
+        format!("\x1b[1;36m=== 示例 7: 派生 Trait ==={}", RESET)
+    );
+}
+
+#[test]
+fn colors_map_to_nearest_palette_entries() {
+    let cases = [
+        // (颜色, 16 色, 256 色)
+        (Color(0, 0, 0), 0, 16),
+        (Color(255, 255, 255), 15, 231),
+        (Color(255, 0, 0), 9, 196),
+        (Color(205, 0, 0), 1, 160),
+        (Color(0, 0, 128), 4, 18),
+        (Color(128, 128, 128), 8, 244),
+        (Color(18, 18, 18), 0, 233),
+        (Color(255, 136, 0), 3, 208),
+        (Color(95, 135, 175), 8, 67),
+    ];
+    for (color, basic, indexed) in cases {
+        assert_eq!(ansi::ansi16(color), basic, "{:?}", color);
+        assert_eq!(ansi::ansi256(color), indexed, "{:?}", color);
+    }
+}
+
+#[test]
+fn detection_respects_no_color_and_tty() {
+    let truecolor = [("TERM", "xterm-256color"), ("COLORTERM", "truecolor")];
+    assert_eq!(detect(true, &truecolor), ColorLevel::TrueColor);
+    assert_eq!(detect(false, &truecolor), ColorLevel::Plain);
+    assert_eq!(
+        detect(true, &[("NO_COLOR", "1"), ("COLORTERM", "24bit")]),
+        ColorLevel::Plain
+    );
+    // 按约定，空的 NO_COLOR 不算设置
+    assert_eq!(
+        detect(true, &[("NO_COLOR", ""), ("TERM", "xterm-256color")]),
+        ColorLevel::Ansi256
+    );
+    assert_eq!(detect(true, &[("TERM", "xterm")]), ColorLevel::Ansi16);
+    assert_eq!(detect(true, &[]), ColorLevel::Ansi16);
+    assert_eq!(
+        detect(true, &[("TERM", "dumb"), ("COLORTERM", "truecolor")]),
+        ColorLevel::Plain
+    );
+}
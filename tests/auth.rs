@@ -0,0 +1,265 @@
+use std::env;
+use std::fs;
+
+use rust_learning::auth::{AuthConfig, AuthError, Authenticator, MAX_ITERATIONS, PasswordHash};
+use rust_learning::structs::User;
+use rust_learning::users::{FileUserRepository, MemoryUserRepository, UserRepository};
+
+const CONFIG: AuthConfig = AuthConfig {
+    // 测试里用较少的迭代次数，避免 debug 构建太慢
+    iterations: 1_000,
+    max_failures: 3,
+    lockout_secs: 600,
+    session_ttl_secs: 3_600,
+};
+
+fn setup() -> Authenticator<MemoryUserRepository> {
+    let mut auth = Authenticator::with_config(MemoryUserRepository::new(), CONFIG);
+    auth.register(User::from_email("alice@example.org").unwrap(), "s3cret!")
+        .unwrap();
+    auth
+}
+
+fn alice(auth: &Authenticator<MemoryUserRepository>) -> &User {
+    auth.users().find_by_username("alice").unwrap()
+}
+
+#[test]
+fn password_hashes_are_salted_and_round_trip() {
+    let a = PasswordHash::new("hunter2", 1_000);
+    let b = PasswordHash::new("hunter2", 1_000);
+    assert_ne!(a, b);
+    assert!(a.verify("hunter2") && b.verify("hunter2"));
+    assert!(!a.verify("hunter3"));
+
+    let text = a.to_string();
+    assert!(text.starts_with("pbkdf2-sha256$1000$"), "{}", text);
+    assert_eq!(text.parse::<PasswordHash>(), Ok(a));
+
+    // 同样的盐和迭代次数得到同样的哈希
+    let salt = *b"0123456789abcdef";
+    let fixed = PasswordHash::with_salt("password", salt, 1);
+    assert_eq!(fixed, PasswordHash::with_salt("password", salt, 1));
+    assert!(
+        fixed
+            .to_string()
+            .starts_with("pbkdf2-sha256$1$30313233343536373839616263646566$")
+    );
+    // 迭代次数有上限，损坏的文件不能让一次登录算上几十亿轮
+    let limit = format!(
+        "pbkdf2-sha256${}$30313233343536373839616263646566${}",
+        MAX_ITERATIONS,
+        "00".repeat(32)
+    );
+    assert_eq!(
+        limit.parse::<PasswordHash>().unwrap().iterations(),
+        MAX_ITERATIONS
+    );
+    for bad in [
+        "",
+        "md5$1$00$00",
+        "pbkdf2-sha256$0$30313233343536373839616263646566$00",
+        "pbkdf2-sha256$4000000000$30313233343536373839616263646566$00",
+        "pbkdf2-sha256$1$zz$00",
+        "pbkdf2-sha256$1$3031$00",
+    ] {
+        assert!(bad.parse::<PasswordHash>().is_err(), "{:?}", bad);
+    }
+}
+
+#[test]
+fn only_successful_logins_are_counted() {
+    let mut auth = setup();
+    assert_eq!(alice(&auth).sign_in_count(), 0);
+    assert_eq!(alice(&auth).last_login(), None);
+
+    assert!(matches!(
+        auth.authenticate("alice", "wrong", 100),
+        Err(AuthError::InvalidCredentials)
+    ));
+    assert!(matches!(
+        auth.authenticate("nobody", "s3cret!", 100),
+        Err(AuthError::InvalidCredentials)
+    ));
+    assert_eq!(alice(&auth).sign_in_count(), 0);
+
+    let session = auth.authenticate("alice", "s3cret!", 200).unwrap();
+    assert_eq!(session.username, "alice");
+    assert_eq!(session.expires_at, 200 + 3_600);
+    assert_eq!(session.token.len(), 64);
+    auth.authenticate("alice", "s3cret!", 300).unwrap();
+    assert_eq!(alice(&auth).sign_in_count(), 2);
+    assert_eq!(alice(&auth).last_login(), Some(300));
+}
+
+#[test]
+fn repeated_failures_lock_the_account() {
+    let mut auth = setup();
+    for now in [10, 11] {
+        assert!(matches!(
+            auth.authenticate("alice", "guess", now),
+            Err(AuthError::InvalidCredentials)
+        ));
+    }
+    assert!(matches!(
+        auth.authenticate("alice", "guess", 12),
+        Err(AuthError::Locked { until: 612 })
+    ));
+    // 锁定期间正确的密码也不行
+    assert!(matches!(
+        auth.authenticate("alice", "s3cret!", 300),
+        Err(AuthError::Locked { until: 612 })
+    ));
+    assert_eq!(alice(&auth).sign_in_count(), 0);
+
+    // 锁定结束后可以登录，成功登录会清零失败次数
+    auth.authenticate("alice", "s3cret!", 612).unwrap();
+    for now in [700, 701] {
+        assert!(auth.authenticate("alice", "guess", now).is_err());
+    }
+    auth.authenticate("alice", "s3cret!", 702).unwrap();
+    assert!(matches!(
+        auth.authenticate("alice", "guess", 703),
+        Err(AuthError::InvalidCredentials)
+    ));
+}
+
+#[test]
+fn unknown_usernames_lock_like_existing_ones() {
+    let mut auth = setup();
+    for name in ["alice", "nobody"] {
+        for now in [10, 11] {
+            assert!(matches!(
+                auth.authenticate(name, "guess", now),
+                Err(AuthError::InvalidCredentials)
+            ));
+        }
+        assert!(matches!(
+            auth.authenticate(name, "guess", 12),
+            Err(AuthError::Locked { until: 612 })
+        ));
+    }
+
+    // 之后注册同名用户不会继承这些失败记录
+    auth.register(User::from_email("nobody@example.org").unwrap(), "pa55")
+        .unwrap();
+    auth.authenticate("nobody", "pa55", 100).unwrap();
+}
+
+#[test]
+fn sessions_expire_and_can_be_revoked() {
+    let mut auth = setup();
+    let first = auth.authenticate("alice", "s3cret!", 0).unwrap();
+    let second = auth.authenticate("alice", "s3cret!", 1_000).unwrap();
+    assert_ne!(first.token, second.token);
+    assert_eq!(
+        auth.session_user(&first.token, 3_599).unwrap().username(),
+        "alice"
+    );
+    assert!(matches!(
+        auth.session_user(&first.token, 3_600),
+        Err(AuthError::SessionExpired)
+    ));
+    assert!(matches!(
+        auth.session_user(&first.token, 3_600),
+        Err(AuthError::InvalidSession)
+    ));
+
+    assert!(auth.logout(&second.token));
+    assert!(!auth.logout(&second.token));
+    assert!(matches!(
+        auth.session_user(&second.token, 1_001),
+        Err(AuthError::InvalidSession)
+    ));
+
+    auth.authenticate("alice", "s3cret!", 5_000).unwrap();
+    auth.authenticate("alice", "s3cret!", 9_000).unwrap();
+    assert_eq!(auth.purge_expired(8_700), 1);
+    assert_eq!(auth.session_count(), 1);
+
+    // 改密码后旧密码和旧会话都失效
+    auth.set_password("alice", "n3w").unwrap();
+    assert_eq!(auth.session_count(), 0);
+    assert!(auth.authenticate("alice", "s3cret!", 9_100).is_err());
+    auth.authenticate("alice", "n3w", 9_100).unwrap();
+}
+
+#[test]
+fn deactivated_users_cannot_sign_in() {
+    let mut auth = setup();
+    let session = auth.authenticate("alice", "s3cret!", 0).unwrap();
+
+    let mut user = alice(&auth).clone();
+    user.deactivate();
+    auth.users_mut().update(user).unwrap();
+    assert!(matches!(
+        auth.authenticate("alice", "s3cret!", 10),
+        Err(AuthError::Inactive(name)) if name == "alice"
+    ));
+    assert!(matches!(
+        auth.session_user(&session.token, 10),
+        Err(AuthError::InvalidSession)
+    ));
+    assert_eq!(alice(&auth).sign_in_count(), 1);
+
+    assert!(matches!(
+        auth.register(User::from_email("bob@example.org").unwrap(), ""),
+        Err(AuthError::EmptyPassword)
+    ));
+    assert!(matches!(
+        auth.register(User::from_email("alice@example.net").unwrap(), "x"),
+        Err(AuthError::User(_))
+    ));
+}
+
+#[test]
+fn recreated_users_do_not_inherit_the_old_password() {
+    let mut auth = setup();
+    auth.users_mut().delete("alice").unwrap();
+    assert!(auth.users().password_hash("alice").is_none());
+
+    // 绕过 Authenticator 直接在仓库里重建同名用户：没有设置密码，旧密码也不能用
+    let user = User::from_email("alice@example.net").unwrap();
+    auth.users_mut().create(user).unwrap();
+    assert!(matches!(
+        auth.authenticate("alice", "s3cret!", 0),
+        Err(AuthError::InvalidCredentials)
+    ));
+    auth.set_password("alice", "fresh").unwrap();
+    auth.authenticate("alice", "fresh", 0).unwrap();
+    assert!(matches!(
+        auth.authenticate("nobody", "s3cret!", 0),
+        Err(AuthError::InvalidCredentials)
+    ));
+}
+
+#[test]
+fn passwords_and_sign_ins_survive_reopening_the_file() {
+    let path = env::temp_dir().join(format!("rust-learning-auth-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+    {
+        let users = FileUserRepository::open(&path).unwrap();
+        let mut auth = Authenticator::with_config(users, CONFIG);
+        auth.register(User::from_email("alice@example.org").unwrap(), "pw")
+            .unwrap();
+        auth.authenticate("alice", "pw", 1_760_601_600).unwrap();
+    }
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(
+        text.contains(
+            "user alice alice@example.org active viewer 1 1760601600 pbkdf2-sha256$1000$"
+        ),
+        "{}",
+        text
+    );
+
+    // 重新打开文件后仍然可以用原来的密码登录
+    let users = FileUserRepository::open(&path).unwrap();
+    let mut auth = Authenticator::with_config(users, CONFIG);
+    assert!(auth.authenticate("alice", "wrong", 1_760_700_000).is_err());
+    auth.authenticate("alice", "pw", 1_760_700_000).unwrap();
+    let alice = auth.users().find_by_username("alice").unwrap();
+    assert_eq!(alice.sign_in_count(), 2);
+    assert_eq!(alice.last_login(), Some(1_760_700_000));
+    let _ = fs::remove_file(&path);
+}
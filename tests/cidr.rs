@@ -0,0 +1,277 @@
+use rust_learning::cidr::{Cidr, CidrError};
+use rust_learning::enums::IpAddr;
+use rust_learning::ip::IpError;
+
+fn cidr(text: &str) -> Cidr {
+    text.parse().unwrap()
+}
+
+fn ip(text: &str) -> IpAddr {
+    text.parse().unwrap()
+}
+
+fn cidrs(texts: &[&str]) -> Vec<Cidr> {
+    texts.iter().map(|text| cidr(text)).collect()
+}
+
+#[test]
+fn parse_and_describe_networks() {
+    // (网段, 掩码, 广播地址, 第一个主机, 最后一个主机, 可用主机数)
+    let cases = [
+        (
+            "10.0.0.0/8",
+            "255.0.0.0",
+            "10.255.255.255",
+            "10.0.0.1",
+            "10.255.255.254",
+            16_777_214,
+        ),
+        (
+            "192.168.1.0/24",
+            "255.255.255.0",
+            "192.168.1.255",
+            "192.168.1.1",
+            "192.168.1.254",
+            254,
+        ),
+        (
+            "172.16.0.0/12",
+            "255.240.0.0",
+            "172.31.255.255",
+            "172.16.0.1",
+            "172.31.255.254",
+            1_048_574,
+        ),
+        (
+            "10.0.0.4/30",
+            "255.255.255.252",
+            "10.0.0.7",
+            "10.0.0.5",
+            "10.0.0.6",
+            2,
+        ),
+        (
+            "10.0.0.4/31",
+            "255.255.255.254",
+            "10.0.0.5",
+            "10.0.0.4",
+            "10.0.0.5",
+            2,
+        ),
+        (
+            "10.0.0.4/32",
+            "255.255.255.255",
+            "10.0.0.4",
+            "10.0.0.4",
+            "10.0.0.4",
+            1,
+        ),
+        (
+            "0.0.0.0/0",
+            "0.0.0.0",
+            "255.255.255.255",
+            "0.0.0.1",
+            "255.255.255.254",
+            4_294_967_294,
+        ),
+        (
+            "fe80::/64",
+            "ffff:ffff:ffff:ffff::",
+            "fe80::ffff:ffff:ffff:ffff",
+            "fe80::",
+            "fe80::ffff:ffff:ffff:ffff",
+            1 << 64,
+        ),
+        (
+            "2001:db8::/127",
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe",
+            "2001:db8::1",
+            "2001:db8::",
+            "2001:db8::1",
+            2,
+        ),
+    ];
+    for (text, mask, broadcast, first, last, hosts) in cases {
+        let network = cidr(text);
+        assert_eq!(network.to_string(), text);
+        assert_eq!(network.netmask(), ip(mask), "{}", text);
+        assert_eq!(network.broadcast(), ip(broadcast), "{}", text);
+        assert_eq!(network.first_host(), ip(first), "{}", text);
+        assert_eq!(network.last_host(), ip(last), "{}", text);
+        assert_eq!(network.host_count(), hosts, "{}", text);
+    }
+    assert_eq!(cidr("::/0").size(), u128::MAX);
+    assert_eq!(cidr("::/1").size(), 1 << 127);
+}
+
+#[test]
+fn parse_errors() {
+    let cases = [
+        ("10.0.0.0", CidrError::MissingPrefix("10.0.0.0".to_string())),
+        ("10.0.0.0/", CidrError::InvalidPrefix("".to_string())),
+        ("10.0.0.0/x", CidrError::InvalidPrefix("x".to_string())),
+        ("10.0.0.0/-1", CidrError::InvalidPrefix("-1".to_string())),
+        (
+            "10.0.0.0/33",
+            CidrError::PrefixTooLong {
+                prefix: 33,
+                max: 32,
+            },
+        ),
+        (
+            "::/129",
+            CidrError::PrefixTooLong {
+                prefix: 129,
+                max: 128,
+            },
+        ),
+        (
+            "10.0.0.0/999",
+            CidrError::PrefixTooLong {
+                prefix: 999,
+                max: 32,
+            },
+        ),
+        ("10.0.0/8", CidrError::Address(IpError::OctetCount(3))),
+        ("10.1.2.3/8", CidrError::HostBitsSet(cidr("10.0.0.0/8"))),
+        ("fe80::1/64", CidrError::HostBitsSet(cidr("fe80::/64"))),
+    ];
+    for (text, error) in cases {
+        assert_eq!(text.parse::<Cidr>(), Err(error), "{:?}", text);
+    }
+    assert_eq!(
+        CidrError::HostBitsSet(cidr("10.0.0.0/8")).to_string(),
+        "主机位不为 0，网段应写成 10.0.0.0/8"
+    );
+    // new 会直接清掉主机位
+    assert_eq!(Cidr::new(ip("10.1.2.3"), 8), Ok(cidr("10.0.0.0/8")));
+    assert_eq!(
+        Cidr::new(ip("10.1.2.3"), 32),
+        Ok(Cidr::host(ip("10.1.2.3")))
+    );
+}
+
+#[test]
+fn containment() {
+    let network = cidr("10.0.0.0/8");
+    assert!(network.contains(ip("10.0.0.0")));
+    assert!(network.contains(ip("10.255.255.255")));
+    assert!(!network.contains(ip("11.0.0.0")));
+    assert!(!network.contains(ip("9.255.255.255")));
+    // 地址族不同
+    assert!(!cidr("::/0").contains(ip("1.2.3.4")));
+    assert!(cidr("0.0.0.0/0").contains(ip("1.2.3.4")));
+    assert!(cidr("fe80::/10").contains(ip("febf::1")));
+    assert!(!cidr("fe80::/10").contains(ip("fec0::1")));
+
+    assert!(network.covers(&cidr("10.20.0.0/16")));
+    assert!(network.covers(&network));
+    assert!(!cidr("10.20.0.0/16").covers(&network));
+    assert!(!network.covers(&cidr("11.0.0.0/16")));
+
+    assert_eq!(cidr("10.0.0.128/25").supernet(), Some(cidr("10.0.0.0/24")));
+    assert_eq!(cidr("0.0.0.0/0").supernet(), None);
+}
+
+#[test]
+fn splitting_into_subnets() {
+    let subnets: Vec<Cidr> = cidr("10.0.0.0/24").subnets(26).unwrap().collect();
+    assert_eq!(
+        subnets,
+        cidrs(&[
+            "10.0.0.0/26",
+            "10.0.0.64/26",
+            "10.0.0.128/26",
+            "10.0.0.192/26"
+        ])
+    );
+    assert_eq!(
+        cidr("10.0.0.0/24").subnets(24).unwrap().collect::<Vec<_>>(),
+        [cidr("10.0.0.0/24")]
+    );
+    assert_eq!(cidr("10.0.0.0/24").subnets(32).unwrap().count(), 256);
+    assert_eq!(
+        cidr("255.255.255.0/24").subnets(25).unwrap().last(),
+        Some(cidr("255.255.255.128/25"))
+    );
+    let v6: Vec<Cidr> = cidr("2001:db8::/32").subnets(34).unwrap().collect();
+    assert_eq!(
+        v6,
+        cidrs(&[
+            "2001:db8::/34",
+            "2001:db8:4000::/34",
+            "2001:db8:8000::/34",
+            "2001:db8:c000::/34"
+        ])
+    );
+    // 整个地址空间：第一个和最后一个子网
+    let mut all = cidr("::/0").subnets(1).unwrap();
+    assert_eq!(all.next(), Some(cidr("::/1")));
+    assert_eq!(all.next(), Some(cidr("8000::/1")));
+    assert_eq!(all.next(), None);
+    assert_eq!(cidr("::/0").subnets(0).unwrap().count(), 1);
+
+    assert_eq!(
+        cidr("10.0.0.0/24").subnets(23).unwrap_err(),
+        CidrError::InvalidSplit {
+            prefix: 24,
+            new_prefix: 23
+        }
+    );
+    assert!(cidr("10.0.0.0/24").subnets(33).is_err());
+}
+
+#[test]
+fn aggregation_merges_adjacent_and_overlapping_networks() {
+    let cases: [(&[&str], &[&str]); 7] = [
+        (&["10.0.0.0/25", "10.0.0.128/25"], &["10.0.0.0/24"]),
+        // 重叠、重复、被包含
+        (
+            &["10.0.0.0/24", "10.0.0.64/26", "10.0.0.0/24", "10.0.1.0/24"],
+            &["10.0.0.0/23"],
+        ),
+        // 相邻但不能合成一个网段
+        (
+            &["10.0.1.0/24", "10.0.2.0/24"],
+            &["10.0.1.0/24", "10.0.2.0/24"],
+        ),
+        (
+            &["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/30", "10.0.0.8/29"],
+            &["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/30", "10.0.0.8/29"],
+        ),
+        (
+            &["10.0.0.0/32", "10.0.0.1/32", "10.0.0.2/32", "10.0.0.3/32"],
+            &["10.0.0.0/30"],
+        ),
+        // IPv4 在前，地址族之间不合并
+        (
+            &["::1/128", "0.0.0.0/1", "::/128", "128.0.0.0/1"],
+            &["0.0.0.0/0", "::/127"],
+        ),
+        (
+            &["255.255.255.254/31", "255.255.255.255/32"],
+            &["255.255.255.254/31"],
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            Cidr::aggregate(cidrs(input)),
+            cidrs(expected),
+            "{:?}",
+            input
+        );
+    }
+    assert!(Cidr::aggregate(Vec::new()).is_empty());
+
+    // 单个地址聚合
+    let hosts = ["192.168.0.7", "192.168.0.4", "192.168.0.5", "192.168.0.6"];
+    let networks = hosts.iter().map(|text| Cidr::from(ip(text)));
+    assert_eq!(Cidr::aggregate(networks), [cidr("192.168.0.4/30")]);
+
+    // 切分后再聚合回到原网段
+    for text in ["10.0.0.0/22", "2001:db8::/48", "0.0.0.0/0"] {
+        let network = cidr(text);
+        let subnets = network.subnets(network.prefix() + 3).unwrap();
+        assert_eq!(Cidr::aggregate(subnets), [network]);
+    }
+}
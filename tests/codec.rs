@@ -0,0 +1,203 @@
+mod common;
+
+use rust_learning::codec::{self, CodecError, Decoder, MAX_TEXT_LEN};
+use rust_learning::enums::Message;
+use rust_learning::structs::Color;
+
+use common::Rng;
+
+fn random_message(rng: &mut Rng) -> Message {
+    const PIECES: [&str; 8] = ["", "a", "Hello Rust!", "你好", "🦀", "\0", "\n\t\"", "é"];
+    let edge = [i32::MIN, -1, 0, 1, i32::MAX];
+    match rng.below(4) {
+        0 => Message::Quit,
+        1 => {
+            let mut coordinate = || match rng.below(3) {
+                0 => edge[rng.below(5) as usize],
+                _ => rng.next() as i32,
+            };
+            Message::Move {
+                x: coordinate(),
+                y: coordinate(),
+            }
+        }
+        2 => {
+            let text: String = (0..rng.below(6))
+                .map(|_| PIECES[rng.below(8) as usize])
+                .collect();
+            Message::Write(text.repeat(rng.below(3) as usize * 40 + 1))
+        }
+        _ => Message::ChangeColor(Color(rng.next() as u8, rng.next() as u8, rng.next() as u8)),
+    }
+}
+
+#[test]
+fn wire_format_is_stable() {
+    let cases: [(Message, &[u8]); 5] = [
+        (Message::Quit, &[0x00]),
+        (
+            Message::Move { x: 10, y: 20 },
+            &[0x01, 0, 0, 0, 10, 0, 0, 0, 20],
+        ),
+        (
+            Message::Move { x: -2, y: i32::MIN },
+            &[0x01, 0xff, 0xff, 0xff, 0xfe, 0x80, 0, 0, 0],
+        ),
+        (
+            Message::Write("hé".to_string()),
+            &[0x02, 0, 0, 0, 3, b'h', 0xc3, 0xa9],
+        ),
+        (
+            Message::ChangeColor(Color(255, 136, 0)),
+            &[0x03, 0xff, 0x88, 0x00],
+        ),
+    ];
+    for (message, bytes) in cases {
+        assert_eq!(message.encode().unwrap(), bytes, "{:?}", message);
+        assert_eq!(Message::decode(bytes), Ok(message));
+    }
+}
+
+#[test]
+fn decoding_errors() {
+    assert_eq!(Message::decode(&[0x04]), Err(CodecError::UnknownTag(4)));
+    assert_eq!(
+        Message::decode(&[0xff, 0, 0]),
+        Err(CodecError::UnknownTag(0xff))
+    );
+    assert_eq!(
+        Message::decode(&[]),
+        Err(CodecError::Truncated {
+            needed: 1,
+            available: 0
+        })
+    );
+    assert_eq!(
+        Message::decode(&[0x01, 0, 0, 0, 10, 0, 0]),
+        Err(CodecError::Truncated {
+            needed: 9,
+            available: 7
+        })
+    );
+    assert_eq!(
+        Message::decode(&[0x02, 0, 0, 0, 5, b'h', b'i']),
+        Err(CodecError::Truncated {
+            needed: 10,
+            available: 7
+        })
+    );
+    let invalid = Message::decode(&[0x02, 0, 0, 0, 2, 0xc3, 0x28]);
+    assert!(
+        matches!(invalid, Err(CodecError::InvalidUtf8(_))),
+        "{:?}",
+        invalid
+    );
+    assert_eq!(
+        Message::decode(&[0x00, 0x00]),
+        Err(CodecError::TrailingBytes(1))
+    );
+
+    // 过长的文字在编码和解码两端都会被拒绝
+    let huge = Message::Write("x".repeat(MAX_TEXT_LEN + 1));
+    assert_eq!(
+        huge.encode(),
+        Err(CodecError::TextTooLong(MAX_TEXT_LEN + 1))
+    );
+    assert_eq!(
+        Message::decode(&[0x02, 0xff, 0xff, 0xff, 0xff]),
+        Err(CodecError::TextTooLong(u32::MAX as usize))
+    );
+    assert!(Message::Write("x".repeat(MAX_TEXT_LEN)).encode().is_ok());
+
+    let mut out = vec![0xaa];
+    assert!(huge.encode_into(&mut out).is_err());
+    assert_eq!(out, [0xaa]);
+}
+
+#[test]
+fn random_messages_round_trip() {
+    let mut rng = Rng(7);
+    for _ in 0..2_000 {
+        let message = random_message(&mut rng);
+        let bytes = message.encode().unwrap();
+        assert_eq!(Message::decode(&bytes), Ok(message.clone()));
+
+        // 任何截断都报告 Truncated，而不是解出别的消息
+        for len in 0..bytes.len() {
+            assert!(
+                matches!(
+                    Message::decode(&bytes[..len]),
+                    Err(CodecError::Truncated { available, .. }) if available == len
+                ),
+                "{:?} 截断到 {} 字节",
+                message,
+                len
+            );
+        }
+
+        // 后面跟着其他数据时只取走自己的字节
+        let mut extended = bytes.clone();
+        extended.extend_from_slice(&[0x00, 0x03]);
+        assert_eq!(codec::decode_prefix(&extended), Ok((message, bytes.len())));
+    }
+}
+
+#[test]
+fn streaming_decoder_handles_partial_reads() {
+    let mut rng = Rng(2024);
+    let messages: Vec<Message> = (0..500).map(|_| random_message(&mut rng)).collect();
+    let mut stream = Vec::new();
+    for message in &messages {
+        message.encode_into(&mut stream).unwrap();
+    }
+
+    // 按随机大小（包括 0 字节）分块送入，解出的消息序列与原来完全相同
+    for seed in 0..5 {
+        let mut rng = Rng(seed);
+        let mut decoder = Decoder::new();
+        let mut decoded = Vec::new();
+        let mut rest = stream.as_slice();
+        while !rest.is_empty() {
+            let size = (rng.below(20) as usize).min(rest.len());
+            let (chunk, tail) = rest.split_at(size);
+            decoder.feed(chunk);
+            rest = tail;
+            while let Some(message) = decoder.next_message().unwrap() {
+                decoded.push(message);
+            }
+        }
+        assert_eq!(decoded, messages);
+        assert_eq!(decoder.buffered(), 0);
+    }
+
+    // 一个字节一个字节地送入
+    let mut decoder = Decoder::new();
+    let mut decoded = Vec::new();
+    for byte in &stream {
+        decoder.feed(std::slice::from_ref(byte));
+        decoded.extend(decoder.next_message().unwrap());
+    }
+    assert_eq!(decoded, messages);
+}
+
+#[test]
+fn streaming_decoder_reports_corrupt_streams() {
+    let mut decoder = Decoder::new();
+    decoder.feed(&[0x00, 0x03, 1, 2]);
+    assert_eq!(decoder.next_message(), Ok(Some(Message::Quit)));
+    assert_eq!(decoder.next_message(), Ok(None));
+    assert_eq!(decoder.buffered(), 3);
+    decoder.feed(&[3, 0x09]);
+    assert_eq!(
+        decoder.next_message(),
+        Ok(Some(Message::ChangeColor(Color(1, 2, 3))))
+    );
+    assert_eq!(decoder.next_message(), Err(CodecError::UnknownTag(9)));
+
+    let mut decoder = Decoder::new();
+    decoder.feed(&[0x02, 0, 0, 0, 1, 0xff]);
+    assert!(matches!(
+        decoder.next_message(),
+        Err(CodecError::InvalidUtf8(_))
+    ));
+}
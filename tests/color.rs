@@ -0,0 +1,180 @@
+use rust_learning::color::{ColorError, Contrast, Hsl, Hsv};
+use rust_learning::enums::Message;
+use rust_learning::i18n::{self, Lang};
+use rust_learning::structs::Color;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 0.005
+}
+
+#[test]
+fn hex_round_trips() {
+    let cases = [
+        ("#ff8800", Color(255, 136, 0)),
+        ("FF8800", Color(255, 136, 0)),
+        ("#f80", Color(255, 136, 0)),
+        (" #000000 ", Color(0, 0, 0)),
+        ("#FfFfFf", Color(255, 255, 255)),
+        ("#123abc", Color(0x12, 0x3a, 0xbc)),
+    ];
+    for (text, color) in cases {
+        assert_eq!(Color::from_hex(text), Ok(color), "{:?}", text);
+        assert_eq!(Color::from_hex(&color.to_hex()), Ok(color));
+    }
+    assert_eq!(Color(255, 136, 0).to_string(), "#ff8800");
+
+    for bad in [
+        "", "#", "#ff88", "#ff880", "#ff88000", "#gg8800", "#+f+f+f", "ff 88 00",
+    ] {
+        assert_eq!(
+            Color::from_hex(bad),
+            Err(ColorError::InvalidHex(bad.to_string())),
+            "{:?}",
+            bad
+        );
+    }
+}
+
+#[test]
+fn channels_are_validated() {
+    assert_eq!(Color::try_from((255, 0, 128)), Ok(Color(255, 0, 128)));
+    assert_eq!(
+        Color::try_from((256, 0, 0)),
+        Err(ColorError::ChannelOutOfRange {
+            index: 0,
+            value: 256
+        })
+    );
+    // 错误文字随语言切换
+    let error = Color::try_from((0, 0, -1)).unwrap_err();
+    i18n::set_lang(Lang::En);
+    assert_eq!(error.to_string(), "b channel value -1 is outside 0..=255");
+    i18n::set_lang(Lang::Zh);
+    assert_eq!(error.to_string(), "b 通道的值 -1 超出 0..=255");
+}
+
+#[test]
+fn hsl_and_hsv_conversions() {
+    // (颜色, HSL, HSV)
+    let cases = [
+        (Color(0, 0, 0), (0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+        (Color(255, 255, 255), (0.0, 0.0, 1.0), (0.0, 0.0, 1.0)),
+        (Color(255, 0, 0), (0.0, 1.0, 0.5), (0.0, 1.0, 1.0)),
+        (Color(0, 255, 0), (120.0, 1.0, 0.5), (120.0, 1.0, 1.0)),
+        (Color(0, 0, 255), (240.0, 1.0, 0.5), (240.0, 1.0, 1.0)),
+        (Color(255, 0, 255), (300.0, 1.0, 0.5), (300.0, 1.0, 1.0)),
+        (Color(128, 128, 128), (0.0, 0.0, 0.502), (0.0, 0.0, 0.502)),
+        (Color(255, 136, 0), (32.0, 1.0, 0.5), (32.0, 1.0, 1.0)),
+        (Color(102, 51, 153), (270.0, 0.5, 0.4), (270.0, 0.667, 0.6)),
+    ];
+    for (color, (h, s, l), (hv, sv, v)) in cases {
+        let hsl = color.to_hsl();
+        assert!(
+            close(hsl.h, h) && close(hsl.s, s) && close(hsl.l, l),
+            "{:?}: {:?}",
+            color,
+            hsl
+        );
+        let hsv = color.to_hsv();
+        assert!(
+            close(hsv.h, hv) && close(hsv.s, sv) && close(hsv.v, v),
+            "{:?}: {:?}",
+            color,
+            hsv
+        );
+        assert_eq!(Color::from_hsl(hsl), color);
+        assert_eq!(Color::from_hsv(hsv), color);
+    }
+
+    // 任意颜色往返都不丢精度
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(17) {
+            for b in (0..=255).step_by(51) {
+                let color = Color(r, g, b);
+                assert_eq!(Color::from_hsl(color.to_hsl()), color);
+                assert_eq!(Color::from_hsv(color.to_hsv()), color);
+            }
+        }
+    }
+
+    // 色相取模，分量越界时截断
+    let wrapped = Hsl {
+        h: 480.0,
+        s: 2.0,
+        l: 0.5,
+    };
+    assert_eq!(Color::from_hsl(wrapped), Color(0, 255, 0));
+    let negative = Hsv {
+        h: -120.0,
+        s: 1.0,
+        v: 1.0,
+    };
+    assert_eq!(Color::from_hsv(negative), Color(0, 0, 255));
+}
+
+#[test]
+fn blending_mixes_by_alpha() {
+    let red = Color(255, 0, 0);
+    let blue = Color(0, 0, 255);
+    assert_eq!(red.blend(blue, 1.0), red);
+    assert_eq!(red.blend(blue, 0.0), blue);
+    assert_eq!(red.blend(blue, 0.5), Color(128, 0, 128));
+    assert_eq!(Color::WHITE.blend(Color::BLACK, 0.25), Color(64, 64, 64));
+    assert_eq!(red.blend(blue, 7.0), red);
+}
+
+#[test]
+fn wcag_contrast() {
+    assert!(close(Color::BLACK.relative_luminance(), 0.0));
+    assert!(close(Color::WHITE.relative_luminance(), 1.0));
+    assert!(close(Color::BLACK.contrast_ratio(Color::WHITE), 21.0));
+    assert!(close(Color::WHITE.contrast_ratio(Color::WHITE), 1.0));
+
+    // (前景, 背景, 对比度, 等级)，数值与 WebAIM 对比度检查器一致
+    let cases = [
+        (
+            Color(0x77, 0x77, 0x77),
+            Color::WHITE,
+            4.48,
+            Contrast::AaLarge,
+        ),
+        (Color(0x76, 0x76, 0x76), Color::WHITE, 4.54, Contrast::Aa),
+        (Color(0x59, 0x59, 0x59), Color::WHITE, 7.0, Contrast::Aaa),
+        (Color(0xff, 0x88, 0x00), Color::WHITE, 2.39, Contrast::Fail),
+        (Color(0x00, 0x00, 0xff), Color::WHITE, 8.59, Contrast::Aaa),
+    ];
+    for (fg, bg, ratio, level) in cases {
+        let actual = fg.contrast_ratio(bg);
+        assert!((actual - ratio).abs() < 0.01, "{}: {}", fg, actual);
+        assert_eq!(bg.contrast_ratio(fg), actual);
+        assert_eq!(fg.contrast(bg), level, "{}", fg);
+    }
+}
+
+#[test]
+fn named_colors() {
+    assert_eq!(Color::named("Orange"), Some(Color(255, 165, 0)));
+    assert_eq!(Color::named("grey"), Color::named("gray"));
+    assert_eq!(Color::named("chartreuse-ish"), None);
+    assert_eq!(Color(0, 255, 255).name(), Some("aqua"));
+    assert_eq!(Color(1, 2, 3).name(), None);
+
+    assert_eq!("navy".parse(), Ok(Color(0, 0, 128)));
+    assert_eq!("#f80".parse(), Ok(Color(255, 136, 0)));
+    assert_eq!("#abc".parse(), Ok(Color(0xaa, 0xbb, 0xcc)));
+    for name in ["nope", "bad", "fed", "add", "cafe00"] {
+        assert_eq!(
+            name.parse::<Color>(),
+            Err(ColorError::UnknownName(name.to_string()))
+        );
+    }
+}
+
+#[test]
+fn messages_carry_colors() {
+    let message = Message::ChangeColor("teal".parse().unwrap());
+    match message {
+        Message::ChangeColor(Color(r, g, b)) => assert_eq!((r, g, b), (0, 128, 128)),
+        other => panic!("{:?}", other),
+    }
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use rust_learning::command::{self, ErrorKind, Expected, ParseError};
+use rust_learning::enums::Message;
+use rust_learning::i18n::{self, Lang};
+use rust_learning::structs::Color;
+
+use common::Rng;
+
+fn expected(column: usize, expected: Expected, found: Option<&str>) -> ParseError {
+    ParseError {
+        column,
+        kind: ErrorKind::Expected {
+            expected,
+            found: found.map(str::to_string),
+        },
+    }
+}
+
+#[test]
+fn parses_every_command() {
+    let cases = [
+        ("quit", Message::Quit),
+        ("move 10 20", Message::Move { x: 10, y: 20 }),
+        (
+            "  move\t-2147483648   +7  ",
+            Message::Move { x: i32::MIN, y: 7 },
+        ),
+        (
+            r#"write "Hello Rust!""#,
+            Message::Write("Hello Rust!".to_string()),
+        ),
+        ("write 你好", Message::Write("你好".to_string())),
+        (r#"write """#, Message::Write(String::new())),
+        ("color 255 0 0", Message::ChangeColor(Color(255, 0, 0))),
+    ];
+    for (line, message) in cases {
+        assert_eq!(command::parse(line), Ok(message.clone()), "{}", line);
+        assert_eq!(line.parse::<Message>(), Ok(message), "{}", line);
+    }
+}
+
+#[test]
+fn string_escapes() {
+    let cases = [
+        (r#""a\"b""#, "a\"b"),
+        (r#""back\\slash""#, "back\\slash"),
+        (r#""line\nbreak\r\ttab""#, "line\nbreak\r\ttab"),
+        (r#""nul\0""#, "nul\0"),
+        (r#""crab \u{1F980} \u{4f60}""#, "crab 🦀 你"),
+        (r#""  spaces  ""#, "  spaces  "),
+    ];
+    for (literal, text) in cases {
+        let line = format!("write {}", literal);
+        assert_eq!(
+            command::parse(&line),
+            Ok(Message::Write(text.to_string())),
+            "{}",
+            line
+        );
+    }
+}
+
+#[test]
+fn errors_report_column_and_expected_token() {
+    let cases = [
+        ("", expected(1, Expected::Command, None)),
+        ("   ", expected(4, Expected::Command, None)),
+        (
+            r#""move" 1 2"#,
+            expected(1, Expected::Command, Some(r#""move""#)),
+        ),
+        (
+            "  jump 1",
+            ParseError {
+                column: 3,
+                kind: ErrorKind::UnknownCommand("jump".to_string()),
+            },
+        ),
+        ("move 10 x", expected(9, Expected::Integer, Some("x"))),
+        ("move 10", expected(8, Expected::Integer, None)),
+        (
+            "move 2147483648 0",
+            expected(6, Expected::Integer, Some("2147483648")),
+        ),
+        (
+            r#"move "1" 2"#,
+            expected(6, Expected::Integer, Some(r#""1""#)),
+        ),
+        ("color 1 2 256", expected(11, Expected::Byte, Some("256"))),
+        ("color 1 -2 3", expected(9, Expected::Byte, Some("-2"))),
+        ("write", expected(6, Expected::Text, None)),
+        (
+            "write hello world",
+            expected(13, Expected::End, Some("world")),
+        ),
+        ("quit now", expected(6, Expected::End, Some("now"))),
+        // 列号按字符计，中文也算一列
+        ("write 你好 世界", expected(10, Expected::End, Some("世界"))),
+        (
+            r#"write "abc"#,
+            ParseError {
+                column: 7,
+                kind: ErrorKind::UnterminatedString,
+            },
+        ),
+        (
+            r#"write "a\qb""#,
+            ParseError {
+                column: 9,
+                kind: ErrorKind::InvalidEscape(r"\q".to_string()),
+            },
+        ),
+        (
+            r#"write "\u{110000}""#,
+            ParseError {
+                column: 8,
+                kind: ErrorKind::InvalidEscape(r"\u{110000}".to_string()),
+            },
+        ),
+    ];
+    for (line, error) in cases {
+        assert_eq!(command::parse(line), Err(error), "{:?}", line);
+    }
+}
+
+#[test]
+fn error_messages() {
+    let cases = [
+        ("move 10 x", "第 9 列: 期望整数（i32），实际是 `x`"),
+        ("color 1 2", "第 10 列: 期望整数（0 到 255），但已到行尾"),
+        (
+            "jump",
+            "第 1 列: 未知的命令 `jump`，可用的命令: quit、move、write、color",
+        ),
+        (r#"write "oops"#, "第 7 列: 字符串缺少结尾的双引号"),
+    ];
+    i18n::set_lang(Lang::Zh);
+    for (line, text) in cases {
+        assert_eq!(command::parse(line).unwrap_err().to_string(), text);
+    }
+
+    let cases = [
+        (
+            "move 10 x",
+            "column 9: expected an integer (i32), found `x`",
+        ),
+        (
+            "jump",
+            "column 1: unknown command `jump`, available commands: quit, move, write, color",
+        ),
+    ];
+    i18n::set_lang(Lang::En);
+    for (line, text) in cases {
+        assert_eq!(command::parse(line).unwrap_err().to_string(), text);
+    }
+    i18n::set_lang(Lang::Zh);
+}
+
+#[test]
+fn to_command_round_trips() {
+    const PIECES: [&str; 10] = [
+        "",
+        "a",
+        "Hello Rust!",
+        "你好",
+        "🦀",
+        "\0",
+        "\n\t\"",
+        "\\",
+        "\u{7f}",
+        " ",
+    ];
+    let mut rng = Rng(7);
+    for _ in 0..1000 {
+        let message = match rng.below(4) {
+            0 => Message::Quit,
+            1 => Message::Move {
+                x: rng.next() as i32,
+                y: rng.next() as i32,
+            },
+            2 => {
+                let count = rng.below(5);
+                let text = (0..count)
+                    .map(|_| PIECES[rng.below(PIECES.len() as u64) as usize])
+                    .collect();
+                Message::Write(text)
+            }
+            _ => {
+                let [r, g, b, ..] = rng.next().to_le_bytes();
+                Message::ChangeColor(Color(r, g, b))
+            }
+        };
+        let line = message.to_command();
+        assert_eq!(command::parse(&line), Ok(message), "{}", line);
+    }
+}
@@ -0,0 +1,18 @@
+//! 几个集成测试共用的辅助代码，用 `mod common;` 引入
+
+/// 可复现的伪随机数（线性同余），用来生成大量测试数据
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 16
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
@@ -0,0 +1,102 @@
+use std::process::Command;
+
+use rust_learning::compare::{self, Comparison};
+use rust_learning::i18n::{self, Lang};
+
+#[test]
+fn parses_inline_and_bulleted_notes() {
+    let source = "\
+fn main() {
+    // 🆚 对比 Java：Java 没有遮蔽
+    // 🆚 对比 Python：所有变量默认可变
+}
+
+// 🆚 对比 C++：
+// - 引用可能悬垂
+// - 用 RAII 管理资源
+// 这一行不是列表项
+// - 所以这一行也不算
+";
+    let parsed = compare::parse(3, source);
+    let points: Vec<(&str, &str, usize)> = parsed
+        .iter()
+        .map(|c| (c.language.as_str(), c.point.as_str(), c.line))
+        .collect();
+    assert_eq!(
+        points,
+        [
+            ("Java", "Java 没有遮蔽", 2),
+            ("Python", "所有变量默认可变", 3),
+            ("C++", "引用可能悬垂", 7),
+            ("C++", "用 RAII 管理资源", 8),
+        ]
+    );
+}
+
+#[test]
+fn language_names_ignore_case_and_aliases() {
+    assert!(compare::same_language("Java", "java"));
+    assert!(compare::same_language("C++", "cpp"));
+    assert!(compare::same_language("Python", "py"));
+    assert!(!compare::same_language("Java", "JavaScript"));
+}
+
+#[test]
+fn lessons_provide_java_cpp_and_python_notes() {
+    let comparisons = compare::all().unwrap();
+    let lessons_for = |language: &str| {
+        let mut lessons: Vec<u8> = compare::for_language(&comparisons, language)
+            .iter()
+            .map(|c| c.lesson)
+            .collect();
+        lessons.dedup();
+        lessons
+    };
+    assert_eq!(lessons_for("java"), [1, 2, 3, 5, 10]);
+    assert_eq!(lessons_for("c++"), [12]);
+    assert_eq!(lessons_for("python"), [1]);
+    assert!(comparisons.iter().all(|c| !c.point.is_empty()));
+}
+
+#[test]
+fn cheat_sheet_groups_points_by_lesson() {
+    i18n::set_lang(Lang::Zh);
+    let comparisons = vec![
+        Comparison {
+            lesson: 5,
+            language: "Java".to_string(),
+            point: "有 GC".to_string(),
+            line: 43,
+        },
+        Comparison {
+            lesson: 5,
+            language: "Java".to_string(),
+            point: "引用检查".to_string(),
+            line: 44,
+        },
+    ];
+    assert_eq!(
+        compare::cheat_sheet(&comparisons, "JAVA").unwrap(),
+        "🆚 对比 Java（1 节课，2 条）\n\n05. 所有权（src/ownership.rs:43）\n  - 有 GC\n  - 引用检查\n"
+    );
+    assert_eq!(compare::cheat_sheet(&comparisons, "go"), None);
+}
+
+#[test]
+fn learn_compare_prints_the_cheat_sheet() {
+    let output = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["compare", "cpp"])
+        .env_remove("LEARN_LANG")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("🆚 对比 C++（1 节课，2 条）"));
+    assert!(stdout.contains("12. 生命周期（src/lifetimes.rs:"));
+
+    let unknown = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["compare", "cobol"])
+        .output()
+        .unwrap();
+    assert!(!unknown.status.success());
+}
@@ -0,0 +1,129 @@
+//! 编译失败测试：验证课程注释里标着「❌ 编译错误」的代码确实无法通过编译。
+//!
+//! `tests/ui/` 下每个文件都是一段从课程中取出的代码，文件开头用
+//! `//@ error: E0xxx` 声明期望的错误码。测试用 rustc 编译每个文件，
+//! 要求编译失败，且报出的错误码与声明的完全一致。
+//!
+//! 用到课程类型（如 `Task<S>`、`User`）的用例通过 `use rust_learning::...` 引用库里真正的类型，
+//! 而不是自己抄一份：库的行为一旦变化（比如给 `Task<Completed>` 加上 `start`），用例就会失败。
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const DIRECTIVE: &str = "//@ error:";
+
+fn ui_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ui")
+}
+
+/// 读取文件头部声明的期望错误码
+fn expected_codes(source: &str) -> BTreeSet<String> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix(DIRECTIVE))
+        .flat_map(|codes| codes.split(','))
+        .map(|code| code.trim().to_string())
+        .filter(|code| !code.is_empty())
+        .collect()
+}
+
+/// 从 rustc 的诊断输出中提取 `error[E0xxx]` 错误码
+fn reported_codes(stderr: &str) -> BTreeSet<String> {
+    stderr
+        .match_indices("error[E")
+        .filter_map(|(start, _)| {
+            let rest = &stderr[start + "error[".len()..];
+            rest.find(']').map(|end| rest[..end].to_string())
+        })
+        .collect()
+}
+
+/// 本次测试所链接的 `rust_learning` 库
+///
+/// 集成测试的可执行文件和库的 rlib 都在 `target/<profile>/deps` 下；cargo 运行测试前
+/// 已经构建好库，同名的 rlib 有多个（旧的构建留下的）时取最新的那个。
+fn library() -> PathBuf {
+    let exe = env::current_exe().expect("无法获取测试程序路径");
+    let deps = exe.parent().expect("测试程序不在 deps 目录下");
+    fs::read_dir(deps)
+        .expect("无法读取 deps 目录")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("librust_learning-") && name.ends_with(".rlib")
+        })
+        .max_by_key(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .unwrap_or_else(|| panic!("{} 下找不到 librust_learning 的 rlib", deps.display()))
+}
+
+fn compile(path: &Path, library: &Path, out_dir: &Path) -> (bool, String) {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let deps = library.parent().unwrap();
+    let output = Command::new(rustc)
+        .args([
+            "--edition",
+            "2024",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .arg("--extern")
+        .arg(format!("rust_learning={}", library.display()))
+        .arg("-L")
+        .arg(format!("dependency={}", deps.display()))
+        .arg("--out-dir")
+        .arg(out_dir)
+        .arg(path)
+        .output()
+        .unwrap_or_else(|e| panic!("无法运行 rustc: {}", e));
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn ui_cases_fail_with_expected_error_codes() {
+    let out_dir = env::temp_dir().join(format!("rust-learning-ui-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).unwrap();
+    let library = library();
+
+    let mut cases: Vec<PathBuf> = fs::read_dir(ui_dir())
+        .expect("缺少 tests/ui 目录")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    cases.sort();
+    assert!(!cases.is_empty(), "tests/ui 下没有任何用例");
+
+    let mut failures = Vec::new();
+    for case in &cases {
+        let name = case.file_name().unwrap().to_string_lossy();
+        let source = fs::read_to_string(case).unwrap();
+        let expected = expected_codes(&source);
+        if expected.is_empty() {
+            failures.push(format!("{}: 缺少 `{} E0xxx` 声明", name, DIRECTIVE));
+            continue;
+        }
+
+        let (compiled, stderr) = compile(case, &library, &out_dir);
+        if compiled {
+            failures.push(format!("{}: 期望编译失败，但编译通过了", name));
+            continue;
+        }
+        let reported = reported_codes(&stderr);
+        if reported != expected {
+            failures.push(format!(
+                "{}: 期望错误码 {:?}，实际 {:?}\n{}",
+                name, expected, reported, stderr
+            ));
+        }
+    }
+
+    let _ = fs::remove_dir_all(&out_dir);
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
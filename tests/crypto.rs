@@ -0,0 +1,109 @@
+use rust_learning::crypto::{
+    Sha256, constant_time_eq, from_hex, hmac_sha256, pbkdf2_sha256, sha256, to_hex,
+};
+
+#[test]
+fn sha256_matches_fips_vectors() {
+    let million_a = "a".repeat(1_000_000);
+    let cases = [
+        (
+            "",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            "abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+        (
+            &million_a,
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0",
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(to_hex(&sha256(input.as_bytes())), expected);
+    }
+}
+
+#[test]
+fn incremental_updates_match_one_shot() {
+    let data: Vec<u8> = (0..=255u8).cycle().take(1_000).collect();
+    let expected = sha256(&data);
+    for split in [0, 1, 55, 56, 63, 64, 65, 127, 128, 999, 1_000] {
+        let mut hasher = Sha256::new();
+        hasher.update(&data[..split]);
+        hasher.update(&data[split..]);
+        assert_eq!(hasher.finalize(), expected, "split at {}", split);
+    }
+}
+
+#[test]
+fn hmac_matches_rfc4231() {
+    let cases: [(Vec<u8>, &[u8], &str); 3] = [
+        (
+            vec![0x0b; 20],
+            b"Hi There",
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        ),
+        (
+            b"Jefe".to_vec(),
+            b"what do ya want for nothing?",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+        // 密钥比分组长，会先做一次哈希
+        (
+            vec![0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+        ),
+    ];
+    for (key, data, expected) in cases {
+        assert_eq!(to_hex(&hmac_sha256(&key, data)), expected);
+    }
+}
+
+#[test]
+fn pbkdf2_matches_known_vectors() {
+    let cases = [
+        (
+            1,
+            32,
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b",
+        ),
+        (
+            2,
+            32,
+            "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43",
+        ),
+        (
+            4096,
+            32,
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a",
+        ),
+        // 输出比一个摘要长时由多个分组拼接，最后一块截断
+        (
+            1,
+            40,
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b4dbf3a2f3dad3377",
+        ),
+    ];
+    for (iterations, len, expected) in cases {
+        let mut out = vec![0; len];
+        pbkdf2_sha256(b"password", b"salt", iterations, &mut out);
+        assert_eq!(to_hex(&out), expected, "c = {}", iterations);
+    }
+}
+
+#[test]
+fn hex_and_comparison_helpers() {
+    assert_eq!(from_hex("00ff7A"), Some(vec![0x00, 0xff, 0x7a]));
+    assert_eq!(from_hex("abc"), None);
+    assert_eq!(from_hex("+1"), None);
+    assert_eq!(from_hex("zz"), None);
+    assert!(constant_time_eq(b"same", b"same"));
+    assert!(!constant_time_eq(b"same", b"sane"));
+    assert!(!constant_time_eq(b"same", b"same!"));
+}
@@ -0,0 +1,158 @@
+use rust_learning::email::{Email, EmailError};
+use rust_learning::structs::User;
+
+#[test]
+fn valid_addresses_are_normalized() {
+    // (输入, 规范化结果, 本地部分, 域名, 标签)
+    let cases = [
+        (
+            "alice@example.org",
+            "alice@example.org",
+            "alice",
+            "example.org",
+            None,
+        ),
+        (
+            "  Bob.Smith@Mail.Example.COM ",
+            "Bob.Smith@mail.example.com",
+            "Bob.Smith",
+            "mail.example.com",
+            None,
+        ),
+        (
+            "alice+news@example.org",
+            "alice+news@example.org",
+            "alice+news",
+            "example.org",
+            Some("news"),
+        ),
+        (
+            "a+b+c@x-y.io",
+            "a+b+c@x-y.io",
+            "a+b+c",
+            "x-y.io",
+            Some("b+c"),
+        ),
+        (
+            "alice+@example.org",
+            "alice+@example.org",
+            "alice+",
+            "example.org",
+            Some(""),
+        ),
+        (
+            "+only@example.org",
+            "+only@example.org",
+            "+only",
+            "example.org",
+            None,
+        ),
+        (
+            "o'brien_99@xn--80ak6aa92e.com",
+            "o'brien_99@xn--80ak6aa92e.com",
+            "o'brien_99",
+            "xn--80ak6aa92e.com",
+            None,
+        ),
+        ("x@123.example", "x@123.example", "x", "123.example", None),
+    ];
+    for (input, normalized, local, domain, tag) in cases {
+        let email = Email::parse(input).unwrap_or_else(|e| panic!("{:?}: {}", input, e));
+        assert_eq!(email.as_str(), normalized, "{:?}", input);
+        assert_eq!(email.local(), local, "{:?}", input);
+        assert_eq!(email.domain(), domain, "{:?}", input);
+        assert_eq!(email.tag(), tag, "{:?}", input);
+        assert_eq!(email.to_string().parse::<Email>(), Ok(email.clone()));
+    }
+}
+
+#[test]
+fn invalid_addresses_are_rejected() {
+    let long_local = format!("{}@example.org", "a".repeat(65));
+    let long_label = format!("a@{}.com", "b".repeat(64));
+    let long_address = format!("a@{}.com", vec!["c".repeat(60); 5].join("."));
+    let cases = [
+        ("", EmailError::Empty),
+        ("   ", EmailError::Empty),
+        ("alice", EmailError::MissingAt),
+        ("a@b@c", EmailError::MultipleAt),
+        ("@example.org", EmailError::EmptyLocal),
+        ("alice@", EmailError::EmptyDomain),
+        ("al ice@example.org", EmailError::InvalidLocalChar(' ')),
+        ("al\"ice@example.org", EmailError::InvalidLocalChar('"')),
+        ("ålice@example.org", EmailError::InvalidLocalChar('å')),
+        (".alice@example.org", EmailError::MisplacedDot),
+        ("alice.@example.org", EmailError::MisplacedDot),
+        ("al..ice@example.org", EmailError::MisplacedDot),
+        ("alice@localhost", EmailError::MissingTld),
+        (
+            "alice@example..org",
+            EmailError::InvalidLabel(String::new()),
+        ),
+        (
+            "alice@example.org.",
+            EmailError::InvalidLabel(String::new()),
+        ),
+        (
+            "alice@-example.org",
+            EmailError::InvalidLabel("-example".into()),
+        ),
+        (
+            "alice@example-.org",
+            EmailError::InvalidLabel("example-".into()),
+        ),
+        (
+            "alice@exa_mple.org",
+            EmailError::InvalidLabel("exa_mple".into()),
+        ),
+        ("alice@10.0.0.1", EmailError::NumericTld("1".into())),
+        (&long_local, EmailError::LocalTooLong(65)),
+        (&long_label, EmailError::InvalidLabel("b".repeat(64))),
+        (&long_address, EmailError::TooLong(long_address.len())),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(Email::parse(input), Err(expected), "{:?}", input);
+    }
+}
+
+#[test]
+fn tags_can_be_stripped() {
+    let cases = [
+        ("alice+news@Example.org", "alice@example.org"),
+        ("alice@example.org", "alice@example.org"),
+        ("a+b+c@example.org", "a@example.org"),
+        ("+only@example.org", "+only@example.org"),
+    ];
+    for (input, expected) in cases {
+        let stripped = Email::parse(input).unwrap().without_tag();
+        assert_eq!(stripped.as_str(), expected, "{:?}", input);
+        assert_eq!(stripped.tag(), None);
+        assert_eq!(stripped.domain(), "example.org");
+    }
+}
+
+#[test]
+fn users_hold_validated_emails() {
+    let user = User::from_email("Alice+Rust@Example.ORG").unwrap();
+    assert_eq!(user.username(), "Alice");
+    assert_eq!(user.email(), "Alice+Rust@example.org");
+    assert_eq!(user.email_domain(), "example.org");
+    assert_eq!(user.email_address().tag(), Some("Rust"));
+    assert_eq!(
+        format!("{:?}", user),
+        "User { active: true, username: \"Alice\", email: \"Alice+Rust@example.org\", sign_in_count: 0, last_login: None, role: Viewer }"
+    );
+
+    for (input, expected) in [
+        ("a@b@c", EmailError::MultipleAt),
+        ("@x", EmailError::EmptyLocal),
+        ("", EmailError::Empty),
+    ] {
+        assert_eq!(
+            User::from_email(input).unwrap_err(),
+            expected,
+            "{:?}",
+            input
+        );
+    }
+}
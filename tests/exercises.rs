@@ -0,0 +1,76 @@
+//! 练习自检：每道练习在提交时都必须是坏的，而参考答案必须全部通过
+
+use std::thread;
+
+use rust_learning::exercises::{self, EXERCISES, Outcome};
+use rust_learning::lessons;
+
+/// 并行检查，rustc 编译每个文件都要零点几秒
+fn check_all(pick: fn(&exercises::Exercise) -> std::path::PathBuf) -> Vec<(&'static str, Outcome)> {
+    thread::scope(|scope| {
+        let handles: Vec<_> = EXERCISES
+            .iter()
+            .map(|exercise| {
+                scope.spawn(move || {
+                    let outcome = exercises::check_file(&pick(exercise)).unwrap();
+                    (exercise.name, outcome)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+#[test]
+fn shipped_exercises_are_unsolved() {
+    let solved: Vec<_> = check_all(|e| e.file())
+        .into_iter()
+        .filter(|(_, outcome)| outcome.is_passed())
+        .map(|(name, _)| name)
+        .collect();
+    assert!(
+        solved.is_empty(),
+        "这些练习不需要修改就能通过: {:?}",
+        solved
+    );
+}
+
+#[test]
+fn solutions_pass() {
+    let failures: Vec<_> = check_all(|e| e.solution())
+        .into_iter()
+        .filter(|(_, outcome)| !outcome.is_passed())
+        .map(|(name, outcome)| format!("{}: {}\n{}", name, outcome, outcome.output()))
+        .collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_lesson_has_several_exercises() {
+    for lesson in lessons::LESSONS {
+        let count = EXERCISES
+            .iter()
+            .filter(|e| e.lesson == lesson.number)
+            .count();
+        assert!(count >= 2, "{} 只有 {} 道练习", lesson.name, count);
+    }
+}
+
+#[test]
+fn names_are_unique_and_files_exist() {
+    for (i, exercise) in EXERCISES.iter().enumerate() {
+        assert!(exercise.file().is_file(), "缺少 {:?}", exercise.file());
+        assert!(
+            exercise.solution().is_file(),
+            "缺少 {:?}",
+            exercise.solution()
+        );
+        assert!(
+            EXERCISES[..i]
+                .iter()
+                .all(|other| other.name != exercise.name),
+            "重复的练习名 {}",
+            exercise.name
+        );
+    }
+}
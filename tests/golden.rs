@@ -0,0 +1,132 @@
+//! 黄金输出（golden output）回归测试：
+//! 运行每个课程二进制，把 stdout 与 `tests/snapshots/<课程>.stdout` 逐字比较。
+//!
+//! 课程默认以中文运行；打印了可翻译文字的课程另有一份英文快照
+//! `tests/snapshots/en/<课程>.stdout`，运行时设置 `LEARN_LANG=en`。
+//!
+//! 有意修改输出后，用下面的命令重新生成快照并连同代码一起提交：
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test golden
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn snapshot_path(lesson: &str, lang: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let dir = if lang == "zh" { dir } else { dir.join(lang) };
+    dir.join(format!("{}.stdout", lesson))
+}
+
+fn update_mode() -> bool {
+    env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value != "0")
+}
+
+/// 运行课程二进制并返回它的 stdout
+fn capture(lesson: &str, exe: &str, lang: &str) -> String {
+    let output = Command::new(exe)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("LEARN_LANG", lang)
+        .output()
+        .unwrap_or_else(|e| panic!("无法运行 {}: {}", lesson, e));
+    assert!(
+        output.status.success(),
+        "{} 退出状态异常: {}\nstderr:\n{}",
+        lesson,
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("课程输出应为 UTF-8")
+}
+
+/// 逐行对比，标出第一处不同及其上下文
+fn describe_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let first = expected
+        .iter()
+        .zip(&actual)
+        .position(|(e, a)| e != a)
+        .unwrap_or(expected.len().min(actual.len()));
+
+    let mut report = format!(
+        "第 {} 行开始不同（快照 {} 行，实际 {} 行）\n",
+        first + 1,
+        expected.len(),
+        actual.len()
+    );
+    let start = first.saturating_sub(2);
+    for line in &expected[start.min(expected.len())..(first + 3).min(expected.len())] {
+        report.push_str(&format!("- {}\n", line));
+    }
+    for line in &actual[start.min(actual.len())..(first + 3).min(actual.len())] {
+        report.push_str(&format!("+ {}\n", line));
+    }
+    report
+}
+
+fn check(lesson: &str, exe: &str, lang: &str) {
+    let actual = capture(lesson, exe, lang);
+    let path = snapshot_path(lesson, lang);
+
+    if update_mode() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(&path, &actual).unwrap_or_else(|e| panic!("无法写入 {:?}: {}", path, e));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "缺少快照 {:?}，运行 `UPDATE_SNAPSHOTS=1 cargo test --test golden` 生成",
+            path
+        )
+    });
+    if expected != actual {
+        panic!(
+            "{} 的输出与快照不一致：\n{}\n确认是有意修改后，运行 `UPDATE_SNAPSHOTS=1 cargo test --test golden` 更新快照",
+            lesson,
+            describe_diff(&expected, &actual)
+        );
+    }
+}
+
+macro_rules! golden {
+    ($lang:literal: $($test:ident => $lesson:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $test() {
+                check($lesson, env!(concat!("CARGO_BIN_EXE_", $lesson)), $lang);
+            }
+        )*
+    };
+}
+
+golden! {
+    "zh":
+    variables => "01_variables",
+    data_types => "02_data_types",
+    functions => "03_functions",
+    control_flow => "04_control_flow",
+    ownership => "05_ownership",
+    structs => "06_structs",
+    enums => "07_enums",
+    collections => "08_collections",
+    modules => "09_modules",
+    error_handling => "10_error_handling",
+    generics_traits => "11_generics_traits",
+    lifetimes => "12_lifetimes",
+    macros => "13_macro",
+    traits => "14_trait",
+}
+
+golden! {
+    "en":
+    structs_en => "06_structs",
+    enums_en => "07_enums",
+    traits_en => "14_trait",
+}
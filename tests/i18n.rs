@@ -0,0 +1,178 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rust_learning::i18n::{self, Lang};
+use rust_learning::lessons::{LESSONS, Section};
+
+fn keys(lang: Lang) -> Vec<String> {
+    i18n::parse_catalog(lang.catalog_source())
+        .unwrap_or_else(|e| panic!("locales/{}.txt {}", lang.code(), e))
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect()
+}
+
+/// 递归收集 `dir` 下的全部 `.rs` 文件
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// 源码中以字面量写出的键：`t("...")` 和 `tf("...", ...)`
+fn literal_keys(source: &str) -> Vec<String> {
+    ["t(\"", "tf(\""]
+        .iter()
+        .flat_map(|call| {
+            source
+                .match_indices(call)
+                .filter(|(start, _)| {
+                    // 排除 expect(" 这类以 t 结尾的其他调用
+                    let before = source[..*start].chars().next_back();
+                    !before.is_some_and(|c| c.is_alphanumeric() || c == '_')
+                })
+                .map(|(start, _)| start + call.len())
+        })
+        .filter_map(|start| {
+            let rest = &source[start..];
+            rest.find('"').map(|end| rest[..end].to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn every_key_exists_in_both_catalogs() {
+    let zh: BTreeSet<String> = keys(Lang::Zh).into_iter().collect();
+    let en: BTreeSet<String> = keys(Lang::En).into_iter().collect();
+    let only_zh: Vec<_> = zh.difference(&en).collect();
+    let only_en: Vec<_> = en.difference(&zh).collect();
+    assert!(
+        only_zh.is_empty() && only_en.is_empty(),
+        "只在中文目录中: {:?}\n只在英文目录中: {:?}",
+        only_zh,
+        only_en
+    );
+}
+
+#[test]
+fn catalogs_have_no_duplicate_or_empty_entries() {
+    for lang in Lang::ALL {
+        let entries = i18n::parse_catalog(lang.catalog_source()).unwrap();
+        let mut seen = BTreeSet::new();
+        for (key, value) in &entries {
+            assert!(
+                seen.insert(key),
+                "locales/{}.txt 重复的键: {}",
+                lang.code(),
+                key
+            );
+            assert!(
+                !value.is_empty(),
+                "locales/{}.txt 空值: {}",
+                lang.code(),
+                key
+            );
+        }
+    }
+}
+
+#[test]
+fn placeholders_match_between_languages() {
+    for key in keys(Lang::Zh) {
+        let zh = i18n::lookup(Lang::Zh, &key).unwrap();
+        let en = i18n::lookup(Lang::En, &key).unwrap();
+        assert_eq!(
+            zh.matches("{}").count(),
+            en.matches("{}").count(),
+            "{} 的占位符数量不一致",
+            key
+        );
+    }
+}
+
+#[test]
+fn lessons_and_sections_are_translated() {
+    for lang in Lang::ALL {
+        for section in Section::ALL {
+            assert!(i18n::lookup(lang, section.key()).is_some());
+        }
+        for lesson in LESSONS {
+            for field in ["topic", "summary"] {
+                let key = lesson.key(field);
+                assert!(
+                    i18n::lookup(lang, &key).is_some(),
+                    "locales/{}.txt 缺少 {}",
+                    lang.code(),
+                    key
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn keys_used_in_source_exist() {
+    let mut files = Vec::new();
+    rust_files(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut files,
+    );
+    let known: BTreeSet<String> = keys(Lang::Zh).into_iter().collect();
+
+    let mut missing = Vec::new();
+    for file in files {
+        let source = fs::read_to_string(&file).unwrap();
+        for key in literal_keys(&source) {
+            if !known.contains(&key) {
+                missing.push(format!("{}: {}", file.display(), key));
+            }
+        }
+    }
+    assert!(
+        missing.is_empty(),
+        "目录中没有的键:\n{}",
+        missing.join("\n")
+    );
+}
+
+#[test]
+fn tf_fills_placeholders_in_order() {
+    i18n::set_lang(Lang::En);
+    assert_eq!(
+        i18n::tf("learn.banner", &[&6, &"Structs", &"src/structs.rs"]),
+        "Lesson 6: Structs (src/structs.rs)"
+    );
+    assert_eq!(i18n::t("no.such.key"), "no.such.key");
+}
+
+#[test]
+fn lang_codes_parse() {
+    assert_eq!(Lang::parse("en"), Some(Lang::En));
+    assert_eq!(Lang::parse("en_US.UTF-8"), Some(Lang::En));
+    assert_eq!(Lang::parse("zh-CN"), Some(Lang::Zh));
+    assert_eq!(Lang::parse("fr"), None);
+}
+
+#[test]
+fn learn_list_follows_lang_flag() {
+    let list = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_learn"))
+            .args(args)
+            .env_remove("LEARN_LANG")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let zh = list(&["list"]);
+    let en = list(&["--lang", "en", "list"]);
+    assert!(zh.contains("【基础概念】") && zh.contains("结构体"));
+    assert!(en.contains("【Basics】") && en.contains("Structs"));
+}
@@ -0,0 +1,217 @@
+use std::net;
+
+use rust_learning::enums::IpAddr;
+use rust_learning::ip::{IpError, Ipv4, Ipv6};
+
+/// 用线性同余生成器得到可复现的“随机”数
+fn pseudo_random(seed: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(seed), |x| {
+        Some(
+            x.wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407),
+        )
+    })
+    .skip(1)
+}
+
+const INPUTS: [&str; 40] = [
+    "0.0.0.0",
+    "127.0.0.1",
+    "192.168.1.20",
+    "255.255.255.255",
+    "01.2.3.4",
+    "256.1.1.1",
+    "1.2.3",
+    "1.2.3.4.5",
+    " 1.2.3.4",
+    "1..2.3",
+    "+1.2.3.4",
+    "1.2.3.-4",
+    "99999999999.1.1.1",
+    "",
+    "::",
+    "::1",
+    "1::",
+    "fe80::1",
+    "FE80:0:0:0:0:0:0:1",
+    "2001:db8:0:0:1:0:0:1",
+    "1:0:1:0:0:1:0:0",
+    "1:2:3:4:5:6:7::",
+    "::2:3:4:5:6:7:8",
+    "::ffff:192.0.2.1",
+    "::1.2.3.4",
+    "64:ff9b::192.0.2.33",
+    "1:2:3:4:5:6:1.2.3.4",
+    "1:2:3:4:5:6::1.2.3.4",
+    "1::2:3:4:5:6:7:8",
+    "1:2:3:4:5:6:7:8:9",
+    "1:2:3:4:5:6:7",
+    "::1.2.3.4:5",
+    "1.2.3.4::",
+    "::01.2.3.4",
+    ":1::2",
+    "1::2:",
+    "1::2::3",
+    "00000::1",
+    "[::1]",
+    "fe80::1%eth0",
+];
+
+#[test]
+fn parsing_agrees_with_std() {
+    for input in INPUTS {
+        let ours = input.parse::<IpAddr>();
+        let std = input.parse::<net::IpAddr>();
+        assert_eq!(ours.is_ok(), std.is_ok(), "{:?}: {:?}", input, ours);
+        if let (Ok(ours), Ok(std)) = (ours, std) {
+            assert_eq!(net::IpAddr::from(ours), std, "{:?}", input);
+            assert_eq!(ours.to_string(), std.to_string(), "{:?}", input);
+            assert_eq!(ours.to_string().parse(), Ok(ours));
+        }
+    }
+}
+
+#[test]
+fn random_addresses_round_trip_through_std() {
+    let mut numbers = pseudo_random(42);
+    for _ in 0..2_000 {
+        let bits = numbers.next().unwrap() as u32;
+        let ours = Ipv4::from_bits(bits);
+        let std = net::Ipv4Addr::from(bits);
+        assert_eq!(ours.to_string(), std.to_string());
+        assert_eq!(Ipv4::from(std), ours);
+        assert_eq!(ours.to_bits(), bits);
+    }
+    for i in 0..2_000 {
+        let mut bits =
+            u128::from(numbers.next().unwrap()) << 64 | u128::from(numbers.next().unwrap());
+        // 随机地清零一些段，覆盖 `::` 出现在各个位置的情况
+        let mask = numbers.next().unwrap();
+        for segment in 0..8 {
+            if mask >> (segment * 2) & 3 != 0 {
+                bits &= !(0xffff << (16 * segment));
+            }
+        }
+        if i % 50 == 0 {
+            bits = 0xffff << 32 | bits & 0xffff_ffff;
+        }
+        let ours = Ipv6::from_bits(bits);
+        let std = net::Ipv6Addr::from(bits);
+        let text = ours.to_string();
+        assert_eq!(text, std.to_string());
+        assert_eq!(text.parse(), Ok(ours));
+        assert_eq!(Ipv6::from(std), ours);
+        assert_eq!(ours.octets(), std.octets());
+        assert_eq!(ours.to_bits(), bits);
+    }
+}
+
+#[test]
+fn errors_are_typed() {
+    let cases = [
+        ("", IpError::Empty),
+        ("banana", IpError::OctetCount(1)),
+        ("1.2.3.4.5", IpError::OctetCount(5)),
+        ("1.2.x.4", IpError::InvalidOctet("x".to_string())),
+        ("1.2..4", IpError::InvalidOctet("".to_string())),
+        ("1.02.3.4", IpError::InvalidOctet("02".to_string())),
+        ("1.2.3.300", IpError::OctetOutOfRange("300".to_string())),
+        ("1:2:3:4:5:6:7", IpError::GroupCount(7)),
+        ("1::2:3:4:5:6:7:8", IpError::GroupCount(8)),
+        ("1:2:3:4:5:6:7:8:9", IpError::GroupCount(9)),
+        ("12345::", IpError::InvalidGroup("12345".to_string())),
+        ("::g", IpError::InvalidGroup("g".to_string())),
+        ("1::2::3", IpError::MultipleCompressions),
+        (":1::2", IpError::MisplacedColon),
+        ("1::2:", IpError::MisplacedColon),
+        ("1.2.3.4::", IpError::MisplacedIpv4),
+        ("::1.2.3.4:5", IpError::MisplacedIpv4),
+        ("::1.2.3.256", IpError::OctetOutOfRange("256".to_string())),
+    ];
+    for (input, error) in cases {
+        assert_eq!(input.parse::<IpAddr>(), Err(error), "{:?}", input);
+    }
+    assert_eq!(
+        IpError::OctetCount(3).to_string(),
+        "IPv4 地址应有 4 段，实际 3 段"
+    );
+    assert_eq!("1.2.3.4".parse::<Ipv6>(), Err(IpError::GroupCount(2)));
+    assert_eq!("::1".parse::<Ipv4>(), Err(IpError::OctetCount(1)));
+}
+
+#[test]
+fn classification_matches_std() {
+    let inputs = [
+        "0.0.0.0",
+        "127.0.0.1",
+        "127.255.0.9",
+        "10.1.2.3",
+        "172.15.0.1",
+        "172.16.0.1",
+        "172.31.255.255",
+        "172.32.0.1",
+        "192.168.0.1",
+        "192.169.0.1",
+        "169.254.10.1",
+        "224.0.0.251",
+        "239.255.255.250",
+        "240.0.0.1",
+        "255.255.255.255",
+        "8.8.8.8",
+        "::",
+        "::1",
+        "::2",
+        "fc00::1",
+        "fdff:ffff::1",
+        "fe00::1",
+        "fe80::1",
+        "febf:ffff::1",
+        "fec0::1",
+        "ff02::1",
+        "2001:db8::1",
+        "::ffff:127.0.0.1",
+    ];
+    for input in inputs {
+        let ours: IpAddr = input.parse().unwrap();
+        match input.parse::<net::IpAddr>().unwrap() {
+            net::IpAddr::V4(std) => {
+                assert_eq!(ours.is_unspecified(), std.is_unspecified(), "{}", input);
+                assert_eq!(ours.is_loopback(), std.is_loopback(), "{}", input);
+                assert_eq!(ours.is_private(), std.is_private(), "{}", input);
+                assert_eq!(ours.is_link_local(), std.is_link_local(), "{}", input);
+                assert_eq!(ours.is_multicast(), std.is_multicast(), "{}", input);
+            }
+            net::IpAddr::V6(std) => {
+                assert_eq!(ours.is_unspecified(), std.is_unspecified(), "{}", input);
+                assert_eq!(ours.is_loopback(), std.is_loopback(), "{}", input);
+                assert_eq!(ours.is_private(), std.is_unique_local(), "{}", input);
+                assert_eq!(
+                    ours.is_link_local(),
+                    std.is_unicast_link_local(),
+                    "{}",
+                    input
+                );
+                assert_eq!(ours.is_multicast(), std.is_multicast(), "{}", input);
+            }
+        }
+    }
+    assert!(Ipv4::BROADCAST.is_broadcast());
+}
+
+#[test]
+fn ipv4_mapped_addresses() {
+    let ipv4 = Ipv4::new(192, 0, 2, 1);
+    let mapped = ipv4.to_ipv6_mapped();
+    assert_eq!(mapped.segments(), [0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201]);
+    assert_eq!(mapped.to_string(), "::ffff:192.0.2.1");
+    assert_eq!(mapped.to_ipv4_mapped(), Some(ipv4));
+    assert_eq!(Ipv6::LOCALHOST.to_ipv4_mapped(), None);
+    assert_eq!(
+        net::Ipv6Addr::from(mapped),
+        net::Ipv4Addr::from(ipv4).to_ipv6_mapped()
+    );
+    assert_eq!(
+        format!("{:?}", IpAddr::from(mapped)),
+        "V6(\"::ffff:192.0.2.1\")"
+    );
+}
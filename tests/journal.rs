@@ -0,0 +1,252 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use rust_learning::journal::{self, Entry, Event, Journal};
+use rust_learning::structs::TaskState;
+
+/// 每个测试使用独立的临时日志文件
+fn temp_journal(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!(
+        "rust-learning-journal-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn states(journal: &Journal) -> Vec<(u64, TaskState)> {
+    journal
+        .tasks()
+        .iter()
+        .map(|(id, record)| (*id, record.state))
+        .collect()
+}
+
+#[test]
+fn entries_round_trip_through_lines() {
+    let entries = [
+        Entry {
+            id: 1,
+            event: Event::New("nightly backup".to_string()),
+        },
+        Entry {
+            id: 1,
+            event: Event::Start,
+        },
+        Entry {
+            id: 1,
+            event: Event::Fail("disk full".to_string()),
+        },
+        Entry {
+            id: 2,
+            event: Event::Requeue,
+        },
+    ];
+    for entry in entries {
+        assert_eq!(Entry::parse(&entry.to_line()), Some(entry));
+    }
+    assert_eq!(Entry::parse("1 explode"), None);
+    assert_eq!(Entry::parse("x start"), None);
+}
+
+#[test]
+fn every_transition_is_appended() {
+    let path = temp_journal("transitions");
+    let (mut journal, recovery) = Journal::open(&path).unwrap();
+    assert!(recovery.ready.is_empty());
+
+    let task = journal.create("Backup").unwrap();
+    let task = journal.start(task).unwrap();
+    let task = journal.pause(task).unwrap();
+    let task = journal.resume(task).unwrap();
+    let done = journal.complete(task).unwrap();
+    assert_eq!(done.task().name(), "Backup");
+
+    let failed = journal.create("Upload").unwrap();
+    let failed = journal.start(failed).unwrap();
+    journal.fail(failed, "disk\nfull").unwrap();
+    let cancelled = journal.create("Report").unwrap();
+    journal.cancel(cancelled).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# rust-learning task journal\n\
+         1 new Backup\n1 start\n1 pause\n1 resume\n1 complete\n\
+         2 new Upload\n2 start\n2 fail disk full\n\
+         3 new Report\n3 cancel\n"
+    );
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn recovery_requeues_interrupted_running_tasks() {
+    let path = temp_journal("recovery");
+    {
+        let (mut journal, _) = Journal::open(&path).unwrap();
+        let _ready = journal.create("ready").unwrap();
+        let running = journal.create("running").unwrap();
+        let paused = journal.create("paused").unwrap();
+        let _running = journal.start(running).unwrap();
+        let paused = journal.start(paused).unwrap();
+        let _paused = journal.pause(paused).unwrap();
+        // 进程在这里「崩溃」：任务 2 停在 Running
+    }
+
+    let (mut journal, recovery) = Journal::open(&path).unwrap();
+    assert_eq!(recovery.requeued, [2]);
+    let ready: Vec<(u64, &str)> = recovery
+        .ready
+        .iter()
+        .map(|t| (t.id(), t.task().name()))
+        .collect();
+    assert_eq!(ready, [(1, "ready"), (2, "running")]);
+    assert_eq!(recovery.paused.len(), 1);
+    assert_eq!(recovery.paused[0].task().name(), "paused");
+    assert!(fs::read_to_string(&path).unwrap().ends_with("2 requeue\n"));
+
+    // 恢复出来的任务可以继续走完生命周期，新任务的编号接着往下排
+    let paused = recovery.paused.into_iter().next().unwrap();
+    let resumed = journal.resume(paused).unwrap();
+    journal.complete(resumed).unwrap();
+    assert_eq!(journal.create("later").unwrap().id(), 4);
+    assert_eq!(
+        states(&journal),
+        [
+            (1, TaskState::Ready),
+            (2, TaskState::Ready),
+            (3, TaskState::Completed),
+            (4, TaskState::Ready),
+        ]
+    );
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn names_and_reasons_round_trip_through_replay() {
+    let path = temp_journal("spaces");
+    {
+        let (mut journal, _) = Journal::open(&path).unwrap();
+        let task = journal.create("backup ").unwrap();
+        let task = journal.start(task).unwrap();
+        journal.fail(task, "disk full  ").unwrap();
+        journal.create(" upload").unwrap();
+    }
+    let (mut journal, recovery) = Journal::open(&path).unwrap();
+    let record = &journal.tasks()[&1];
+    assert_eq!(record.name, "backup ");
+    assert_eq!(record.reason.as_deref(), Some("disk full  "));
+    assert_eq!(recovery.ready[0].task().name(), " upload");
+
+    // 回车符会被 lines() 吃掉：任务名里不允许，失败原因里换成空格
+    assert!(journal.create("report\r").is_err());
+    let task = journal.create("report").unwrap();
+    let task = journal.start(task).unwrap();
+    journal.fail(task, "timeout\r").unwrap();
+    drop(journal);
+    let (journal, _) = Journal::open(&path).unwrap();
+    assert_eq!(journal.tasks()[&3].reason.as_deref(), Some("timeout "));
+
+    // Windows 风格的换行也能读
+    let (tasks, _) = journal::replay("1 new backup \r\n1 start\r\n").unwrap();
+    assert_eq!(tasks[&1].name, "backup ");
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn transitions_from_another_journal_are_refused() {
+    let path = temp_journal("foreign");
+    let other_path = temp_journal("foreign-other");
+    let (mut journal, _) = Journal::open(&path).unwrap();
+    let (mut other, _) = Journal::open(&other_path).unwrap();
+
+    let task = journal.create("backup").unwrap();
+    let task = journal.start(task).unwrap();
+    journal.complete(task).unwrap();
+    let before = fs::read_to_string(&path).unwrap();
+
+    // 另一个日志里的 1 号任务还是 Ready，这里的 1 号已经结束了；2 号在这里根本不存在
+    let stale = other.create("upload").unwrap();
+    let unknown = other.create("report").unwrap();
+    let error = journal.start(stale).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(journal.cancel(unknown).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+    assert_eq!(states(&journal), [(1, TaskState::Completed)]);
+
+    // 日志没有被写坏，还能重新打开
+    drop(journal);
+    let (journal, _) = Journal::open(&path).unwrap();
+    assert_eq!(states(&journal), [(1, TaskState::Completed)]);
+    let _ = fs::remove_file(&path);
+    let _ = fs::remove_file(&other_path);
+}
+
+#[test]
+fn torn_last_line_is_discarded() {
+    let path = temp_journal("torn");
+    fs::write(&path, "1 new Backup\n1 start\n1 comp").unwrap();
+    let (journal, recovery) = Journal::open(&path).unwrap();
+    assert_eq!(recovery.requeued, [1]);
+    assert_eq!(states(&journal), [(1, TaskState::Ready)]);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "1 new Backup\n1 start\n1 requeue\n"
+    );
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn replay_rejects_illegal_transitions() {
+    let cases = [
+        ("1 new a\n1 pause\n", 2),
+        ("1 start\n", 1),
+        ("1 new a\n1 new b\n", 2),
+        ("1 new a\n1 start\n1 complete\n1 resume\n", 4),
+        ("garbage\n", 1),
+    ];
+    for (text, line) in cases {
+        let error = journal::replay(text).unwrap_err();
+        assert!(
+            error.to_string().contains(&format!("第 {} 行", line)),
+            "{:?}: {}",
+            text,
+            error
+        );
+    }
+}
+
+#[test]
+fn compaction_keeps_live_tasks_and_ids() {
+    let path = temp_journal("compact");
+    let (mut journal, _) = Journal::open(&path).unwrap();
+    for i in 0..10 {
+        let task = journal.create(&format!("done{}", i)).unwrap();
+        let task = journal.start(task).unwrap();
+        journal.complete(task).unwrap();
+    }
+    let waiting = journal.create("waiting").unwrap();
+    let paused = journal.create("paused").unwrap();
+    let paused = journal.start(paused).unwrap();
+    let _paused = journal.pause(paused).unwrap();
+    assert_eq!(journal.len(), 34);
+
+    assert!(!journal.compact_if_needed(100, 4).unwrap());
+    assert!(journal.compact_if_needed(10, 4).unwrap());
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# rust-learning task journal\n11 new waiting\n12 new paused\n12 start\n12 pause\nnext 13\n"
+    );
+    assert_eq!(journal.len(), 5);
+
+    // 压缩后继续追加，重新打开时状态一致
+    let waiting = journal.start(waiting).unwrap();
+    journal.complete(waiting).unwrap();
+    drop(journal);
+    let (mut journal, recovery) = Journal::open(&path).unwrap();
+    assert!(recovery.ready.is_empty());
+    assert_eq!(recovery.paused[0].id(), 12);
+    assert_eq!(journal.create("new").unwrap().id(), 13);
+    let _ = fs::remove_file(&path);
+}
@@ -0,0 +1,205 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use rust_learning::codec::CodecError;
+use rust_learning::enums::{Handler, Message};
+use rust_learning::messaging::{Client, ClientError, Closed, Reply, Server};
+use rust_learning::structs::Color;
+
+/// 把收到的消息原样记下来
+#[derive(Default)]
+struct Recorder(Vec<Message>);
+
+impl Handler for Recorder {
+    fn quit(&mut self) {
+        self.0.push(Message::Quit);
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        self.0.push(Message::Move { x, y });
+    }
+
+    fn write(&mut self, text: &str) {
+        self.0.push(Message::Write(text.to_string()));
+    }
+
+    fn change_color(&mut self, color: Color) {
+        self.0.push(Message::ChangeColor(color));
+    }
+}
+
+/// 把消息转发到通道里的 Handler，连接结束时把结束方式也发过去
+struct Forward(mpsc::Sender<Event>);
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Message(Message),
+    Closed(Closed),
+}
+
+impl Handler for Forward {
+    fn quit(&mut self) {
+        self.0.send(Event::Message(Message::Quit)).unwrap();
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        self.0.send(Event::Message(Message::Move { x, y })).unwrap();
+    }
+
+    fn write(&mut self, text: &str) {
+        let message = Message::Write(text.to_string());
+        self.0.send(Event::Message(message)).unwrap();
+    }
+
+    fn change_color(&mut self, color: Color) {
+        let message = Message::ChangeColor(color);
+        self.0.send(Event::Message(message)).unwrap();
+    }
+}
+
+/// 在系统分配的端口上启动服务端，返回地址和事件通道
+fn start_server() -> (SocketAddr, Receiver<Event>) {
+    let server = Server::bind("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    let (sender, receiver) = mpsc::channel();
+    let closed = sender.clone();
+    thread::spawn(move || {
+        server.run(
+            move |_| Forward(sender.clone()),
+            move |_, result| closed.send(Event::Closed(result.unwrap())).unwrap(),
+            |e| panic!("接受连接失败: {}", e),
+        )
+    });
+    (addr, receiver)
+}
+
+fn next_event(events: &Receiver<Event>) -> Event {
+    events.recv_timeout(Duration::from_secs(5)).unwrap()
+}
+
+fn sample() -> Vec<Message> {
+    vec![
+        Message::Move { x: 10, y: -20 },
+        Message::Write("你好, Rust!".to_string()),
+        Message::ChangeColor(Color(255, 128, 0)),
+        Message::Write(String::new()),
+    ]
+}
+
+#[test]
+fn dispatch_calls_the_matching_handler_method() {
+    let mut recorder = Recorder::default();
+    let mut messages = sample();
+    messages.push(Message::Quit);
+    for message in &messages {
+        message.dispatch(&mut recorder);
+    }
+    assert_eq!(recorder.0, messages);
+}
+
+#[test]
+fn client_and_server_round_trip() {
+    let (addr, events) = start_server();
+    let mut client = Client::connect(addr).unwrap();
+    for (i, message) in sample().into_iter().enumerate() {
+        assert_eq!(client.send(&message).unwrap(), i as u32 + 1);
+        assert_eq!(next_event(&events), Event::Message(message));
+    }
+    assert_eq!(client.send(&Message::Quit).unwrap(), 5);
+    assert_eq!(next_event(&events), Event::Message(Message::Quit));
+    assert_eq!(next_event(&events), Event::Closed(Closed::Quit));
+
+    // Quit 之后连接已关闭，再发消息会失败
+    let error = client.send(&Message::Write("too late".to_string()));
+    assert!(matches!(error, Err(ClientError::Io(_))), "{:?}", error);
+}
+
+#[test]
+fn messages_split_across_writes_are_reassembled() {
+    let (addr, events) = start_server();
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.set_nodelay(true).unwrap();
+    let mut bytes = Vec::new();
+    for message in sample() {
+        message.encode_into(&mut bytes).unwrap();
+    }
+    // 一个字节一个字节地发，服务端每次 read 都只能拿到一小块
+    for byte in &bytes {
+        stream.write_all(&[*byte]).unwrap();
+    }
+    for (i, message) in sample().into_iter().enumerate() {
+        assert_eq!(
+            Reply::read_from(&mut stream).unwrap(),
+            Reply::Ack(i as u32 + 1)
+        );
+        assert_eq!(next_event(&events), Event::Message(message));
+    }
+
+    // 断开时还剩半条消息
+    stream.write_all(&[0x01, 0, 0]).unwrap();
+    drop(stream);
+    assert_eq!(
+        next_event(&events),
+        Event::Closed(Closed::Eof { pending: 3 })
+    );
+}
+
+#[test]
+fn corrupt_data_is_rejected_and_the_connection_closed() {
+    let (addr, events) = start_server();
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let mut bytes = Message::Move { x: 1, y: 2 }.encode().unwrap();
+    bytes.push(0x7f);
+    stream.write_all(&bytes).unwrap();
+
+    assert_eq!(Reply::read_from(&mut stream).unwrap(), Reply::Ack(1));
+    match Reply::read_from(&mut stream).unwrap() {
+        Reply::Error(text) => assert!(text.contains("0x7f"), "{}", text),
+        reply => panic!("期望 Error，实际是 {:?}", reply),
+    }
+    let mut rest = Vec::new();
+    stream.read_to_end(&mut rest).unwrap();
+    assert!(rest.is_empty());
+
+    assert_eq!(
+        next_event(&events),
+        Event::Message(Message::Move { x: 1, y: 2 })
+    );
+    match next_event(&events) {
+        Event::Closed(Closed::Corrupt(e)) => assert_eq!(e.to_string(), "未知的消息标签 0x7f"),
+        event => panic!("期望 Corrupt，实际是 {:?}", event),
+    }
+}
+
+#[test]
+fn overlong_error_replies_are_refused() {
+    let mut bytes = vec![0x81];
+    bytes.extend_from_slice(&u32::MAX.to_be_bytes());
+    match Reply::read_from(&mut &bytes[..]) {
+        Err(ClientError::Codec(CodecError::TextTooLong(len))) => {
+            assert_eq!(len, u32::MAX as usize)
+        }
+        reply => panic!("期望 TextTooLong，实际是 {:?}", reply),
+    }
+}
+
+#[test]
+fn each_connection_counts_its_own_messages() {
+    let (addr, _events) = start_server();
+    let clients: Vec<_> = (0..4)
+        .map(|id| {
+            thread::spawn(move || {
+                let mut client = Client::connect(addr).unwrap();
+                (1..=20)
+                    .map(|n| client.send(&Message::Move { x: id, y: n }).unwrap())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    for client in clients {
+        assert_eq!(client.join().unwrap(), (1..=20).collect::<Vec<u32>>());
+    }
+}
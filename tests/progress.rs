@@ -0,0 +1,141 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use rust_learning::lessons::Section;
+use rust_learning::progress::{self, Progress, QuizScore};
+
+fn temp_file(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("rust-learning-{}-{}", name, std::process::id()))
+}
+
+#[test]
+fn round_trips_through_text() {
+    let mut progress = Progress::default();
+    progress.record_lesson(1, 1_760_601_600);
+    progress.record_lesson(6, 1_760_601_700);
+    progress.record_exercise("functions1", 1_760_601_900);
+    progress.record_quiz(1, 3, 4, 1_760_602_200);
+
+    let parsed = Progress::parse(&progress.to_text()).unwrap();
+    assert_eq!(parsed, progress);
+    assert_eq!(
+        parsed.quizzes[&1],
+        QuizScore {
+            correct: 3,
+            total: 4,
+            at: 1_760_602_200
+        }
+    );
+}
+
+#[test]
+fn later_records_win_and_comments_are_ignored() {
+    let text = "# comment\n\nlesson 2 100\nlesson 2 200\nquiz 2 1/2 50\nquiz 2 2/2 60\n";
+    let progress = Progress::parse(text).unwrap();
+    assert_eq!(progress.lessons[&2], 200);
+    assert_eq!(progress.quizzes[&2].correct, 2);
+}
+
+#[test]
+fn rejects_malformed_lines() {
+    for text in [
+        "lesson x 1",
+        "lesson 1",
+        "quiz 1 3 100",
+        "quiz 1 a/4 100",
+        "badge 1 1",
+    ] {
+        let err = Progress::parse(text).unwrap_err();
+        assert!(err.to_string().contains("第 1 行"), "{}: {}", text, err);
+    }
+}
+
+#[test]
+fn missing_file_is_empty_progress() {
+    let path = temp_file("missing-progress");
+    let _ = fs::remove_file(&path);
+    assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+}
+
+#[test]
+fn update_refuses_to_overwrite_corrupt_file() {
+    let path = temp_file("corrupt-progress");
+    fs::write(&path, "not a progress file\n").unwrap();
+    assert!(progress::update(&path, |p| p.record_lesson(1, 1)).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "not a progress file\n");
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn update_persists_changes() {
+    let path = temp_file("update-progress");
+    let _ = fs::remove_file(&path);
+    progress::update(&path, |p| p.record_lesson(3, 10)).unwrap();
+    progress::update(&path, |p| p.record_exercise("functions2", 20)).unwrap();
+    let loaded = Progress::load(&path).unwrap();
+    assert_eq!(loaded.lessons[&3], 10);
+    assert_eq!(loaded.exercises["functions2"], 20);
+    assert!(!path.with_extension("tmp").exists());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn next_lesson_is_first_unviewed() {
+    let mut progress = Progress::default();
+    assert_eq!(progress.next_lesson(), Some(1));
+    progress.record_lesson(1, 1);
+    progress.record_lesson(3, 1);
+    assert_eq!(progress.next_lesson(), Some(2));
+}
+
+#[test]
+fn next_lesson_follows_the_learning_path() {
+    let mut progress = Progress::default();
+    for number in 1..=9 {
+        progress.record_lesson(number, 1);
+    }
+    // 第 10 课属于进阶部分，抽象部分的第 11 课排在它前面
+    assert_eq!(progress.next_lesson(), Some(11));
+    for number in [11, 12, 14] {
+        progress.record_lesson(number, 1);
+    }
+    assert_eq!(progress.next_lesson(), Some(10));
+}
+
+#[test]
+fn section_status_counts_lessons_and_exercises() {
+    let mut progress = Progress::default();
+    for number in 1..=4 {
+        progress.record_lesson(number, 1);
+    }
+    progress.record_exercise("variables1", 2);
+    progress.record_exercise("ownership1", 2);
+    progress.record_quiz(1, 3, 4, 3);
+
+    let basics = progress.section_status(Section::Basics);
+    assert_eq!((basics.lessons_viewed, basics.lessons_total), (4, 4));
+    assert_eq!((basics.exercises_passed, basics.exercises_total), (1, 8));
+    assert_eq!((basics.quiz_correct, basics.quiz_total), (3, 4));
+    assert_eq!(basics.percent(), 5 * 100 / 12);
+
+    let core = progress.section_status(Section::Core);
+    assert_eq!(core.lessons_viewed, 0);
+    assert_eq!(core.exercises_passed, 1);
+
+    let report = progress.report();
+    for section in Section::ALL {
+        assert!(report.contains(section.title()));
+    }
+    assert!(report.contains("最近活动：1970-01-01 00:00 UTC"));
+}
+
+#[test]
+fn formats_timestamps_as_utc() {
+    assert_eq!(progress::format_timestamp(0), "1970-01-01 00:00");
+    assert_eq!(progress::format_timestamp(951_782_400), "2000-02-29 00:00");
+    assert_eq!(
+        progress::format_timestamp(1_760_601_600 + 3_723),
+        "2025-10-16 09:02"
+    );
+}
@@ -0,0 +1,176 @@
+use std::env;
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use std::process::Command;
+
+use rust_learning::lessons::LESSONS;
+use rust_learning::progress::Progress;
+use rust_learning::quiz::{self, QuestionKind};
+
+fn normalize(text: &str) -> Vec<&str> {
+    text.trim().lines().map(str::trim).collect()
+}
+
+#[test]
+fn every_lesson_has_a_valid_bank() {
+    for lesson in LESSONS {
+        let questions = quiz::load(lesson).unwrap_or_else(|e| panic!("{}: {}", lesson.name, e));
+        assert!(
+            questions.len() >= 3,
+            "{} 只有 {} 道题",
+            lesson.name,
+            questions.len()
+        );
+        assert!(
+            questions
+                .iter()
+                .any(|q| matches!(q.kind, QuestionKind::Output { .. })),
+            "{} 缺少「打印什么」题",
+            lesson.name
+        );
+    }
+}
+
+/// 没有代码片段的输出题问的是整节课的输出，答案必须与黄金快照一致
+#[test]
+fn whole_lesson_output_answers_match_snapshots() {
+    for lesson in LESSONS {
+        for question in quiz::load(lesson).unwrap() {
+            let QuestionKind::Output { expected } = &question.kind else {
+                continue;
+            };
+            if question.code.is_some() {
+                continue;
+            }
+            let snapshot = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/snapshots")
+                .join(format!("{}.stdout", lesson.name));
+            let snapshot = fs::read_to_string(snapshot).unwrap();
+            assert_eq!(normalize(expected), normalize(&snapshot), "{}", lesson.name);
+        }
+    }
+}
+
+#[test]
+fn grades_choice_and_output_answers() {
+    let questions = quiz::parse(
+        r#"
+[[question]]
+kind = "choice"
+prompt = "1 + 1 = ?"
+choices = ["1", "2", "3"]
+answer = "B"
+explain = "加法"
+
+[[question]]
+kind = "output"
+prompt = "打印什么？"
+code = """
+for i in 1..3 { println!("{}!", i); }
+"""
+answer = """
+1!
+2!
+"""
+explain = "左闭右开"
+"#,
+    )
+    .unwrap();
+
+    assert!(questions[0].is_correct("b"));
+    assert!(questions[0].is_correct("2"));
+    assert!(!questions[0].is_correct("A"));
+    assert!(!questions[0].is_correct("D"));
+    assert!(questions[1].is_correct("  1!  \n2!\n\n"));
+    assert_eq!(
+        questions[1].code.as_deref(),
+        Some("for i in 1..3 { println!(\"{}!\", i); }\n")
+    );
+
+    let mut out = Vec::new();
+    let result = quiz::run(
+        &questions,
+        "src/control_flow.rs",
+        &mut Cursor::new("c\n1!\n2!\n\n"),
+        &mut out,
+    )
+    .unwrap();
+    assert_eq!((result.correct, result.total), (1, 2));
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("❌ 不对，正确答案：B. 2"));
+    assert!(out.contains("参见 src/control_flow.rs"));
+    assert!(out.contains("得分：1/2"));
+}
+
+#[test]
+fn reports_errors_with_line_numbers() {
+    let cases = [
+        ("kind = \"choice\"", 1, "[[question]]"),
+        (
+            "[[question]]\nkind = \"essay\"\nprompt = \"p\"\nexplain = \"e\"",
+            1,
+            "未知题型",
+        ),
+        (
+            "[[question]]\nkind = \"choice\"\nprompt = \"p\"\nchoices = [\"a\", \"b\"]\nanswer = \"C\"\nexplain = \"e\"",
+            5,
+            "不是有效的选项",
+        ),
+        ("[[question]]\nprompt = \"unterminated", 2, "结尾引号"),
+        ("[[question]]\ncode = \"\"\"\nfn main() {}\n", 2, "\"\"\""),
+        (
+            "[[question]]\nkind = \"output\"\nprompt = \"p\"\nanswer = \"a\"\nexplain = \"e\"\ncolour = \"red\"",
+            6,
+            "未知字段",
+        ),
+        (
+            "[[question]]\nkind = \"output\"\nkind = \"choice\"",
+            3,
+            "重复",
+        ),
+        (
+            "[[question]]\nkind = \"choice\"\nprompt = \"p\"\nchoices = [\"a\", \"b\"]\nanswer = -9223372036854775808\nexplain = \"e\"",
+            5,
+            "不是有效的选项",
+        ),
+        (
+            "[[question]]\nkind = \"output\"\nprompt = \"p\"\nanswer = \"1\\n\\n2\"\nexplain = \"e\"",
+            4,
+            "空行",
+        ),
+    ];
+    for (text, line, message) in cases {
+        let err = quiz::parse(text).unwrap_err();
+        assert_eq!(err.line, line, "{:?}: {}", text, err);
+        assert!(err.message.contains(message), "{:?}: {}", text, err);
+    }
+}
+
+#[test]
+fn learn_quiz_reads_answers_from_file_and_records_score() {
+    let progress_file = env::temp_dir().join(format!("rust-learning-quiz-{}", std::process::id()));
+    let _ = fs::remove_file(&progress_file);
+    let answers = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/01_variables.answers");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_learn"))
+        .args(["quiz", "1", "--answers"])
+        .arg(&answers)
+        .env("LEARN_PROGRESS_FILE", &progress_file)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("得分：3/3"), "{}", stdout);
+
+    let progress = Progress::load(&progress_file).unwrap();
+    assert_eq!(
+        (progress.quizzes[&1].correct, progress.quizzes[&1].total),
+        (3, 3)
+    );
+    fs::remove_file(&progress_file).unwrap();
+}
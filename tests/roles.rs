@@ -0,0 +1,104 @@
+use rust_learning::structs::{Action, Role, User, can};
+use rust_learning::users::{MemoryUserRepository, UserRepository};
+
+fn user_with(role: Role) -> User {
+    let mut user = User::from_email("someone@example.org").unwrap();
+    user.set_role(role);
+    user
+}
+
+#[test]
+fn permission_matrix() {
+    use Action::*;
+    // (角色, [Read, Comment, Edit, Publish, Delete, ManageUsers])
+    let matrix = [
+        (Role::Viewer, [true, true, false, false, false, false]),
+        (Role::Editor, [true, true, true, true, false, false]),
+        (Role::Admin, [true, true, true, true, true, true]),
+    ];
+    assert_eq!(
+        Action::ALL,
+        [Read, Comment, Edit, Publish, Delete, ManageUsers]
+    );
+    for (role, expected) in matrix {
+        let mut user = user_with(role);
+        for (action, allowed) in Action::ALL.into_iter().zip(expected) {
+            assert_eq!(role.allows(action), allowed, "{:?} {:?}", role, action);
+            assert_eq!(can(&user, action), allowed, "{:?} {:?}", role, action);
+        }
+        // 停用的用户无论什么角色都被拒绝
+        user.deactivate();
+        for action in Action::ALL {
+            assert!(!can(&user, action), "停用的 {:?} 不应能 {:?}", role, action);
+        }
+        user.activate();
+        assert_eq!(can(&user, Read), expected[0]);
+    }
+}
+
+#[test]
+fn roles_inherit_from_lower_roles() {
+    assert_eq!(
+        Role::Admin.lineage().collect::<Vec<_>>(),
+        [Role::Admin, Role::Editor, Role::Viewer]
+    );
+    assert_eq!(Role::Viewer.lineage().collect::<Vec<_>>(), [Role::Viewer]);
+    for role in Role::ALL {
+        if let Some(parent) = role.parent() {
+            assert!(parent < role);
+            assert_eq!(parent.child(), Some(role));
+            // 子角色拥有父角色的全部权限，并且至少多一项
+            for action in Action::ALL {
+                assert!(!parent.allows(action) || role.allows(action));
+            }
+            assert!(role.grants().iter().all(|&action| !parent.allows(action)));
+        }
+    }
+    // 每个操作都恰好由一个角色直接授予
+    for action in Action::ALL {
+        let granting: Vec<Role> = Role::ALL
+            .into_iter()
+            .filter(|role| role.grants().contains(&action))
+            .collect();
+        assert_eq!(granting.len(), 1, "{:?}", action);
+    }
+}
+
+#[test]
+fn promote_and_demote_walk_the_hierarchy() {
+    let mut user = User::from_email("alice@example.org").unwrap();
+    assert_eq!(user.role(), Role::Viewer);
+    assert!(!user.demote());
+    assert!(user.promote());
+    assert_eq!(user.role(), Role::Editor);
+    assert!(user.promote());
+    assert!(!user.promote());
+    assert_eq!(user.role(), Role::Admin);
+    assert!(user.demote());
+    assert_eq!(user.role(), Role::Editor);
+    // 提升角色不是登录
+    assert_eq!(user.sign_in_count(), 0);
+}
+
+#[test]
+fn role_names_round_trip_and_persist() {
+    for role in Role::ALL {
+        assert_eq!(Role::parse(role.name()), Some(role));
+        assert_eq!(Role::parse(&role.name().to_uppercase()), Some(role));
+    }
+    assert_eq!(Role::parse("root"), None);
+
+    let mut repo = MemoryUserRepository::new();
+    repo.create(user_with(Role::Admin)).unwrap();
+    let text = repo.to_text();
+    assert!(
+        text.contains("someone someone@example.org active admin 0 -"),
+        "{}",
+        text
+    );
+    let reloaded = MemoryUserRepository::parse(&text).unwrap();
+    assert_eq!(
+        reloaded.find_by_username("someone").unwrap().role(),
+        Role::Admin
+    );
+}
@@ -0,0 +1,166 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rust_learning::scheduler::{Priority, Report, Scheduler, TaskId};
+use rust_learning::structs::{AnyTask, Task, TaskState};
+
+const WAIT: Duration = Duration::from_secs(5);
+
+fn next(scheduler: &Scheduler) -> Report {
+    scheduler
+        .results()
+        .recv_timeout(WAIT)
+        .expect("等待任务结果超时")
+}
+
+/// 提交一个一直占着工作线程、直到 `release` 被调用的任务
+fn block(scheduler: &Scheduler) -> impl FnOnce() {
+    let (release, wait) = mpsc::channel::<()>();
+    scheduler.submit(Task::new("blocker"), Priority::High, move || {
+        let _ = wait.recv();
+        Ok(())
+    });
+    move || release.send(()).unwrap()
+}
+
+#[test]
+fn reports_completed_failed_and_panicked_tasks() {
+    let scheduler = Scheduler::new(2);
+    let ok = scheduler.submit(Task::new("ok"), Priority::Normal, || Ok(()));
+    let err = scheduler.submit(Task::new("err"), Priority::Normal, || {
+        Err("disk full".to_string())
+    });
+    let boom = scheduler.submit(Task::new("boom"), Priority::Normal, || panic!("oops"));
+
+    let mut reports: Vec<Report> = (0..3).map(|_| next(&scheduler)).collect();
+    reports.sort_by_key(|report| report.id);
+    assert_eq!(
+        reports.iter().map(|r| r.id).collect::<Vec<_>>(),
+        [ok, err, boom]
+    );
+    assert_eq!(reports[0].task.state(), TaskState::Completed);
+    match &reports[1].task {
+        AnyTask::Failed(task) => assert_eq!(task.reason(), "disk full"),
+        other => panic!("应为 Failed: {:?}", other),
+    }
+    match &reports[2].task {
+        AnyTask::Failed(task) => assert_eq!(task.reason(), "panicked: oops"),
+        other => panic!("应为 Failed: {:?}", other),
+    }
+    assert!(scheduler.shutdown().is_empty());
+}
+
+#[test]
+fn higher_priority_runs_first() {
+    let scheduler = Scheduler::new(1);
+    let release = block(&scheduler);
+    let order = Arc::new(Mutex::new(Vec::new()));
+    for (name, priority) in [
+        ("low", Priority::Low),
+        ("normal-1", Priority::Normal),
+        ("high", Priority::High),
+        ("normal-2", Priority::Normal),
+    ] {
+        let order = Arc::clone(&order);
+        scheduler.submit(Task::new(name), priority, move || {
+            order.lock().unwrap().push(name);
+            Ok(())
+        });
+    }
+    release();
+    scheduler.shutdown();
+    assert_eq!(
+        *order.lock().unwrap(),
+        ["high", "normal-1", "normal-2", "low"]
+    );
+}
+
+#[test]
+fn max_concurrency_limits_running_tasks() {
+    let scheduler = Scheduler::new(4);
+    scheduler.set_max_concurrency(2);
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    for i in 0..8 {
+        let running = Arc::clone(&running);
+        let peak = Arc::clone(&peak);
+        scheduler.submit(
+            Task::new(&format!("job{}", i)),
+            Priority::Normal,
+            move || {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(20));
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            },
+        );
+    }
+    let reports = scheduler.shutdown();
+    assert_eq!(reports.len(), 8);
+    // 睡眠不能保证两个任务一定重叠，这里只检查上限
+    let peak = peak.load(Ordering::SeqCst);
+    assert!((1..=2).contains(&peak), "peak = {}", peak);
+}
+
+#[test]
+fn paused_tasks_wait_until_resumed() {
+    let scheduler = Scheduler::new(1);
+    let release = block(&scheduler);
+    let held = scheduler.submit(Task::new("held"), Priority::Normal, || Ok(()));
+    let free = scheduler.submit(Task::new("free"), Priority::Normal, || Ok(()));
+    assert!(scheduler.pause(held));
+    assert!(!scheduler.pause(TaskId(999)));
+    release();
+
+    assert_eq!(next(&scheduler).task.name(), "blocker");
+    assert_eq!(next(&scheduler).id, free);
+    assert_eq!(scheduler.pending(), 1);
+    assert!(scheduler.results().try_recv().is_err());
+
+    assert!(scheduler.resume(held));
+    let report = next(&scheduler);
+    assert_eq!(report.id, held);
+    assert_eq!(report.task.state(), TaskState::Completed);
+    scheduler.shutdown();
+}
+
+#[test]
+fn queued_tasks_can_be_cancelled() {
+    let scheduler = Scheduler::new(1);
+    let release = block(&scheduler);
+    let doomed = scheduler.submit(Task::new("doomed"), Priority::Normal, || {
+        panic!("被取消的任务不应执行")
+    });
+    assert!(scheduler.cancel(doomed));
+    assert!(!scheduler.cancel(doomed));
+
+    let report = next(&scheduler);
+    assert_eq!(report.id, doomed);
+    assert_eq!(report.task.state(), TaskState::Cancelled);
+    release();
+    scheduler.shutdown();
+}
+
+#[test]
+fn shutdown_drains_queue_and_cancels_paused_tasks() {
+    let scheduler = Scheduler::new(1);
+    let release = block(&scheduler);
+    let queued = scheduler.submit(Task::new("queued"), Priority::Low, || Ok(()));
+    let paused = scheduler.submit(Task::new("paused"), Priority::Low, || Ok(()));
+    scheduler.pause(paused);
+    release();
+
+    let states: Vec<(TaskId, TaskState)> = scheduler
+        .shutdown()
+        .iter()
+        .map(|report| (report.id, report.task.state()))
+        .filter(|(id, _)| *id == queued || *id == paused)
+        .collect();
+    assert_eq!(states.len(), 2);
+    assert!(states.contains(&(queued, TaskState::Completed)));
+    assert!(states.contains(&(paused, TaskState::Cancelled)));
+}
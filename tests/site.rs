@@ -0,0 +1,130 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use rust_learning::i18n::{self, Lang};
+use rust_learning::lessons::{self, LESSONS};
+use rust_learning::site::{self, Block, Part};
+
+#[test]
+fn split_on_inline_and_banner_separators() {
+    let source = "\
+//! 模块说明
+
+pub fn run() {
+    // ===== 第一段 =====
+    let a = 1;
+}
+
+// ===============================================================================
+// 第二段
+// ===============================================================================
+
+/// 一个函数
+pub fn f() {}
+";
+    let parts = site::split(source);
+    let titles: Vec<Option<&str>> = parts.iter().map(|p| p.title.as_deref()).collect();
+    assert_eq!(titles, [None, Some("第一段"), Some("第二段")]);
+
+    assert_eq!(
+        parts[0].blocks,
+        [
+            Block::Prose("模块说明".to_string()),
+            Block::Code("pub fn run() {".to_string()),
+        ]
+    );
+    assert_eq!(
+        parts[1],
+        Part {
+            title: Some("第一段".to_string()),
+            blocks: vec![Block::Code("    let a = 1;\n}".to_string())],
+        }
+    );
+    assert_eq!(
+        parts[2].blocks,
+        [
+            Block::Prose("一个函数".to_string()),
+            Block::Code("pub fn f() {}".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn indented_doc_comments_stay_in_code() {
+    let source = "pub trait T {\n    /// 方法说明\n    fn m(&self);\n}\n";
+    assert_eq!(
+        site::split(source),
+        [Part {
+            title: None,
+            blocks: vec![Block::Code(source.trim_end().to_string())],
+        }]
+    );
+}
+
+#[test]
+fn lesson_sources_leave_no_separator_lines() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for lesson in LESSONS {
+        let source = fs::read_to_string(root.join(lesson.source)).unwrap();
+        let parts = site::split(&source);
+        assert!(!parts.is_empty(), "{} 没有任何内容", lesson.name);
+        for block in parts.iter().flat_map(|p| &p.blocks) {
+            if let Block::Code(code) = block {
+                assert!(
+                    !code.lines().any(|l| l.trim().starts_with("// =====")),
+                    "{} 的代码块里留下了分隔注释:\n{}",
+                    lesson.name,
+                    code
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn highlight_escapes_and_marks_tokens() {
+    let html = site::highlight("let s: &'a str = \"<b>\"; // 注释\nprintln!(\"{}\", 'x');");
+    assert!(html.contains("<span class=\"kw\">let</span>"));
+    assert!(html.contains("<span class=\"lt\">'a</span>"));
+    assert!(html.contains("<span class=\"str\">&quot;&lt;b&gt;&quot;</span>"));
+    assert!(html.contains("<span class=\"com\">// 注释</span>"));
+    assert!(html.contains("<span class=\"mac\">println!</span>"));
+    assert!(html.contains("<span class=\"str\">'x'</span>"));
+    assert!(!html.contains("<b>"));
+}
+
+#[test]
+fn render_prose_handles_lists_and_inline_code() {
+    let html = site::render_prose("所有权规则：\n- 每个值有唯一所有者\n- 用 `clone` 深拷贝");
+    assert_eq!(
+        html,
+        "<p>所有权规则：</p>\n<ul>\n<li>每个值有唯一所有者</li>\n<li>用 <code>clone</code> 深拷贝</li>\n</ul>\n"
+    );
+}
+
+#[test]
+fn build_writes_every_lesson_with_output_and_learning_path_navigation() {
+    i18n::set_lang(Lang::Zh);
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out = env::temp_dir().join(format!("rust-learning-site-{}", std::process::id()));
+    let files = site::build(root, &out).unwrap();
+    assert_eq!(files.len(), LESSONS.len() + 2);
+
+    let index = fs::read_to_string(out.join("index.html")).unwrap();
+    let positions: Vec<usize> = lessons::learning_path()
+        .map(|lesson| index.find(&site::page_name(lesson)).unwrap())
+        .collect();
+    assert!(
+        positions.windows(2).all(|w| w[0] < w[1]),
+        "首页顺序应与学习路径一致"
+    );
+
+    // 学习路径中 14_trait 之后是 10_error_handling（高级特性的第一课）
+    let trait_page = fs::read_to_string(out.join("14_trait.html")).unwrap();
+    assert!(trait_page.contains("<a href=\"10_error_handling.html\">错误处理 →</a>"));
+    assert!(trait_page.contains("<h2>示例 1: 基本 Trait 定义</h2>"));
+    assert!(trait_page.contains("Counter values:"));
+
+    let _ = fs::remove_dir_all(&out);
+}
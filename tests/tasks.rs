@@ -0,0 +1,60 @@
+use std::mem::size_of;
+
+use rust_learning::structs::{AnyTask, Failed, Ready, Running, Task, TaskState};
+
+#[test]
+fn full_lifecycle_keeps_the_name() {
+    let task = Task::new("Backup").start().pause().resume().complete();
+    assert_eq!(task.name(), "Backup");
+
+    let failed = Task::new("Upload").start().fail("disk full");
+    assert_eq!(failed.reason(), "disk full");
+}
+
+#[test]
+fn marker_states_take_no_space() {
+    // 状态只存在于类型里，Task<Ready> 和一个 String 一样大；只有 Failed 多带一个失败原因
+    assert_eq!(size_of::<Task<Ready>>(), size_of::<String>());
+    assert_eq!(size_of::<Task<Running>>(), size_of::<String>());
+    assert_eq!(size_of::<Task<Failed>>(), 2 * size_of::<String>());
+}
+
+#[test]
+fn any_task_reports_state() {
+    let tasks: Vec<AnyTask> = vec![
+        Task::new("a").into(),
+        Task::new("b").start().into(),
+        Task::new("c").start().pause().into(),
+        Task::new("d").start().complete().into(),
+        Task::new("e").start().fail("boom").into(),
+        Task::new("f").cancel().into(),
+    ];
+    let states: Vec<TaskState> = tasks.iter().map(AnyTask::state).collect();
+    assert_eq!(
+        states,
+        [
+            TaskState::Ready,
+            TaskState::Running,
+            TaskState::Paused,
+            TaskState::Completed,
+            TaskState::Failed,
+            TaskState::Cancelled,
+        ]
+    );
+    let terminal: Vec<&str> = tasks
+        .iter()
+        .filter(|task| task.is_terminal())
+        .map(AnyTask::name)
+        .collect();
+    assert_eq!(terminal, ["d", "e", "f"]);
+}
+
+#[test]
+fn any_task_cancel_only_active_tasks() {
+    let paused: AnyTask = Task::new("a").start().pause().into();
+    assert_eq!(paused.cancel().unwrap().name(), "a");
+
+    let done: AnyTask = Task::new("b").start().complete().into();
+    let rejected = done.cancel().unwrap_err();
+    assert_eq!(rejected.state(), TaskState::Completed);
+}
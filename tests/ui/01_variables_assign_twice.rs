@@ -0,0 +1,9 @@
+//@ error: E0384
+// 01_variables.rs：变量默认不可变，不能再次赋值
+
+pub fn main() {
+    let x = 5;
+    println!("x = {}", x);
+    x = 6;
+    println!("x = {}", x);
+}
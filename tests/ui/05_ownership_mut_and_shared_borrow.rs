@@ -0,0 +1,9 @@
+//@ error: E0502
+// 05_ownership.rs：不可变引用仍在使用时，不能再创建可变引用
+
+pub fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &mut s;
+    println!("{}, {}", r1, r2);
+}
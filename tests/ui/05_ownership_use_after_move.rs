@@ -0,0 +1,9 @@
+//@ error: E0382
+// 05_ownership.rs：s1 被 move 到 s2 之后不能再使用
+
+pub fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("{}", s1);
+    println!("s2 = {}", s2);
+}
@@ -0,0 +1,9 @@
+//@ error: E0599
+// 06_structs.rs：cancel 要求状态实现 Active，已完成的任务不能再取消
+
+use rust_learning::structs::Task;
+
+pub fn main() {
+    let task = Task::new("Backup").start().complete();
+    task.cancel();
+}
@@ -0,0 +1,9 @@
+//@ error: E0599
+// 06_structs.rs：只有 Running 的任务能 complete，暂停的任务要先 resume
+
+use rust_learning::structs::Task;
+
+pub fn main() {
+    let task = Task::new("Backup").start().pause();
+    task.complete();
+}
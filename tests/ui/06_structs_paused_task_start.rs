@@ -0,0 +1,11 @@
+//@ error: E0599
+// 06_structs.rs：Task<Paused> 没有 start 方法，类型状态在编译期阻止非法转换
+
+use rust_learning::structs::Task;
+
+pub fn main() {
+    let task = Task::new("Backup");
+    let task = task.start();
+    let task = task.pause();
+    task.start();
+}
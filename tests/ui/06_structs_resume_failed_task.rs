@@ -0,0 +1,9 @@
+//@ error: E0599
+// 06_structs.rs：Failed 是终止状态，失败的任务没有 resume 方法
+
+use rust_learning::structs::Task;
+
+pub fn main() {
+    let task = Task::new("Backup").start().fail("disk full");
+    task.resume();
+}
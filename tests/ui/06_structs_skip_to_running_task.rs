@@ -0,0 +1,14 @@
+//@ error: E0451
+// 06_structs.rs：字段是私有的，模块外不能直接构造 Task<Running> 跳过 start
+
+use std::marker::PhantomData;
+
+use rust_learning::structs::{Running, Task};
+
+pub fn main() {
+    let _task: Task<Running> = Task {
+        name: String::from("Backup"),
+        state: PhantomData,
+        data: (),
+    };
+}
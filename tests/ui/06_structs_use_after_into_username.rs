@@ -0,0 +1,11 @@
+//@ error: E0382
+// 06_structs.rs：into_username 以 self 获取所有权，调用后 user1 不能再使用
+
+use rust_learning::structs::User;
+
+pub fn main() {
+    let user1 = User::from_email("someusername123@example.com").unwrap();
+    let username = user1.into_username();
+    println!("moved out username: {}", username);
+    println!("try to use user1, {:?}", user1);
+}
@@ -0,0 +1,24 @@
+//@ error: E0308
+// 14_trait.rs：返回 impl Trait 时，所有分支必须返回同一具体类型
+
+use rust_learning::traits::{NewsArticle, Summary, Tweet};
+
+pub fn returns_summarizable_condition(switch: bool) -> impl Summary {
+    if switch {
+        NewsArticle {
+            headline: String::from("Penguins win the Stanley Cup Championship!"),
+            location: String::from("Pittsburgh, PA, USA"),
+            author: String::from("Iceburgh"),
+            content: String::from(
+                "The Pittsburgh Penguins once again are the best hockey team in the NHL.",
+            ),
+        }
+    } else {
+        Tweet {
+            username: String::from("horse_ebooks"),
+            content: String::from("of course, as you probably already know, people"),
+            reply: false,
+            retweet: false,
+        }
+    }
+}
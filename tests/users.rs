@@ -0,0 +1,196 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use rust_learning::email::Email;
+use rust_learning::structs::{Role, User};
+use rust_learning::users::{FileUserRepository, MemoryUserRepository, UserError, UserRepository};
+
+/// 每个测试使用独立的临时用户文件
+fn temp_users(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!(
+        "rust-learning-users-{}-{}",
+        std::process::id(),
+        name
+    ));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn user(username: &str, email: &str) -> User {
+    User::new(Email::parse(email).unwrap(), username.to_string())
+}
+
+fn usernames(users: Vec<&User>) -> Vec<&str> {
+    users.into_iter().map(User::username).collect()
+}
+
+/// 两种实现共用的增删改查检查
+fn exercise_crud(repo: &mut impl UserRepository) {
+    repo.create(user("carol", "carol@example.com")).unwrap();
+    repo.create(user("alice", "alice@example.org")).unwrap();
+    repo.create(user("bob", "bob@example.com")).unwrap();
+
+    assert_eq!(usernames(repo.all()), ["alice", "bob", "carol"]);
+    assert_eq!(
+        repo.find_by_username("bob").map(User::email),
+        Some("bob@example.com")
+    );
+    assert_eq!(
+        repo.find_by_email("Alice@Example.ORG").map(User::username),
+        Some("alice")
+    );
+    assert!(repo.find_by_username("dave").is_none());
+
+    let mut bob = repo.find_by_username("bob").unwrap().clone();
+    bob.deactivate();
+    let old = repo.update(bob).unwrap();
+    assert!(old.is_active());
+    assert_eq!(usernames(repo.list_active()), ["alice", "carol"]);
+
+    let removed = repo.delete("carol").unwrap();
+    assert_eq!(removed.email(), "carol@example.com");
+    assert_eq!(usernames(repo.all()), ["alice", "bob"]);
+}
+
+#[test]
+fn memory_repository_supports_crud() {
+    let mut repo = MemoryUserRepository::new();
+    exercise_crud(&mut repo);
+    assert_eq!(repo.len(), 2);
+}
+
+#[test]
+fn usernames_and_emails_are_unique() {
+    let mut repo = MemoryUserRepository::new();
+    repo.create(user("alice", "alice@example.org")).unwrap();
+    repo.create(user("bob", "bob@example.com")).unwrap();
+
+    let cases = [
+        (
+            repo.create(user("alice", "other@example.org")),
+            "用户名已存在: alice",
+        ),
+        (
+            repo.create(user("alice2", "ALICE@example.org")),
+            "邮箱已被使用: ALICE@example.org",
+        ),
+        (
+            repo.update(user("bob", "alice@example.org")).map(drop),
+            "邮箱已被使用: alice@example.org",
+        ),
+        (
+            repo.update(user("nobody", "n@example.org")).map(drop),
+            "用户不存在: nobody",
+        ),
+        (repo.delete("nobody").map(drop), "用户不存在: nobody"),
+        (
+            repo.create(user("has space", "s@example.org")),
+            "无效的用户名: \"has space\"",
+        ),
+    ];
+    for (result, message) in cases {
+        assert_eq!(result.unwrap_err().to_string(), message);
+    }
+    // 失败的操作不会改变仓库；用户保留自己的邮箱（只改大小写）不算冲突
+    assert_eq!(usernames(repo.all()), ["alice", "bob"]);
+    repo.update(user("bob", "BOB@example.com")).unwrap();
+}
+
+#[test]
+fn file_repository_persists_every_change() {
+    let path = temp_users("persist");
+    {
+        let mut repo = FileUserRepository::open(&path).unwrap();
+        assert!(repo.is_empty());
+        exercise_crud(&mut repo);
+    }
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# rust-learning users\n\
+         version 2\n\
+         user alice alice@example.org active viewer 0 - -\n\
+         user bob bob@example.com inactive viewer 0 - -\n"
+    );
+
+    let mut repo = FileUserRepository::open(&path).unwrap();
+    assert_eq!(usernames(repo.list_active()), ["alice"]);
+    let mut alice = repo.find_by_username("alice").unwrap().clone();
+    alice.deactivate();
+    repo.update(alice).unwrap();
+    let repo = FileUserRepository::open(&path).unwrap();
+    assert!(repo.list_active().is_empty());
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn failed_change_leaves_file_untouched() {
+    let path = temp_users("untouched");
+    let mut repo = FileUserRepository::open(&path).unwrap();
+    repo.create(user("alice", "alice@example.org")).unwrap();
+    let before = fs::read_to_string(&path).unwrap();
+
+    assert!(matches!(
+        repo.create(user("mallory", "alice@example.org")),
+        Err(UserError::EmailTaken(_))
+    ));
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+    assert_eq!(repo.len(), 1);
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn files_without_a_version_line_are_migrated() {
+    // 三种早期格式：只有登录次数、加上最近登录时间、再加上角色
+    let text = "# rust-learning users\n\
+                user alice alice@example.org active 3\n\
+                user bob bob@example.com inactive 1 1760601600\n\
+                user carol carol@example.net active admin 2 -\n";
+    let repo = MemoryUserRepository::parse(text).unwrap();
+    let alice = repo.find_by_username("alice").unwrap();
+    assert_eq!(alice.role(), Role::Viewer);
+    assert_eq!((alice.sign_in_count(), alice.last_login()), (3, None));
+    let bob = repo.find_by_username("bob").unwrap();
+    assert!(!bob.is_active());
+    assert_eq!(bob.last_login(), Some(1_760_601_600));
+    assert_eq!(repo.find_by_username("carol").unwrap().role(), Role::Admin);
+    assert!(repo.password_hash("alice").is_none());
+
+    assert_eq!(
+        repo.to_text(),
+        "# rust-learning users\n\
+         version 2\n\
+         user alice alice@example.org active viewer 3 - -\n\
+         user bob bob@example.com inactive viewer 1 1760601600 -\n\
+         user carol carol@example.net active admin 2 - -\n"
+    );
+}
+
+#[test]
+fn parse_reports_line_numbers() {
+    let cases = [
+        ("version 2\nuser alice a@example.org active viewer 1 -\n", 2),
+        ("version 3\n", 1),
+        (
+            "user alice a@example.org active viewer 1 - -\nversion 2\n",
+            2,
+        ),
+        ("user alice a@example.org\n", 1),
+        ("# users\n\nuser alice a@example.org busy viewer 1 - -\n", 3),
+        ("user alice a@example.org active viewer many - -\n", 1),
+        ("user alice a@example.org active viewer 1 yesterday -\n", 1),
+        (
+            "user alice a@example.org active viewer 1 - -\nuser bob A@example.org active viewer 1 - -\n",
+            2,
+        ),
+        ("user alice a@b@example.org active viewer 1 - -\n", 1),
+        ("user alice a@example.org active root 1 - -\n", 1),
+        ("user alice a@example.org active viewer 1 - md5$abc\n", 1),
+    ];
+    for (text, line) in cases {
+        match MemoryUserRepository::parse(text) {
+            Err(UserError::Parse { line: actual, .. }) => assert_eq!(actual, line, "{:?}", text),
+            other => panic!("{:?} 应解析失败: {:?}", text, other),
+        }
+    }
+}
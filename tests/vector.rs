@@ -0,0 +1,95 @@
+use rust_learning::structs;
+use rust_learning::traits;
+use rust_learning::vector::{Vec2, Vec3};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn operators_work_componentwise() {
+    let a = Vec3::new(1, -2, 3);
+    let b = Vec3::new(4, 5, -6);
+    assert_eq!(a + b, Vec3::new(5, 3, -3));
+    assert_eq!(a - b, Vec3::new(-3, -7, 9));
+    assert_eq!(a * 3, Vec3::new(3, -6, 9));
+    assert_eq!(-a, Vec3::new(-1, 2, -3));
+    assert_eq!(a + -a, Vec3::default());
+
+    let p = Vec2::new(1.5, -0.5);
+    assert_eq!(p + p, p * 2.0);
+    assert_eq!(p - p, Vec2::new(0.0, 0.0));
+    assert_eq!(-p, Vec2::new(-1.5, 0.5));
+}
+
+#[test]
+fn dot_and_cross_products() {
+    let x = Vec3::new(1, 0, 0);
+    let y = Vec3::new(0, 1, 0);
+    let z = Vec3::new(0, 0, 1);
+    assert_eq!(x.cross(y), z);
+    assert_eq!(y.cross(z), x);
+    assert_eq!(y.cross(x), -z);
+    assert_eq!(x.dot(y), 0);
+
+    let a = Vec3::new(2, 3, 4);
+    let b = Vec3::new(5, 6, 7);
+    assert_eq!(a.dot(b), 56);
+    // 叉积垂直于两个因子
+    let c = a.cross(b);
+    assert_eq!(c, Vec3::new(-3, 6, -3));
+    assert_eq!((c.dot(a), c.dot(b)), (0, 0));
+    assert_eq!(a.cross(a), Vec3::default());
+
+    assert_eq!(Vec2::new(1, 0).cross(Vec2::new(0, 1)), 1);
+    assert_eq!(Vec2::new(0, 1).cross(Vec2::new(1, 0)), -1);
+    assert_eq!(Vec2::new(3, 4).dot(Vec2::new(-4, 3)), 0);
+}
+
+#[test]
+fn length_normalize_and_distance() {
+    assert_eq!(Vec2::new(3, 4).length(), 5.0);
+    assert_eq!(Vec3::new(2, 3, 6).length(), 7.0);
+    assert_eq!(Vec3::new(1.0f32, 2.0, 2.0).length(), 3.0);
+    assert_eq!(Vec3::new(1, 1, 1).distance(Vec3::new(3, 4, 7)), 7.0);
+    assert_eq!(Vec2::new(-1, -1).distance(Vec2::new(2, 3)), 5.0);
+
+    let unit = Vec3::new(0, -3, 4).normalize().unwrap();
+    assert!(close(unit.x, 0.0) && close(unit.y, -0.6) && close(unit.z, 0.8));
+    assert!(close(unit.length(), 1.0));
+    let unit = Vec2::new(7.0, 7.0).normalize().unwrap();
+    assert!(close(unit.x, 1.0 / 2f64.sqrt()) && close(unit.y, unit.x));
+    assert_eq!(Vec3::new(0, 0, 0).normalize(), None);
+    assert_eq!(Vec2::new(0.0, 0.0).normalize(), None);
+}
+
+#[test]
+fn integer_and_float_conversions() {
+    let v = Vec3::new(1, -2, 3);
+    let f: Vec3<f64> = v.into();
+    assert_eq!(f, Vec3::new(1.0, -2.0, 3.0));
+    assert_eq!(f.round(), v);
+    assert_eq!(Vec2::new(2.5, -2.5).round(), Vec2::new(3, -3));
+    assert_eq!(Vec2::new(0.49, -0.51).round(), Vec2::new(0, -1));
+    // 越界时取边界值，NaN 变成 0
+    assert_eq!(
+        Vec3::new(1e12, -1e12, f64::NAN).round(),
+        Vec3::new(i32::MAX, i32::MIN, 0)
+    );
+
+    assert_eq!(Vec3::new(1, 2, 3).to_string(), "(1, 2, 3)");
+    assert_eq!(format!("{:.2}", Vec2::new(1.0, 1.0 / 3.0)), "(1.00, 0.33)");
+}
+
+#[test]
+fn lesson_points_convert_to_vectors() {
+    let v = Vec3::from(structs::Point(1, 2, 3)) * 2;
+    assert_eq!(v, Vec3::new(2, 4, 6));
+    let structs::Point(x, y, z) = v.into();
+    assert_eq!((x, y, z), (2, 4, 6));
+
+    let p = traits::Point { x: 3, y: 4 };
+    let moved: traits::Point = (Vec2::from(p) + Vec2::new(1, -1)).into();
+    assert_eq!(moved, traits::Point { x: 4, y: 3 });
+    assert_eq!(Vec2::from(p).length(), 5.0);
+}